@@ -0,0 +1,145 @@
+use crate::context::LogLevel;
+use crate::core::MD5_PREFIX;
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Context, Value, tags};
+use std::fs;
+use std::path::PathBuf;
+use walkdir::WalkDir;
+
+/// Collects the existing generated artifacts: the output env file plus the
+/// `.properties` and `MD5_*` files under the configured versions folder
+fn collect_targets(output_env: &str, ctx: &Context) -> Vec<PathBuf> {
+  let mut targets = Vec::new();
+
+  let output_env = ctx.get_basedir().join(output_env);
+  if output_env.is_file() {
+    targets.push(output_env);
+  }
+
+  let versions_folder = ctx.get_basedir().join(ctx.config.versions_folder());
+  if versions_folder.is_dir() {
+    for entry in WalkDir::new(&versions_folder)
+      .into_iter()
+      .filter_map(|e| e.ok())
+      .filter(|e| e.file_type().is_file())
+    {
+      let file_name = entry.file_name().to_string_lossy();
+      if file_name.ends_with(".properties") || file_name.starts_with(MD5_PREFIX) {
+        targets.push(entry.path().to_path_buf());
+      }
+    }
+  }
+
+  targets.sort();
+  targets
+}
+
+/// Register the clean command
+pub fn register_clean_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "clean",
+    "Remove generated artifacts (output env file, version .properties and MD5_* files); without force only lists them",
+    "(clean output-env [\"force\"])",
+    "  (clean \".env.docker\")           ; Dry run, returns the files that would be removed\n  (clean \".env.docker\" \"force\")   ; Remove the files and return them",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "clean", "executing clean command");
+
+      let (output_env, force) = match args.as_slice() {
+        [Value::Str(output_env)] => (output_env.clone(), false),
+        [Value::Str(output_env), Value::Str(flag)] if flag == "force" => (output_env.clone(), true),
+        [Value::Str(output_env), Value::Bool(force)] => (output_env.clone(), *force),
+        [Value::Str(_), _] => return Err("clean second argument must be \"force\"".to_string()),
+        [_] | [_, _] => return Err("clean output-env must be a string".to_string()),
+        _ => return Err("clean expects one or two arguments (output-env [\"force\"])".to_string()),
+      };
+
+      let targets = collect_targets(&output_env, ctx);
+      if targets.is_empty() {
+        debug_log(ctx, LogLevel::Info, "clean", "nothing to clean");
+        return Ok(Value::List(vec![]));
+      }
+
+      for target in &targets {
+        if force {
+          fs::remove_file(target).map_err(|e| format!("Failed to remove {}: {}", target.display(), e))?;
+          debug_log(ctx, LogLevel::Info, "clean", &format!("removed {}", target.display()));
+        } else {
+          debug_log(ctx, LogLevel::Info, "clean", &format!("would remove {}", target.display()));
+        }
+      }
+
+      Ok(Value::List(
+        targets.iter().map(|t| Value::Str(t.to_string_lossy().to_string())).collect(),
+      ))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::VERSIONS_FOLDER_KEY;
+
+  fn setup(name: &str) -> (PathBuf, Context) {
+    let base = PathBuf::from("target").join(name);
+    let _ = fs::remove_dir_all(&base);
+    let versions = base.join("versions");
+    fs::create_dir_all(versions.join("nested")).unwrap();
+    fs::write(base.join(".env.out"), "A=1\n").unwrap();
+    fs::write(versions.join("app.properties"), "md5=abc\n").unwrap();
+    fs::write(versions.join("nested").join("db.properties"), "md5=def\n").unwrap();
+    fs::write(versions.join("MD5_app"), "abc").unwrap();
+    fs::write(versions.join("keep.txt"), "keep").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_clean_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(base.clone());
+    ctx.config.set(VERSIONS_FOLDER_KEY, "versions").unwrap();
+    (base, ctx)
+  }
+
+  fn clean(ctx: &mut Context, args: Vec<Value>) -> Result<Value, String> {
+    ctx.registry.get("clean").unwrap().execute(args, ctx)
+  }
+
+  #[test]
+  fn test_clean_dry_run_keeps_files() {
+    let (base, mut ctx) = setup("test_clean_dry_run");
+
+    let result = clean(&mut ctx, vec![Value::Str(".env.out".to_string())]).unwrap();
+    let Value::List(targets) = result else { panic!("expected a list") };
+    assert_eq!(targets.len(), 4);
+    assert!(!targets.iter().any(|t| t.to_string().ends_with("keep.txt")));
+    assert!(targets.iter().all(|t| PathBuf::from(t.to_string()).exists()));
+
+    let _ = fs::remove_dir_all(&base);
+  }
+
+  #[test]
+  fn test_clean_force_removes_artifacts() {
+    let (base, mut ctx) = setup("test_clean_force");
+
+    let args = vec![Value::Str(".env.out".to_string()), Value::Str("force".to_string())];
+    let Value::List(targets) = clean(&mut ctx, args.clone()).unwrap() else { panic!("expected a list") };
+    assert_eq!(targets.len(), 4);
+    assert!(targets.iter().all(|t| !PathBuf::from(t.to_string()).exists()));
+    assert!(base.join("versions").join("keep.txt").exists());
+    assert_eq!(clean(&mut ctx, args).unwrap(), Value::List(vec![]));
+
+    let _ = fs::remove_dir_all(&base);
+  }
+
+  #[test]
+  fn test_clean_invalid_arguments() {
+    let (base, mut ctx) = setup("test_clean_invalid");
+
+    assert!(clean(&mut ctx, vec![]).is_err());
+    assert!(clean(&mut ctx, vec![Value::Int(1)]).is_err());
+    assert!(clean(&mut ctx, vec![Value::Str(".env.out".to_string()), Value::Str("yes".to_string())]).is_err());
+    assert!(base.join(".env.out").exists());
+
+    let _ = fs::remove_dir_all(&base);
+  }
+}
@@ -1,12 +1,16 @@
 use crate::file_ops::read_env_file;
 use crate::model::*;
+use crate::context::LogLevel;
 use crate::utils::debug_log;
-use crate::utils::{get_home_directory, socket_exists};
+use crate::signals::status_tracked;
+use crate::utils::{get_home_directory, output_with_timeout, socket_exists, wait_with_timeout};
 use crate::{CommandRegistry, Context, Value, tags};
 use std::collections::HashMap;
 use std::env;
-use std::process::{Command, Stdio};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
 use std::io::{stdin, stdout, IsTerminal};
+use std::time::Duration;
 
 /// Configuration structure for Docker commands
 /// Allows dynamic configuration of Docker command behavior through Lisp functions
@@ -24,6 +28,29 @@ pub struct DockerCommandConfig {
   pub pre_commands: Vec<Vec<String>>,
   /// Commands to execute after Docker command
   pub post_commands: Vec<Vec<String>>,
+  /// Commands to execute only when the Docker command fails
+  pub post_failure_commands: Vec<Vec<String>>,
+  /// Whether a failing pre-command aborts the run (default: true)
+  pub abort_on_pre_failure: bool,
+  /// Maximum run time of the Docker command (default: None for no limit)
+  pub timeout: Option<Duration>,
+  /// Directory the Docker command runs in (default: None for basedir)
+  pub workdir: Option<PathBuf>,
+  /// Whether the command runs a service container (default: true)
+  /// When false (compose lifecycle commands such as up/down/build) the socket
+  /// volume, `-e` flags and make args are omitted; a custom socket is passed
+  /// through `DOCKER_HOST` instead
+  pub service_run: bool,
+  /// Whether user arguments are checked against `denied_args` (default: false)
+  pub strict: bool,
+  /// Arguments rejected in strict mode (default: DOCKER_DENIED_ARGS)
+  pub denied_args: Vec<String>,
+  /// Arguments permitted in strict mode even when denied
+  pub allowed_args: Vec<String>,
+  /// Context variables forwarded to Docker (default: empty for all)
+  pub env_allow: Vec<String>,
+  /// Context variables never forwarded to Docker
+  pub env_deny: Vec<String>,
 }
 
 impl Default for DockerCommandConfig {
@@ -35,10 +62,39 @@ impl Default for DockerCommandConfig {
       env_vars: HashMap::new(),
       pre_commands: Vec::new(),
       post_commands: Vec::new(),
+      post_failure_commands: Vec::new(),
+      abort_on_pre_failure: true,
+      timeout: None,
+      workdir: None,
+      service_run: true,
+      strict: false,
+      denied_args: DOCKER_DENIED_ARGS.iter().map(|s| s.to_string()).collect(),
+      allowed_args: Vec::new(),
+      env_allow: Vec::new(),
+      env_deny: Vec::new(),
     }
   }
 }
 
+/// Converts a hook list stored in the context into command vectors
+/// Non-list entries and non-string arguments are ignored
+fn hooks_from_value(hooks: &[Value]) -> Vec<Vec<String>> {
+  hooks.iter()
+    .filter_map(|v| match v {
+      Value::List(cmd_args) => {
+        let cmd: Vec<String> = cmd_args.iter()
+          .filter_map(|arg| match arg {
+            Value::Str(s) => Some(s.clone()),
+            _ => None,
+          })
+          .collect();
+        if !cmd.is_empty() { Some(cmd) } else { None }
+      },
+      _ => None,
+    })
+    .collect()
+}
+
 /// Builds Docker configuration from Context variables
 /// Extracts configuration from Lisp variables set by configuration commands
 fn build_docker_config(ctx: &Context) -> DockerCommandConfig {
@@ -104,20 +160,7 @@ fn build_docker_config(ctx: &Context) -> DockerCommandConfig {
   if let Some(value) = ctx.get_variable("docker_pre_hooks") {
     match value {
       Value::List(pre_hooks) => {
-        config.pre_commands = pre_hooks.iter()
-          .filter_map(|v| match v {
-            Value::List(cmd_args) => {
-              let cmd: Vec<String> = cmd_args.iter()
-                .filter_map(|arg| match arg {
-                  Value::Str(s) => Some(s.clone()),
-                  _ => None,
-                })
-                .collect();
-              if !cmd.is_empty() { Some(cmd) } else { None }
-            },
-            _ => None,
-          })
-          .collect();
+        config.pre_commands = hooks_from_value(&pre_hooks);
       },
       Value::Nil => {
         // Keep default (empty) when explicitly set to nil
@@ -133,20 +176,7 @@ fn build_docker_config(ctx: &Context) -> DockerCommandConfig {
   if let Some(value) = ctx.get_variable("docker_post_hooks") {
     match value {
       Value::List(post_hooks) => {
-        config.post_commands = post_hooks.iter()
-          .filter_map(|v| match v {
-            Value::List(cmd_args) => {
-              let cmd: Vec<String> = cmd_args.iter()
-                .filter_map(|arg| match arg {
-                  Value::Str(s) => Some(s.clone()),
-                  _ => None,
-                })
-                .collect();
-              if !cmd.is_empty() { Some(cmd) } else { None }
-            },
-            _ => None,
-          })
-          .collect();
+        config.post_commands = hooks_from_value(&post_hooks);
       },
       Value::Nil => {
         // Keep default (empty) when explicitly set to nil
@@ -158,9 +188,138 @@ fn build_docker_config(ctx: &Context) -> DockerCommandConfig {
     }
   }
 
+  // Extract post_failure_commands from context
+  if let Some(value) = ctx.get_variable("docker_post_failure_hooks") {
+    match value {
+      Value::List(failure_hooks) => {
+        config.post_failure_commands = hooks_from_value(&failure_hooks);
+      },
+      Value::Nil => {
+        // Keep default (empty) when explicitly set to nil
+        config.post_failure_commands = Vec::new();
+      },
+      _ => {
+        // Invalid type, keep defaults
+      }
+    }
+  }
+
+  // Extract pre-hook failure policy from context
+  if let Some(Value::Str(policy)) = ctx.get_variable("docker_pre_hook_policy") {
+    config.abort_on_pre_failure = policy != "continue";
+  }
+
+  // Extract timeout (seconds) from context
+  if let Some(Value::Int(secs)) = ctx.get_variable("docker_timeout") {
+    if secs > 0 {
+      config.timeout = Some(Duration::from_secs(secs as u64));
+    }
+  }
+
+  // Extract working directory from context, resolved relative to basedir
+  if let Some(Value::Str(workdir)) = ctx.get_variable("docker_workdir") {
+    if !workdir.is_empty() {
+      config.workdir = Some(ctx.get_basedir().join(workdir));
+    }
+  }
+
+  // Extract strict mode and argument deny/allow lists from context
+  if let Some(Value::Bool(strict)) = ctx.get_variable("docker_strict") {
+    config.strict = strict;
+  }
+  if let Some(Value::List(denied)) = ctx.get_variable("docker_denied_args") {
+    config.denied_args.extend(denied.iter().filter_map(|v| match v {
+      Value::Str(s) => Some(s.clone()),
+      _ => None,
+    }));
+  }
+  if let Some(Value::List(allowed)) = ctx.get_variable("docker_allowed_args") {
+    config.allowed_args = allowed.iter()
+      .filter_map(|v| match v {
+        Value::Str(s) => Some(s.clone()),
+        _ => None,
+      })
+      .collect();
+  }
+
+  // Extract environment variable allow/deny lists from context
+  if let Some(Value::List(allow)) = ctx.get_variable("docker_env_allow") {
+    config.env_allow = allow.iter()
+      .filter_map(|v| match v {
+        Value::Str(s) => Some(s.clone()),
+        _ => None,
+      })
+      .collect();
+  }
+  if let Some(Value::List(deny)) = ctx.get_variable("docker_env_deny") {
+    config.env_deny = deny.iter()
+      .filter_map(|v| match v {
+        Value::Str(s) => Some(s.clone()),
+        _ => None,
+      })
+      .collect();
+  }
+
   config
 }
 
+/// Whether `arg` is covered by the deny/allow list entry `pattern`
+/// Matches the exact flag, its `flag=value` form, and short flags with an
+/// attached value such as `-v/host:/host`
+fn docker_arg_matches(arg: &str, pattern: &str) -> bool {
+  if arg == pattern {
+    return true;
+  }
+  if let Some(rest) = arg.strip_prefix(pattern) {
+    let is_short_flag = pattern.len() == 2 && pattern.starts_with('-') && pattern != "--";
+    return rest.starts_with('=') || is_short_flag;
+  }
+  false
+}
+
+/// Rejects user arguments matching the denylist when strict mode is enabled
+/// A flag followed by its value (`--network host`) is checked as `--network=host`
+/// Checking stops at the first positional argument that is not a flag value
+/// (the image, service or command), so `grep -v x` is not mistaken for a volume
+fn check_docker_args(config: &DockerCommandConfig, args: &[String]) -> Result<(), String> {
+  if !config.strict {
+    return Ok(());
+  }
+
+  let mut follows_flag = false;
+  for (index, arg) in args.iter().enumerate() {
+    if arg == "--" {
+      break;
+    }
+    if !arg.starts_with('-') {
+      if !follows_flag {
+        break;
+      }
+      follows_flag = false;
+      continue;
+    }
+    follows_flag = !arg.contains('=');
+
+    let mut candidates = vec![arg.clone()];
+    if let Some(next) = args.get(index + 1) {
+      candidates.push(format!("{}={}", arg, next));
+    }
+
+    for candidate in &candidates {
+      let denied = config.denied_args.iter().any(|d| docker_arg_matches(candidate, d));
+      let allowed = config.allowed_args.iter().any(|a| docker_arg_matches(candidate, a));
+      if denied && !allowed {
+        return Err(format!(
+          "docker argument '{}' is denied in strict mode (use docker-allow to permit it)",
+          candidate
+        ));
+      }
+    }
+  }
+
+  Ok(())
+}
+
 /// Configura un [`Command`] per comportarsi come un processo TTY interattivo se possibile.
 /// - Se stdin/stdout sono TTY → eredita gli stream, abilita interattività.
 /// - Se non lo sono → disabilita il TTY, ma mantiene output visibile.
@@ -176,11 +335,11 @@ pub fn prepare_tty_command(mut cmd: Command) -> Command {
 
   // Se abbiamo una console TTY, possiamo impostare env/flag opzionali
   if have_tty {
-    println!("TTY DETECTED");
+    eprintln!("TTY DETECTED");
     // Puoi settare env custom o logging più verboso se serve
     cmd.env("TERM", std::env::var("TERM").unwrap_or_else(|_| "xterm-256color".into()));
   } else {
-    println!("NO TTY DETECTED");
+    eprintln!("NO TTY DETECTED");
     // In ambienti non interattivi puoi regolare l’ambiente
     cmd.env("NO_TTY", "1");
   }
@@ -190,13 +349,13 @@ pub fn prepare_tty_command(mut cmd: Command) -> Command {
 
 /// Executes a generic command with arguments
 fn execute_command(command: &str, args: &[String], ctx: &Context) -> Result<(), String> {
-  debug_log(ctx, "docker", &format!("executing command: {} {:?}", command, args));
+  debug_log(ctx, LogLevel::Debug, "docker", &format!("executing command: {} {:?}", command, args));
 
   let mut cmd = prepare_tty_command(Command::new(command));
   cmd.current_dir(ctx.get_basedir());
   cmd.args(args);
 
-  match cmd.status() {
+  match status_tracked(&mut cmd) {
     Ok(status) => {
       if status.success() {
         Ok(())
@@ -208,30 +367,67 @@ fn execute_command(command: &str, args: &[String], ctx: &Context) -> Result<(),
   }
 }
 
-/// Executes Docker command with the provided configuration
-fn execute_docker_command_with_config(
-  ctx: &Context,
-  config: &DockerCommandConfig,
-  env_vars: &HashMap<String, String>,
-  existing_env_vars: &HashMap<String, String>,
-  args: &[String],
-  verbose: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-  // Execute pre-commands
+/// Runs the configured pre-commands
+/// Stops at the first failure unless the pre-hook policy is "continue"
+fn run_pre_hooks(ctx: &Context, config: &DockerCommandConfig) -> Result<(), String> {
   for pre_cmd in &config.pre_commands {
     if !pre_cmd.is_empty() {
       let cmd_name = &pre_cmd[0];
       let cmd_args = &pre_cmd[1..];
       if let Err(e) = execute_command(cmd_name, cmd_args, ctx) {
-        debug_log(ctx, "docker", &format!("pre-command failed: {}", e));
-        return Err(e.into());
+        debug_log(ctx, LogLevel::Error, "docker", &format!("pre-command failed: {}", e));
+        if config.abort_on_pre_failure {
+          return Err(e);
+        }
       }
     }
   }
+  Ok(())
+}
 
-  // Prepare Docker command
-  let mut command = prepare_tty_command(Command::new("docker"));
-  command.current_dir(ctx.get_basedir());
+/// Runs the post-commands matching the Docker exit status
+/// Post-command failures are logged but never fail the main operation
+fn run_post_hooks(ctx: &Context, config: &DockerCommandConfig, docker_succeeded: bool) {
+  // Select the post-hook list according to the Docker exit status
+  let post_commands = if docker_succeeded {
+    &config.post_commands
+  } else {
+    &config.post_failure_commands
+  };
+
+  for post_cmd in post_commands {
+    if !post_cmd.is_empty() {
+      let cmd_name = &post_cmd[0];
+      let cmd_args = &post_cmd[1..];
+      if let Err(e) = execute_command(cmd_name, cmd_args, ctx) {
+        debug_log(ctx, LogLevel::Error, "docker", &format!("post-command failed: {}", e));
+      }
+    }
+  }
+}
+
+/// Waits for the Docker process, killing it if the configured timeout elapses
+/// When waiting fails the failure post-hooks run before the error is returned
+fn wait_docker_child(ctx: &Context, config: &DockerCommandConfig, child: &mut Child) -> Result<ExitStatus, String> {
+  wait_with_timeout(child, config.timeout).inspect_err(|_| run_post_hooks(ctx, config, false))
+}
+
+/// Assembles the Docker command line: compose args, socket mapping,
+/// environment variables and make args, followed by the user arguments
+/// Shared by the streaming and capturing execution modes
+fn build_docker_process(
+  ctx: &Context,
+  config: &DockerCommandConfig,
+  env_vars: &HashMap<String, String>,
+  existing_env_vars: &HashMap<String, String>,
+  args: &[String],
+  verbose: bool,
+) -> Result<Command, Box<dyn std::error::Error>> {
+  let mut command = Command::new("docker");
+  match &config.workdir {
+    Some(workdir) => command.current_dir(workdir),
+    None => command.current_dir(ctx.get_basedir()),
+  };
 
   // Use configured compose args or fallback to defaults
   if config.compose_args.is_empty() {
@@ -240,6 +436,19 @@ fn execute_docker_command_with_config(
     command.args(&config.compose_args);
   }
 
+  if !config.service_run {
+    // Lifecycle commands only need the environment for compose interpolation
+    if let Some(socket_path) = &config.socket_path {
+      command.env("DOCKER_HOST", format!("unix://{}", socket_path));
+    }
+    command.envs(env_vars);
+    command.args(args);
+    if verbose {
+      eprintln!("Executing command: {}", describe_command(&command, ctx));
+    }
+    return Ok(command);
+  }
+
   // Handle socket mapping (adapted for cross-platform compatibility)
   if cfg!(target_os = "windows") {
     // On Windows, Docker socket is handled differently or omitted
@@ -247,13 +456,13 @@ fn execute_docker_command_with_config(
     let docker_socket = format!("{}:{}", socket_path, DOCKER_SOCKET_PATH);
     command.args(&["-v", &docker_socket]);
     if verbose {
-      println!("Docker Socket mapping: {}", docker_socket);
+      eprintln!("Docker Socket mapping: {}", docker_socket);
     }
   } else {
     // Check if DOCKER_HOST exists in .env file
     if let Some(docker_host_map) = existing_env_vars.get(ENV_DOCKER_HOST_MAP) {
       if verbose {
-        println!("Using DOCKER_HOST_MAP from .env file: {}", docker_host_map);
+        eprintln!("Using DOCKER_HOST_MAP from .env file: {}", docker_host_map);
       }
       command.args(&["-v", &*docker_host_map]);
     } else {
@@ -285,7 +494,7 @@ fn execute_docker_command_with_config(
       let docker_socket = format!("{}:{}", socket_path, DOCKER_SOCKET_PATH);
       command.args(&["-v", &*docker_socket]);
       if verbose {
-        println!("Docker Socket mapping: {}", docker_socket);
+        eprintln!("Docker Socket mapping: {}", docker_socket);
       }
     };
   }
@@ -294,7 +503,7 @@ fn execute_docker_command_with_config(
   for (key, value) in env_vars {
     command.env(key, value);
     if verbose {
-      println!("* env key: {} = {}", key, value);
+      eprintln!("* env key: {} = {}", key, ctx.display_value(key, value));
     }
   }
 
@@ -320,30 +529,254 @@ fn execute_docker_command_with_config(
 
   // Print complete command (for debugging)
   if verbose {
-    println!("Executing command: {:?}", command);
+    eprintln!("Executing command: {}", describe_command(&command, ctx));
   }
 
-  // Execute Docker command
-  let status = command.status()?;
+  Ok(command)
+}
+
+/// Builds the `docker version` probe used by `docker-available`
+/// Output is captured and discarded; a custom socket is passed through `DOCKER_HOST`
+fn build_availability_process(config: &DockerCommandConfig) -> Command {
+  let mut command = Command::new("docker");
+  command.args(["version", "--format", "{{.Server.Version}}"]);
+  command.stdin(Stdio::null());
+  if let Some(socket_path) = &config.socket_path {
+    command.env("DOCKER_HOST", format!("unix://{}", socket_path));
+  }
+  command
+}
+
+/// Builds the compose args for a lifecycle subcommand (up, down, build)
+/// Keeps the compose prefix of the configured args, i.e. everything before
+/// `run` or `exec` such as `compose -f file.yml`, followed by `subcommand`
+fn compose_lifecycle_args(compose_args: &[String], subcommand: &[&str]) -> Vec<String> {
+  let compose_args: Vec<String> = if compose_args.is_empty() {
+    DOCKER_COMPOSE_ARGS.iter().map(|s| s.to_string()).collect()
+  } else {
+    compose_args.to_vec()
+  };
+
+  let prefix_len = compose_args
+    .iter()
+    .position(|arg| arg == "run" || arg == "exec")
+    .unwrap_or(compose_args.len());
+
+  let mut result = compose_args[..prefix_len].to_vec();
+  result.extend(subcommand.iter().map(|s| s.to_string()));
+  result
+}
+
+/// Builds the configuration of a compose lifecycle command from the context
+fn build_lifecycle_config(ctx: &Context, subcommand: &[&str]) -> DockerCommandConfig {
+  let mut config = build_docker_config(ctx);
+  config.compose_args = compose_lifecycle_args(&config.compose_args, subcommand);
+  config.service_run = false;
+  config
+}
+
+/// Executes Docker command with the provided configuration
+/// Output is streamed to the terminal (interactive mode)
+fn execute_docker_command_with_config(
+  ctx: &Context,
+  config: &DockerCommandConfig,
+  env_vars: &HashMap<String, String>,
+  existing_env_vars: &HashMap<String, String>,
+  args: &[String],
+  verbose: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+  check_docker_args(config, args)?;
+  run_pre_hooks(ctx, config)?;
+
+  let command = build_docker_process(ctx, config, env_vars, existing_env_vars, args, verbose)?;
+  let mut command = prepare_tty_command(command);
+
+  // Execute Docker command, killing it if the configured timeout elapses
+  let mut child = command.spawn()?;
+  let status = wait_docker_child(ctx, config, &mut child)?;
+
+  run_post_hooks(ctx, config, status.success());
 
   if !status.success() {
     eprintln!("{}", MSG_DOCKER_COMMAND_FAILED);
     return Err("Docker command failed".into());
   }
 
-  // Execute post-commands
-  for post_cmd in &config.post_commands {
-    if !post_cmd.is_empty() {
-      let cmd_name = &post_cmd[0];
-      let cmd_args = &post_cmd[1..];
-      if let Err(e) = execute_command(cmd_name, cmd_args, ctx) {
-        debug_log(ctx, "docker", &format!("post-command failed: {}", e));
-        // Post-command failures are logged but don't fail the main operation
+  Ok(())
+}
+
+/// Executes Docker command with the provided configuration, capturing
+/// stdout and stderr instead of streaming them
+/// A non-zero exit status is reported through the returned output, not as an error
+fn capture_docker_command_with_config(
+  ctx: &Context,
+  config: &DockerCommandConfig,
+  env_vars: &HashMap<String, String>,
+  existing_env_vars: &HashMap<String, String>,
+  args: &[String],
+  verbose: bool,
+) -> Result<Output, Box<dyn std::error::Error>> {
+  check_docker_args(config, args)?;
+  run_pre_hooks(ctx, config)?;
+
+  let mut command = build_docker_process(ctx, config, env_vars, existing_env_vars, args, verbose)?;
+  command.stdin(Stdio::null());
+  let output = output_with_timeout(&mut command, config.timeout).inspect_err(|_| run_post_hooks(ctx, config, false))?;
+
+  run_post_hooks(ctx, config, output.status.success());
+
+  Ok(output)
+}
+
+/// Renders `command` like its `Debug` output (env assignments, program and
+/// arguments) with secret values masked
+fn describe_command(command: &Command, ctx: &Context) -> String {
+  let mut parts = Vec::new();
+  for (key, value) in command.get_envs() {
+    if let Some(value) = value {
+      let key = key.to_string_lossy();
+      let value = value.to_string_lossy();
+      parts.push(format!("{}={:?}", key, ctx.display_value(&key, &value)));
+    }
+  }
+  parts.push(format!("{:?}", command.get_program()));
+  parts.extend(command.get_args().map(|arg| format!("{:?}", arg)));
+  parts.join(" ")
+}
+
+/// Whether the context variable `key` passes the env allow/deny lists
+/// An empty allowlist admits every key; the denylist always wins
+fn docker_env_forwarded(config: &DockerCommandConfig, key: &str) -> bool {
+  let allowed = config.env_allow.is_empty() || config.env_allow.iter().any(|k| k == key);
+  allowed && !config.env_deny.iter().any(|k| k == key)
+}
+
+/// Collects the environment for a Docker invocation
+///
+/// # Returns
+/// * `(HashMap<String, String>, HashMap<String, String>)` - The string context
+///   variables passing the env allow/deny lists, and the variables read from
+///   the `.env` file in basedir
+fn collect_docker_env(ctx: &Context, config: &DockerCommandConfig) -> (HashMap<String, String>, HashMap<String, String>) {
+  // Collect the string variables from context as environment variables
+  let mut env_vars = HashMap::new();
+  for (key, value) in &ctx.variables {
+    if let Value::Str(val) = value {
+      if docker_env_forwarded(config, key) {
+        env_vars.insert(key.clone(), val.clone());
       }
     }
   }
 
-  Ok(())
+  // Read existing environment variables from .env files if they exist
+  let mut existing_env_vars = HashMap::new();
+  let basedir = ctx.get_basedir();
+  let env_file_path = basedir.join(".env");
+
+  if env_file_path.exists() {
+    match read_env_file(&env_file_path.to_string_lossy()) {
+      Ok(vars) => {
+        existing_env_vars.extend(vars);
+        debug_log(ctx, LogLevel::Info, "docker", &format!("loaded {} variables from .env file", existing_env_vars.len()));
+      },
+      Err(e) => {
+        debug_log(ctx, LogLevel::Error, "docker", &format!("warning: failed to read .env file: {}", e));
+      }
+    }
+  }
+
+  (env_vars, existing_env_vars)
+}
+
+/// Converts docker command arguments (strings or integers) to strings
+fn docker_args_to_strings(command_name: &str, args: Vec<Value>) -> Result<Vec<String>, String> {
+  let mut docker_args = Vec::new();
+  for arg in args {
+    match arg {
+      Value::Str(s) => docker_args.push(s),
+      Value::Int(i) => docker_args.push(i.to_string()),
+      _ => return Err(format!("{} arguments must be strings or integers", command_name)),
+    }
+  }
+  Ok(docker_args)
+}
+
+/// Registers a compose lifecycle command running `docker <compose prefix> <subcommand> args...`
+/// Hooks, timeout, workdir, socket and context variables are honored as for `docker`
+fn register_docker_lifecycle_command(
+  registry: &mut CommandRegistry,
+  name: &'static str,
+  subcommand: &'static [&'static str],
+  description: &'static str,
+  syntax: &'static str,
+  examples: &'static str,
+) {
+  registry.register_closure_with_help_and_tag(
+    name,
+    description,
+    syntax,
+    examples,
+    &tags::COMMANDS,
+    move |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, name, &format!("executing {} command", name));
+
+      let docker_args = docker_args_to_strings(name, args)?;
+      let config = build_lifecycle_config(ctx, subcommand);
+      let (env_vars, existing_env_vars) = collect_docker_env(ctx, &config);
+
+      debug_log(ctx, LogLevel::Debug, name, &format!("compose args: {:?}", config.compose_args));
+
+      match execute_docker_command_with_config(ctx, &config, &env_vars, &existing_env_vars, &docker_args, ctx.get_debug_print()) {
+        Ok(_) => {
+          debug_log(ctx, LogLevel::Info, name, &format!("{} executed successfully", name));
+          Ok(Value::Str(format!("{} executed successfully", name)))
+        },
+        Err(e) => {
+          let error_msg = format!("{} failed: {}", name, e);
+          debug_log(ctx, LogLevel::Error, name, &error_msg);
+          Err(error_msg)
+        }
+      }
+    },
+  );
+}
+
+/// Registers a command appending its string arguments to the list stored in `variable`
+fn register_docker_arg_list_command(
+  registry: &mut CommandRegistry,
+  name: &'static str,
+  variable: &'static str,
+  description: &'static str,
+  syntax: &'static str,
+  examples: &'static str,
+) {
+  registry.register_closure_with_help_and_tag(
+    name,
+    description,
+    syntax,
+    examples,
+    &tags::COMMANDS,
+    move |args, ctx| {
+      if args.is_empty() {
+        return Err(format!("{} requires at least one argument", name));
+      }
+
+      let mut list = match ctx.get_variable(variable) {
+        Some(Value::List(existing)) => existing,
+        _ => Vec::new(),
+      };
+      for arg in args {
+        match arg {
+          Value::Str(s) if !s.is_empty() => list.push(Value::Str(s)),
+          _ => return Err(format!("{} arguments must be non-empty strings", name)),
+        }
+      }
+
+      debug_log(ctx, LogLevel::Debug, name, &format!("{} now contains {} entries", variable, list.len()));
+      ctx.set_variable(variable.to_string(), Value::List(list.clone()));
+      Ok(Value::List(list))
+    },
+  );
 }
 
 /// Register docker command
@@ -355,67 +788,129 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker \"run\" \"hello-world\")     ; Run a simple Docker container\n  (docker \"ps\" \"-a\")              ; List all containers\n  (docker \"build\" \".\" \"-t\" \"myapp\") ; Build an image",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker", "executing docker command");
+      debug_log(ctx, LogLevel::Debug, "docker", "executing docker command");
 
       // Convert args to strings
-      let mut docker_args = Vec::new();
-      for arg in args {
-        match arg {
-          Value::Str(s) => docker_args.push(s),
-          Value::Int(i) => docker_args.push(i.to_string()),
-          _ => return Err("docker arguments must be strings or integers".to_string()),
-        }
-      }
-
-      debug_log(ctx, "docker", &format!("docker args: {:?}", docker_args));
-
-      // Get environment variables from context
-      let mut env_vars = HashMap::new();
-
-      // Collect all string variables from context as environment variables
-      for (key, value) in &ctx.variables {
-        if let Value::Str(val) = value {
-          env_vars.insert(key.clone(), val.clone());
-        }
-      }
+      let docker_args = docker_args_to_strings("docker", args)?;
 
-      //debug_log(ctx, "docker", &format!("collected {} environment variables", env_vars.len()));
-
-      // Read existing environment variables from .env files if they exist
-      let mut existing_env_vars = HashMap::new();
-      let basedir = ctx.get_basedir();
-      let env_file_path = basedir.join(".env");
-
-      if env_file_path.exists() {
-        match read_env_file(&env_file_path.to_string_lossy()) {
-          Ok(vars) => {
-            existing_env_vars.extend(vars);
-            debug_log(ctx, "docker", &format!("loaded {} variables from .env file", existing_env_vars.len()));
-          },
-          Err(e) => {
-            debug_log(ctx, "docker", &format!("warning: failed to read .env file: {}", e));
-          }
-        }
-      }
+      debug_log(ctx, LogLevel::Debug, "docker", &format!("docker args: {:?}", docker_args));
 
       // Build configuration from context
       let config = build_docker_config(ctx);
 
+      // Get environment variables from context and .env file
+      let (env_vars, existing_env_vars) = collect_docker_env(ctx, &config);
+
       // Execute the docker command with configuration
       match execute_docker_command_with_config(ctx, &config, &env_vars, &existing_env_vars, &docker_args, ctx.get_debug_print()) {
         Ok(_) => {
-          debug_log(ctx, "docker", "docker command executed successfully");
+          debug_log(ctx, LogLevel::Info, "docker", "docker command executed successfully");
           Ok(Value::Str("Docker command executed successfully".to_string()))
         },
         Err(e) => {
           let error_msg = format!("Docker command failed: {}", e);
-          debug_log(ctx, "docker", &error_msg);
+          debug_log(ctx, LogLevel::Error, "docker", &error_msg);
+          Err(error_msg)
+        }
+      }
+    },
+  );
+
+  // Register docker-capture command
+  registry.register_closure_with_help_and_tag(
+    "docker-capture",
+    "Execute Docker commands like docker, but capture output instead of streaming it",
+    "(docker-capture [args...])",
+    "  (docker-capture \"ps\")                 ; Returns (stdout stderr success exit-code)\n  (list-first (docker-capture \"version\")) ; Get only stdout",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-capture", "executing docker-capture command");
+
+      let docker_args = docker_args_to_strings("docker-capture", args)?;
+      debug_log(ctx, LogLevel::Debug, "docker-capture", &format!("docker args: {:?}", docker_args));
+
+      let config = build_docker_config(ctx);
+      let (env_vars, existing_env_vars) = collect_docker_env(ctx, &config);
+
+      match capture_docker_command_with_config(ctx, &config, &env_vars, &existing_env_vars, &docker_args, ctx.get_debug_print()) {
+        Ok(output) => {
+          let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+          let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+          let success = output.status.success();
+          let code = output.status.code().unwrap_or(-1);
+
+          debug_log(ctx, LogLevel::Info, "docker-capture", &format!("docker completed with success: {}, exit code: {}", success, code));
+          Ok(Value::List(vec![
+            Value::Str(stdout),
+            Value::Str(stderr),
+            Value::Bool(success),
+            Value::Int(code as i64),
+          ]))
+        },
+        Err(e) => {
+          let error_msg = format!("Docker command failed: {}", e);
+          debug_log(ctx, LogLevel::Error, "docker-capture", &error_msg);
           Err(error_msg)
         }
       }
     },
   );
 
+  // Register docker-available command
+  registry.register_closure_with_help_and_tag(
+    "docker-available",
+    "Check whether the Docker daemon is reachable, honoring docker-socket and docker-timeout",
+    "(docker-available)",
+    "  (docker-available)                          ; #t when `docker version` reaches the daemon\n  (if (docker-available) (docker-up) (print \"Docker not running, skipping\"))",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-available", "checking Docker daemon availability");
+
+      if !args.is_empty() {
+        return Err("docker-available takes no arguments".to_string());
+      }
+
+      let config = build_docker_config(ctx);
+      let mut command = build_availability_process(&config);
+      let available = match output_with_timeout(&mut command, config.timeout) {
+        Ok(output) => output.status.success(),
+        Err(e) => {
+          debug_log(ctx, LogLevel::Debug, "docker-available", &format!("docker could not be run: {}", e));
+          false
+        }
+      };
+
+      debug_log(ctx, LogLevel::Info, "docker-available", &format!("Docker daemon available: {}", available));
+      Ok(Value::Bool(available))
+    },
+  );
+
+  // Register compose lifecycle commands
+  register_docker_lifecycle_command(
+    registry,
+    "docker-up",
+    &["up", "-d"],
+    "Start the compose services in the background",
+    "(docker-up [args...])",
+    "  (docker-up)                 ; docker compose up -d\n  (docker-up \"--build\" \"web\") ; docker compose up -d --build web",
+  );
+  register_docker_lifecycle_command(
+    registry,
+    "docker-down",
+    &["down"],
+    "Stop and remove the compose services",
+    "(docker-down [args...])",
+    "  (docker-down)               ; docker compose down\n  (docker-down \"-v\")          ; Also remove named volumes",
+  );
+  register_docker_lifecycle_command(
+    registry,
+    "docker-build",
+    &["build"],
+    "Build the compose service images",
+    "(docker-build [args...])",
+    "  (docker-build)              ; docker compose build\n  (docker-build \"--no-cache\" \"web\") ; Rebuild the web image from scratch",
+  );
+
   // Register docker-compose-args command
   registry.register_closure_with_help_and_tag(
     "docker-compose-args",
@@ -424,7 +919,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-compose-args \"compose\" \"run\" \"--rm\")  ; Set compose arguments\n  (docker-compose-args \"-f\" \"custom-compose.yml\")   ; Use custom compose file",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-compose-args", "configuring Docker Compose arguments");
+      debug_log(ctx, LogLevel::Debug, "docker-compose-args", "configuring Docker Compose arguments");
 
       let mut compose_args = Vec::new();
       for arg in args {
@@ -438,7 +933,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       let args_list = compose_args.into_iter().map(Value::Str).collect();
       ctx.set_variable("docker_compose_args".to_string(), Value::List(args_list));
 
-      debug_log(ctx, "docker-compose-args", "Docker Compose arguments configured");
+      debug_log(ctx, LogLevel::Debug, "docker-compose-args", "Docker Compose arguments configured");
       Ok(Value::Str("Docker Compose arguments configured".to_string()))
     },
   );
@@ -451,7 +946,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-make-args \"make\" \"build\")     ; Set make arguments\n  (docker-make-args \"npm\" \"run\" \"dev\")  ; Use npm instead of make",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-make-args", "configuring Docker make arguments");
+      debug_log(ctx, LogLevel::Debug, "docker-make-args", "configuring Docker make arguments");
 
       let mut make_args = Vec::new();
       for arg in args {
@@ -465,7 +960,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       let args_list = make_args.into_iter().map(Value::Str).collect();
       ctx.set_variable("docker_make_args".to_string(), Value::List(args_list));
 
-      debug_log(ctx, "docker-make-args", "Docker make arguments configured");
+      debug_log(ctx, LogLevel::Debug, "docker-make-args", "Docker make arguments configured");
       Ok(Value::Str("Docker make arguments configured".to_string()))
     },
   );
@@ -478,7 +973,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-socket \"/var/run/docker.sock\")           ; Set standard socket\n  (docker-socket \"/home/user/.docker/desktop/docker.sock\") ; Set custom socket",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-socket", "configuring Docker socket path");
+      debug_log(ctx, LogLevel::Debug, "docker-socket", "configuring Docker socket path");
 
       if args.len() != 1 {
         return Err("docker-socket requires exactly one argument (socket path)".to_string());
@@ -487,7 +982,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       match &args[0] {
         Value::Str(path) => {
           ctx.set_variable("docker_socket_path".to_string(), Value::Str(path.clone()));
-          debug_log(ctx, "docker-socket", &format!("Docker socket path set to: {}", path));
+          debug_log(ctx, LogLevel::Debug, "docker-socket", &format!("Docker socket path set to: {}", path));
           Ok(Value::Str(format!("Docker socket path set to: {}", path)))
         },
         _ => Err("docker-socket argument must be a string".to_string()),
@@ -495,6 +990,31 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     },
   );
 
+  // Register docker-workdir command
+  registry.register_closure_with_help_and_tag(
+    "docker-workdir",
+    "Set the directory the Docker command runs in, relative to basedir",
+    "(docker-workdir path)",
+    "  (docker-workdir \"services/api\")  ; Run Docker from a subdirectory of basedir\n  (docker-workdir \"\")              ; Run Docker from basedir (default)",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-workdir", "configuring Docker working directory");
+
+      if args.len() != 1 {
+        return Err("docker-workdir requires exactly one argument (path)".to_string());
+      }
+
+      match &args[0] {
+        Value::Str(path) => {
+          ctx.set_variable("docker_workdir".to_string(), Value::Str(path.clone()));
+          debug_log(ctx, LogLevel::Debug, "docker-workdir", &format!("Docker working directory set to: {}", path));
+          Ok(Value::Str(format!("Docker working directory set to: {}", path)))
+        },
+        _ => Err("docker-workdir argument must be a string".to_string()),
+      }
+    },
+  );
+
   // Register docker-pre command
   registry.register_closure_with_help_and_tag(
     "docker-pre",
@@ -503,7 +1023,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-pre \"echo\" \"Starting Docker...\")  ; Add echo command\n  (docker-pre \"mkdir\" \"-p\" \"logs\")          ; Create logs directory",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-pre", "adding Docker pre-hook command");
+      debug_log(ctx, LogLevel::Debug, "docker-pre", "adding Docker pre-hook command");
 
       if args.is_empty() {
         return Err("docker-pre requires at least one argument (command)".to_string());
@@ -527,7 +1047,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       pre_hooks.push(Value::List(cmd_args));
       ctx.set_variable("docker_pre_hooks".to_string(), Value::List(pre_hooks));
 
-      debug_log(ctx, "docker-pre", "Docker pre-hook command added");
+      debug_log(ctx, LogLevel::Debug, "docker-pre", "Docker pre-hook command added");
       Ok(Value::Str("Docker pre-hook command added".to_string()))
     },
   );
@@ -540,7 +1060,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-post \"echo\" \"Docker completed\")  ; Add echo command\n  (docker-post \"rm\" \"-rf\" \"temp\")          ; Clean up temp files",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-post", "adding Docker post-hook command");
+      debug_log(ctx, LogLevel::Debug, "docker-post", "adding Docker post-hook command");
 
       if args.is_empty() {
         return Err("docker-post requires at least one argument (command)".to_string());
@@ -564,11 +1084,182 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       post_hooks.push(Value::List(cmd_args));
       ctx.set_variable("docker_post_hooks".to_string(), Value::List(post_hooks));
 
-      debug_log(ctx, "docker-post", "Docker post-hook command added");
+      debug_log(ctx, LogLevel::Debug, "docker-post", "Docker post-hook command added");
       Ok(Value::Str("Docker post-hook command added".to_string()))
     },
   );
 
+  // Register docker-post-on-failure command
+  registry.register_closure_with_help_and_tag(
+    "docker-post-on-failure",
+    "Add post-hook command to execute only when the Docker command fails",
+    "(docker-post-on-failure command arg1 arg2 ...)",
+    "  (docker-post-on-failure \"echo\" \"Docker failed\")       ; Report failure\n  (docker-post-on-failure \"docker\" \"compose\" \"logs\")  ; Dump logs on failure",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-post-on-failure", "adding Docker failure post-hook command");
+
+      if args.is_empty() {
+        return Err("docker-post-on-failure requires at least one argument (command)".to_string());
+      }
+
+      let mut cmd_args = Vec::new();
+      for arg in args {
+        match arg {
+          Value::Str(s) => cmd_args.push(Value::Str(s)),
+          Value::Int(i) => cmd_args.push(Value::Str(i.to_string())),
+          _ => return Err("docker-post-on-failure arguments must be strings or integers".to_string()),
+        }
+      }
+
+      // Get existing failure hooks or create new list
+      let mut failure_hooks = match ctx.get_variable("docker_post_failure_hooks") {
+        Some(Value::List(hooks)) => hooks.clone(),
+        _ => Vec::new(),
+      };
+
+      failure_hooks.push(Value::List(cmd_args));
+      ctx.set_variable("docker_post_failure_hooks".to_string(), Value::List(failure_hooks));
+
+      debug_log(ctx, LogLevel::Info, "docker-post-on-failure", "Docker failure post-hook command added");
+      Ok(Value::Str("Docker failure post-hook command added".to_string()))
+    },
+  );
+
+  // Register docker-pre-policy command
+  registry.register_closure_with_help_and_tag(
+    "docker-pre-policy",
+    "Configure whether a failing pre-hook aborts or continues the Docker run",
+    "(docker-pre-policy \"abort\"|\"continue\")",
+    "  (docker-pre-policy \"abort\")     ; Stop on first failing pre-hook (default)\n  (docker-pre-policy \"continue\")  ; Log pre-hook failures and keep going",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-pre-policy", "configuring Docker pre-hook failure policy");
+
+      if args.len() != 1 {
+        return Err("docker-pre-policy requires exactly one argument (\"abort\" or \"continue\")".to_string());
+      }
+
+      match &args[0] {
+        Value::Str(policy) if policy == "abort" || policy == "continue" => {
+          ctx.set_variable("docker_pre_hook_policy".to_string(), Value::Str(policy.clone()));
+          debug_log(ctx, LogLevel::Debug, "docker-pre-policy", &format!("Docker pre-hook policy set to: {}", policy));
+          Ok(Value::Str(format!("Docker pre-hook policy set to: {}", policy)))
+        },
+        _ => Err("docker-pre-policy argument must be \"abort\" or \"continue\"".to_string()),
+      }
+    },
+  );
+
+  // Register docker-strict command
+  registry.register_closure_with_help_and_tag(
+    "docker-strict",
+    "Enable or disable rejection of denied arguments (volumes, --privileged, host networking) passed to docker",
+    "(docker-strict #t|#f)",
+    "  (docker-strict #t)  ; Reject e.g. (docker \"-v\" \"/:/host\") unless allowed with docker-allow",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-strict", "configuring Docker strict mode");
+
+      match args.as_slice() {
+        [Value::Bool(strict)] => {
+          ctx.set_variable("docker_strict".to_string(), Value::Bool(*strict));
+          debug_log(ctx, LogLevel::Debug, "docker-strict", &format!("Docker strict mode set to: {}", strict));
+          Ok(Value::Bool(*strict))
+        },
+        _ => Err("docker-strict requires exactly one boolean argument".to_string()),
+      }
+    },
+  );
+
+  // Register docker-deny and docker-allow commands
+  register_docker_arg_list_command(
+    registry,
+    "docker-deny",
+    "docker_denied_args",
+    "Add arguments rejected by docker in strict mode, in addition to the defaults",
+    "(docker-deny arg...)",
+    "  (docker-deny \"--ipc=host\" \"--device\")  ; Also reject host IPC and device access",
+  );
+  register_docker_arg_list_command(
+    registry,
+    "docker-allow",
+    "docker_allowed_args",
+    "Permit arguments in strict mode even when they are denied",
+    "(docker-allow arg...)",
+    "  (docker-allow \"--network=host\")  ; Allow host networking in strict mode",
+  );
+
+  // Register docker-env-allow and docker-env-deny commands
+  register_docker_arg_list_command(
+    registry,
+    "docker-env-allow",
+    "docker_env_allow",
+    "Forward only the listed context variables to Docker (all are forwarded while the list is empty)",
+    "(docker-env-allow key...)",
+    "  (docker-env-allow \"APP_ENV\" \"APP_PORT\")  ; Pass only these two variables",
+  );
+  register_docker_arg_list_command(
+    registry,
+    "docker-env-deny",
+    "docker_env_deny",
+    "Never forward the listed context variables to Docker",
+    "(docker-env-deny key...)",
+    "  (docker-env-deny \"DB_PASSWORD\")  ; Keep the secret out of the container",
+  );
+
+  // Register docker-env-secret command
+  registry.register_closure_with_help_and_tag(
+    "docker-env-secret",
+    "Mask the values of the listed variables as **** in verbose and debug output; they are still passed to Docker",
+    "(docker-env-secret key...)",
+    "  (docker-env-secret \"DB_PASSWORD\" \"API_TOKEN\")  ; Forwarded, but never printed",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-env-secret", "registering secret variables");
+
+      if args.is_empty() {
+        return Err("docker-env-secret requires at least one argument".to_string());
+      }
+
+      let mut keys = Vec::new();
+      for arg in args {
+        match arg {
+          Value::Str(s) if !s.is_empty() => keys.push(s),
+          _ => return Err("docker-env-secret arguments must be non-empty strings".to_string()),
+        }
+      }
+      ctx.secret_vars.extend(keys);
+
+      Ok(Value::Str(format!("{} secret variables registered", ctx.secret_vars.len())))
+    },
+  );
+
+  // Register docker-timeout command
+  registry.register_closure_with_help_and_tag(
+    "docker-timeout",
+    "Set the maximum run time in seconds for Docker commands (0 disables)",
+    "(docker-timeout seconds)",
+    "  (docker-timeout 300)  ; Kill the Docker command after 5 minutes\n  (docker-timeout 0)    ; No timeout (default)",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-timeout", "configuring Docker timeout");
+
+      if args.len() != 1 {
+        return Err("docker-timeout requires exactly one argument (seconds)".to_string());
+      }
+
+      match &args[0] {
+        Value::Int(secs) if *secs >= 0 => {
+          ctx.set_variable("docker_timeout".to_string(), Value::Int(*secs));
+          debug_log(ctx, LogLevel::Debug, "docker-timeout", &format!("Docker timeout set to: {}s", secs));
+          Ok(Value::Str(format!("Docker timeout set to: {} seconds", secs)))
+        },
+        _ => Err("docker-timeout argument must be a non-negative integer".to_string()),
+      }
+    },
+  );
+
   // Register docker-reset command
   registry.register_closure_with_help_and_tag(
     "docker-reset",
@@ -577,7 +1268,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-reset)  ; Reset all Docker configuration to defaults",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-reset", "resetting Docker configuration to defaults");
+      debug_log(ctx, LogLevel::Debug, "docker-reset", "resetting Docker configuration to defaults");
 
       if !args.is_empty() {
         return Err("docker-reset takes no arguments".to_string());
@@ -589,8 +1280,18 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       ctx.set_variable("docker_socket_path".to_string(), Value::Nil);
       ctx.set_variable("docker_pre_hooks".to_string(), Value::Nil);
       ctx.set_variable("docker_post_hooks".to_string(), Value::Nil);
-
-      debug_log(ctx, "docker-reset", "Docker configuration reset to defaults");
+      ctx.set_variable("docker_post_failure_hooks".to_string(), Value::Nil);
+      ctx.set_variable("docker_pre_hook_policy".to_string(), Value::Nil);
+      ctx.set_variable("docker_timeout".to_string(), Value::Nil);
+      ctx.set_variable("docker_workdir".to_string(), Value::Nil);
+      ctx.set_variable("docker_strict".to_string(), Value::Nil);
+      ctx.set_variable("docker_denied_args".to_string(), Value::Nil);
+      ctx.set_variable("docker_allowed_args".to_string(), Value::Nil);
+      ctx.set_variable("docker_env_allow".to_string(), Value::Nil);
+      ctx.set_variable("docker_env_deny".to_string(), Value::Nil);
+      ctx.secret_vars.clear();
+
+      debug_log(ctx, LogLevel::Debug, "docker-reset", "Docker configuration reset to defaults");
       Ok(Value::Str("Docker configuration reset to defaults".to_string()))
     },
   );
@@ -603,7 +1304,7 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
     "  (docker-show-config)  ; Display current Docker configuration",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "docker-show-config", "showing Docker configuration");
+      debug_log(ctx, LogLevel::Debug, "docker-show-config", "showing Docker configuration");
 
       if !args.is_empty() {
         return Err("docker-show-config takes no arguments".to_string());
@@ -618,9 +1319,21 @@ pub fn register_docker_command(registry: &mut CommandRegistry) {
       output.push_str(&format!("Socket path: {:?}\n", config.socket_path));
       output.push_str(&format!("Pre-commands: {:?}\n", config.pre_commands));
       output.push_str(&format!("Post-commands: {:?}\n", config.post_commands));
+      output.push_str(&format!("Post-failure commands: {:?}\n", config.post_failure_commands));
+      output.push_str(&format!("Abort on pre-command failure: {}\n", config.abort_on_pre_failure));
+      output.push_str(&format!("Timeout: {:?}\n", config.timeout));
+      output.push_str(&format!("Working directory: {:?}\n", config.workdir));
+      output.push_str(&format!("Strict mode: {}\n", config.strict));
+      output.push_str(&format!("Denied args: {:?}\n", config.denied_args));
+      output.push_str(&format!("Allowed args: {:?}\n", config.allowed_args));
+      output.push_str(&format!("Env allowlist: {:?}\n", config.env_allow));
+      output.push_str(&format!("Env denylist: {:?}\n", config.env_deny));
+      let mut secrets: Vec<&String> = ctx.secret_vars.iter().collect();
+      secrets.sort();
+      output.push_str(&format!("Env secrets: {:?}\n", secrets));
       output.push_str("============================");
 
-      println!("{}", output);
+      eprintln!("{}", output);
       Ok(Value::Str(output))
     },
   );
@@ -647,13 +1360,13 @@ fn execute_docker_command_internal(
       format!("{}:{}", DOCKER_SOCKET_PATH, DOCKER_SOCKET_PATH);
     command.args(&["-v", &docker_socket]);
     if verbose {
-      println!("Docker Socket mapping: {}", docker_socket);
+      eprintln!("Docker Socket mapping: {}", docker_socket);
     }
   } else {
     // Controlla se esiste la variabile DOCKER_HOST nel file .env
     if let Some(docker_host_map) = existing_env_vars.get(ENV_DOCKER_HOST_MAP) {
       if verbose {
-        println!(
+        eprintln!(
           "Utilizzo DOCKER_HOST_MAP dal file .env: {}",
           docker_host_map
         );
@@ -685,7 +1398,7 @@ fn execute_docker_command_internal(
         format!("{}:{}", docker_socket_path, DOCKER_SOCKET_PATH);
       command.args(&["-v", &*docker_socket]);
       if verbose {
-        println!("Docker Socket mapping: {}", docker_socket);
+        eprintln!("Docker Socket mapping: {}", docker_socket);
       }
     };
   }
@@ -694,7 +1407,7 @@ fn execute_docker_command_internal(
   for (key, value) in env_vars {
     command.env(key, value);
     if verbose {
-      println!("* env key: {} = {}", key, value);
+      eprintln!("* env key: {} = {}", key, ctx.display_value(key, value));
     }
   }
 
@@ -717,11 +1430,11 @@ fn execute_docker_command_internal(
 
   // Stampa del comando completo (per il debug)
   if verbose {
-    println!("Eseguendo il comando: {:?}", command);
+    eprintln!("Eseguendo il comando: {}", describe_command(&command, ctx));
   }
 
   // Esegue il comando Docker
-  let status = command.status()?;
+  let status = status_tracked(&mut command)?;
 
   if !status.success() {
     eprintln!("{}", MSG_DOCKER_COMMAND_FAILED);
@@ -802,6 +1515,10 @@ mod tests {
     assert_eq!(config.socket_path, None);
     assert!(config.pre_commands.is_empty());
     assert!(config.post_commands.is_empty());
+    assert!(config.post_failure_commands.is_empty());
+    assert!(config.abort_on_pre_failure);
+    assert_eq!(config.timeout, None);
+    assert_eq!(config.workdir, None);
   }
 
   #[test]
@@ -1056,4 +1773,341 @@ mod tests {
     assert_eq!(config_nil.compose_args, DOCKER_COMPOSE_ARGS.iter().map(|s| s.to_string()).collect::<Vec<String>>());
     assert_eq!(config_nil.socket_path, None);
   }
+
+  #[test]
+  fn test_docker_post_on_failure_command() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![
+      Value::Str("echo".to_string()),
+      Value::Str("Docker failed".to_string()),
+    ];
+    let result = ctx.registry.get("docker-post-on-failure").unwrap().execute(args, &mut ctx);
+    assert!(result.is_ok());
+
+    let args = vec![Value::Str("docker".to_string()), Value::Str("compose".to_string()), Value::Str("logs".to_string())];
+    ctx.registry.get("docker-post-on-failure").unwrap().execute(args, &mut ctx).unwrap();
+
+    // Verify configuration was set and kept separate from regular post-hooks
+    let config = build_docker_config(&ctx);
+    assert!(config.post_commands.is_empty());
+    assert_eq!(config.post_failure_commands.len(), 2);
+    assert_eq!(config.post_failure_commands[0], vec!["echo", "Docker failed"]);
+    assert_eq!(config.post_failure_commands[1], vec!["docker", "compose", "logs"]);
+
+    // Reset clears the failure hooks
+    ctx.registry.get("docker-reset").unwrap().execute(vec![], &mut ctx).unwrap();
+    let config = build_docker_config(&ctx);
+    assert!(config.post_failure_commands.is_empty());
+  }
+
+  #[test]
+  fn test_docker_pre_policy_command() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![Value::Str("continue".to_string())];
+    let result = ctx.registry.get("docker-pre-policy").unwrap().execute(args, &mut ctx);
+    assert!(result.is_ok());
+    assert!(!build_docker_config(&ctx).abort_on_pre_failure);
+
+    let args = vec![Value::Str("abort".to_string())];
+    ctx.registry.get("docker-pre-policy").unwrap().execute(args, &mut ctx).unwrap();
+    assert!(build_docker_config(&ctx).abort_on_pre_failure);
+
+    // Invalid policy is rejected
+    let args = vec![Value::Str("ignore".to_string())];
+    let result = ctx.registry.get("docker-pre-policy").unwrap().execute(args, &mut ctx);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_docker_timeout_command() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![Value::Int(300)];
+    let result = ctx.registry.get("docker-timeout").unwrap().execute(args, &mut ctx);
+    assert!(result.is_ok());
+    assert_eq!(build_docker_config(&ctx).timeout, Some(Duration::from_secs(300)));
+
+    // Zero disables the timeout
+    let args = vec![Value::Int(0)];
+    ctx.registry.get("docker-timeout").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(build_docker_config(&ctx).timeout, None);
+
+    // Negative and non-integer values are rejected
+    let result = ctx.registry.get("docker-timeout").unwrap().execute(vec![Value::Int(-1)], &mut ctx);
+    assert!(result.is_err());
+    let result = ctx.registry.get("docker-timeout").unwrap().execute(vec![Value::Str("10".to_string())], &mut ctx);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_docker_timeout_runs_failure_hooks() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    let dir = std::env::temp_dir().join("dpm_test_docker_timeout_hooks");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![Value::Str("touch".to_string()), Value::Str("failed".to_string())];
+    ctx.registry.get("docker-post-on-failure").unwrap().execute(args, &mut ctx).unwrap();
+    let mut config = build_docker_config(&ctx);
+    config.timeout = Some(Duration::from_millis(100));
+
+    // A process outliving the timeout is killed and the failure hooks still run
+    let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+    let result = wait_docker_child(&ctx, &config, &mut child);
+    assert!(result.unwrap_err().contains("timed out"));
+    assert!(dir.join("failed").exists());
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_docker_capture_invalid_args() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![Value::List(vec![Value::Str("invalid".to_string())])];
+    let result = ctx.registry.get("docker-capture").unwrap().execute(args, &mut ctx);
+    assert_eq!(
+      result.unwrap_err(),
+      "docker-capture arguments must be strings or integers"
+    );
+  }
+
+  #[test]
+  fn test_build_docker_process_shares_configuration() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("docker_compose_args".to_string(), Value::List(vec![Value::Str("compose".to_string()), Value::Str("exec".to_string())]));
+    ctx.set_variable("docker_socket_path".to_string(), Value::Str("/custom/docker.sock".to_string()));
+
+    let config = build_docker_config(&ctx);
+    let mut env_vars = HashMap::new();
+    env_vars.insert("FOO".to_string(), "bar".to_string());
+    let command = build_docker_process(&ctx, &config, &env_vars, &HashMap::new(), &["ls".to_string()], false).unwrap();
+
+    let args: Vec<String> = command.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+    assert_eq!(&args[..2], &["compose", "exec"]);
+    assert!(args.iter().any(|a| a.starts_with("/custom/docker.sock:")) || cfg!(target_os = "windows"));
+    assert!(args.windows(2).any(|w| w[0] == "-e" && w[1] == "FOO"));
+    assert_eq!(args.last().unwrap(), "ls");
+  }
+
+  #[test]
+  fn test_docker_env_secret_masks_printed_values() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("docker_socket_path".to_string(), Value::Str("/custom/docker.sock".to_string()));
+    ctx.registry.get("docker-env-secret").unwrap().execute(vec![Value::Str("DB_PASSWORD".to_string())], &mut ctx).unwrap();
+
+    let config = build_docker_config(&ctx);
+    let mut env_vars = HashMap::new();
+    env_vars.insert("DB_PASSWORD".to_string(), "s3cr3t-value".to_string());
+    env_vars.insert("APP_ENV".to_string(), "staging".to_string());
+    let command = build_docker_process(&ctx, &config, &env_vars, &HashMap::new(), &["ls".to_string()], false).unwrap();
+
+    // The container still receives the real value
+    let passed = command.get_envs().find(|(k, _)| *k == "DB_PASSWORD").and_then(|(_, v)| v).unwrap();
+    assert_eq!(passed, "s3cr3t-value");
+
+    let printed = describe_command(&command, &ctx);
+    assert!(!printed.contains("s3cr3t-value"), "{}", printed);
+    assert!(printed.contains("DB_PASSWORD=\"****\""), "{}", printed);
+    assert!(printed.contains("APP_ENV=\"staging\""), "{}", printed);
+    assert_eq!(ctx.display_value("DB_PASSWORD", "s3cr3t-value"), "****");
+    assert_eq!(ctx.display_value("APP_ENV", "staging"), "staging");
+
+    // Reset forgets the secrets
+    ctx.registry.get("docker-reset").unwrap().execute(vec![], &mut ctx).unwrap();
+    assert_eq!(ctx.display_value("DB_PASSWORD", "s3cr3t-value"), "s3cr3t-value");
+  }
+
+  #[test]
+  fn test_docker_workdir_command() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    let basedir = ctx.get_basedir().clone();
+
+    let args = vec![Value::Str("sub/path".to_string())];
+    let result = ctx.registry.get("docker-workdir").unwrap().execute(args, &mut ctx);
+    assert!(result.is_ok());
+    assert_eq!(build_docker_config(&ctx).workdir, Some(basedir.join("sub/path")));
+
+    // The process runs in the resolved directory
+    let config = build_docker_config(&ctx);
+    let command = build_docker_process(&ctx, &config, &HashMap::new(), &HashMap::new(), &[], false).unwrap();
+    assert_eq!(command.get_current_dir(), Some(basedir.join("sub/path").as_path()));
+
+    // An empty path falls back to basedir
+    let args = vec![Value::Str(String::new())];
+    ctx.registry.get("docker-workdir").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(build_docker_config(&ctx).workdir, None);
+
+    // docker-reset clears the override
+    ctx.registry.get("docker-workdir").unwrap().execute(vec![Value::Str("sub".to_string())], &mut ctx).unwrap();
+    ctx.registry.get("docker-reset").unwrap().execute(vec![], &mut ctx).unwrap();
+    assert_eq!(build_docker_config(&ctx).workdir, None);
+
+    let result = ctx.registry.get("docker-workdir").unwrap().execute(vec![Value::Int(1)], &mut ctx);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_docker_lifecycle_compose_args() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    for name in ["docker-up", "docker-down", "docker-build"] {
+      assert!(ctx.registry.get(name).is_some());
+    }
+
+    let process_args = |ctx: &Context, subcommand: &[&str], args: &[&str]| -> Vec<String> {
+      let config = build_lifecycle_config(ctx, subcommand);
+      let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+      let command = build_docker_process(ctx, &config, &HashMap::new(), &HashMap::new(), &args, false).unwrap();
+      command.get_args().map(|a| a.to_string_lossy().to_string()).collect()
+    };
+
+    // Default compose args keep only the "compose" prefix
+    assert_eq!(process_args(&ctx, &["up", "-d"], &[]), vec!["compose", "up", "-d"]);
+    assert_eq!(process_args(&ctx, &["down"], &["-v"]), vec!["compose", "down", "-v"]);
+    assert_eq!(process_args(&ctx, &["build"], &["web"]), vec!["compose", "build", "web"]);
+
+    // Compose files configured with docker-compose-args are kept
+    let args = ["compose", "-f", "docker-compose.core.yml", "run", "--rm", "-T"]
+      .iter()
+      .map(|a| Value::Str(a.to_string()))
+      .collect();
+    ctx.registry.get("docker-compose-args").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(
+      process_args(&ctx, &["down"], &[]),
+      vec!["compose", "-f", "docker-compose.core.yml", "down"]
+    );
+
+    // A custom socket is passed through DOCKER_HOST instead of a volume
+    ctx.set_variable("docker_socket_path".to_string(), Value::Str("/custom/docker.sock".to_string()));
+    let config = build_lifecycle_config(&ctx, &["build"]);
+    let command = build_docker_process(&ctx, &config, &HashMap::new(), &HashMap::new(), &[], false).unwrap();
+    let docker_host = command.get_envs().find(|(key, _)| *key == "DOCKER_HOST").and_then(|(_, value)| value);
+    assert_eq!(docker_host, Some(std::ffi::OsStr::new("unix:///custom/docker.sock")));
+    assert!(!command.get_args().any(|a| a == "-v"));
+  }
+
+  #[test]
+  fn test_docker_strict_mode_blocks_and_allows_flags() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    let args = |items: &[&str]| -> Vec<String> { items.iter().map(|s| s.to_string()).collect() };
+
+    // Strict mode is off by default
+    let config = build_docker_config(&ctx);
+    assert!(check_docker_args(&config, &args(&["-v", "/host:/host"])).is_ok());
+
+    ctx.registry.get("docker-strict").unwrap().execute(vec![Value::Bool(true)], &mut ctx).unwrap();
+    let config = build_docker_config(&ctx);
+    assert!(check_docker_args(&config, &args(&["ls", "-la"])).is_ok());
+    let err = check_docker_args(&config, &args(&["-v", "/host:/host", "ls"])).unwrap_err();
+    assert!(err.contains("'-v' is denied"), "{}", err);
+    assert!(check_docker_args(&config, &args(&["-v/host:/host"])).is_err());
+    assert!(check_docker_args(&config, &args(&["--volume=/host:/host"])).is_err());
+    assert!(check_docker_args(&config, &args(&["--network", "host"])).is_err());
+    assert!(check_docker_args(&config, &args(&["--network", "bridge"])).is_ok());
+
+    // Flags after the image, service or command belong to it
+    assert!(check_docker_args(&config, &args(&["grep", "-v", "x"])).is_ok());
+    assert!(check_docker_args(&config, &args(&["--rm", "app", "sh", "--privileged"])).is_ok());
+    assert!(check_docker_args(&config, &args(&["--network", "bridge", "--privileged", "app"])).is_err());
+    assert!(check_docker_args(&config, &args(&["--", "-v", "/host:/host"])).is_ok());
+
+    // docker-allow permits a denied flag
+    ctx.registry.get("docker-allow").unwrap().execute(vec![Value::Str("--network=host".to_string())], &mut ctx).unwrap();
+    let config = build_docker_config(&ctx);
+    assert!(check_docker_args(&config, &args(&["--network", "host"])).is_ok());
+    assert!(check_docker_args(&config, &args(&["--privileged"])).is_err());
+
+    // docker-deny extends the defaults
+    ctx.registry.get("docker-deny").unwrap().execute(vec![Value::Str("--device".to_string())], &mut ctx).unwrap();
+    let config = build_docker_config(&ctx);
+    assert!(check_docker_args(&config, &args(&["--device=/dev/sda"])).is_err());
+    assert!(check_docker_args(&config, &args(&["--privileged"])).is_err());
+
+    // Invalid arguments and reset
+    assert!(ctx.registry.get("docker-strict").unwrap().execute(vec![Value::Str("yes".to_string())], &mut ctx).is_err());
+    assert!(ctx.registry.get("docker-allow").unwrap().execute(vec![], &mut ctx).is_err());
+    ctx.registry.get("docker-reset").unwrap().execute(vec![], &mut ctx).unwrap();
+    let config = build_docker_config(&ctx);
+    assert!(!config.strict);
+    assert!(config.allowed_args.is_empty());
+    assert_eq!(config.denied_args.len(), DOCKER_DENIED_ARGS.len());
+  }
+
+  #[test]
+  fn test_docker_env_allow_and_deny_filter_collected_env() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(std::env::temp_dir().join("dpm_test_docker_env_filter"));
+    for key in ["APP_ENV", "APP_PORT", "DB_PASSWORD"] {
+      ctx.set_variable(key.to_string(), Value::Str(format!("{}-value", key)));
+    }
+    let collected_keys = |ctx: &Context| -> Vec<String> {
+      let (env_vars, _) = collect_docker_env(ctx, &build_docker_config(ctx));
+      let mut keys: Vec<String> = env_vars.into_keys().filter(|k| k.starts_with("APP_") || k.starts_with("DB_")).collect();
+      keys.sort();
+      keys
+    };
+
+    // No filters: every string variable is forwarded
+    assert_eq!(collected_keys(&ctx), vec!["APP_ENV", "APP_PORT", "DB_PASSWORD"]);
+
+    ctx.registry.get("docker-env-deny").unwrap().execute(vec![Value::Str("DB_PASSWORD".to_string())], &mut ctx).unwrap();
+    assert_eq!(collected_keys(&ctx), vec!["APP_ENV", "APP_PORT"]);
+
+    // Only allowed keys appear, and the denylist still applies
+    let allow = vec![Value::Str("APP_ENV".to_string()), Value::Str("DB_PASSWORD".to_string())];
+    ctx.registry.get("docker-env-allow").unwrap().execute(allow, &mut ctx).unwrap();
+    let (env_vars, _) = collect_docker_env(&ctx, &build_docker_config(&ctx));
+    assert_eq!(env_vars.len(), 1);
+    assert_eq!(env_vars.get("APP_ENV"), Some(&"APP_ENV-value".to_string()));
+
+    ctx.registry.get("docker-reset").unwrap().execute(vec![], &mut ctx).unwrap();
+    assert_eq!(collected_keys(&ctx), vec!["APP_ENV", "APP_PORT", "DB_PASSWORD"]);
+  }
+
+  #[test]
+  fn test_docker_available_returns_bool() {
+    let mut registry = CommandRegistry::new();
+    register_docker_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    // Docker may or may not be installed; either way the result is a boolean
+    let result = ctx.registry.get("docker-available").unwrap().execute(vec![], &mut ctx).unwrap();
+    assert!(matches!(result, Value::Bool(_)));
+
+    // A missing socket is reported as unavailable rather than as an error
+    ctx.set_variable("docker_socket_path".to_string(), Value::Str("/nonexistent/docker.sock".to_string()));
+    let config = build_docker_config(&ctx);
+    let command = build_availability_process(&config);
+    let docker_host = command.get_envs().find(|(key, _)| *key == "DOCKER_HOST").and_then(|(_, value)| value);
+    assert_eq!(docker_host, Some(std::ffi::OsStr::new("unix:///nonexistent/docker.sock")));
+    let result = ctx.registry.get("docker-available").unwrap().execute(vec![], &mut ctx).unwrap();
+    assert_eq!(result, Value::Bool(false));
+
+    assert!(ctx.registry.get("docker-available").unwrap().execute(vec![Value::Int(1)], &mut ctx).is_err());
+  }
 }
@@ -0,0 +1,227 @@
+use crate::context::LogLevel;
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Value, tags};
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// A single `.dockerignore` rule
+struct IgnoreRule {
+  regex: Regex,
+  /// `!pattern`: re-include paths excluded by earlier rules
+  negated: bool,
+}
+
+/// Translates a `.dockerignore` pattern into an anchored regex
+/// `*` and `?` stop at `/`, `**` spans any number of directories and
+/// `[...]` character classes (with `[!...]` negation) are kept
+fn pattern_to_regex(pattern: &str) -> Result<Regex, String> {
+  let chars: Vec<char> = pattern.chars().collect();
+  let mut regex = String::from("^");
+  let mut i = 0;
+
+  while i < chars.len() {
+    match chars[i] {
+      '*' if chars.get(i + 1) == Some(&'*') => {
+        if chars.get(i + 2) == Some(&'/') {
+          regex.push_str("(?:.*/)?");
+          i += 3;
+        } else {
+          regex.push_str(".*");
+          i += 2;
+        }
+        continue;
+      }
+      '*' => regex.push_str("[^/]*"),
+      '?' => regex.push_str("[^/]"),
+      '[' => match chars[i..].iter().position(|&c| c == ']') {
+        Some(len) if len > 1 => {
+          let class: String = chars[i + 1..i + len].iter().collect();
+          let class = class.strip_prefix('!').map(|c| format!("^{}", c)).unwrap_or(class);
+          regex.push('[');
+          regex.push_str(&class.replace('\\', "\\\\"));
+          regex.push(']');
+          i += len + 1;
+          continue;
+        }
+        _ => regex.push_str("\\["),
+      },
+      '\\' if i + 1 < chars.len() => {
+        regex.push_str(&regex::escape(&chars[i + 1].to_string()));
+        i += 2;
+        continue;
+      }
+      c => regex.push_str(&regex::escape(&c.to_string())),
+    }
+    i += 1;
+  }
+
+  regex.push('$');
+  Regex::new(&regex).map_err(|e| format!("invalid .dockerignore pattern '{}': {}", pattern, e))
+}
+
+/// Parses `.dockerignore` content: comments and blank lines are skipped,
+/// leading `/` and `./` are ignored and `!` marks an exception
+fn parse_dockerignore(content: &str) -> Result<Vec<IgnoreRule>, String> {
+  let mut rules = Vec::new();
+  for line in content.lines() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+
+    let (negated, pattern) = match line.strip_prefix('!') {
+      Some(rest) => (true, rest.trim()),
+      None => (false, line),
+    };
+    let pattern = pattern.trim_start_matches("./").trim_start_matches('/').trim_end_matches('/');
+    if pattern.is_empty() {
+      continue;
+    }
+
+    rules.push(IgnoreRule {
+      regex: pattern_to_regex(pattern)?,
+      negated,
+    });
+  }
+  Ok(rules)
+}
+
+/// Whether `path` (relative, `/`-separated) is excluded from the build context
+/// A rule matches a path or any of its parent directories; the last matching rule wins
+fn is_ignored(rules: &[IgnoreRule], path: &str) -> bool {
+  let mut candidates = vec![path];
+  candidates.extend(path.match_indices('/').map(|(index, _)| &path[..index]));
+
+  let mut ignored = false;
+  for rule in rules {
+    if candidates.iter().any(|candidate| rule.regex.is_match(candidate)) {
+      ignored = !rule.negated;
+    }
+  }
+  ignored
+}
+
+/// Lists the files of `dir` that Docker would send as build context, sorted
+fn docker_context_files(dir: &Path) -> Result<Vec<String>, String> {
+  let ignore_path = dir.join(".dockerignore");
+  let rules = if ignore_path.is_file() {
+    let content = fs::read_to_string(&ignore_path)
+      .map_err(|e| format!("Failed to read {}: {}", ignore_path.display(), e))?;
+    parse_dockerignore(&content)?
+  } else {
+    Vec::new()
+  };
+
+  let mut files = Vec::new();
+  for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
+    if !entry.file_type().is_file() {
+      continue;
+    }
+    let relative = match entry.path().strip_prefix(dir) {
+      Ok(relative) => relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/"),
+      Err(_) => continue,
+    };
+    if !is_ignored(&rules, &relative) {
+      files.push(relative);
+    }
+  }
+
+  files.sort();
+  Ok(files)
+}
+
+/// Register docker-context-files command
+pub fn register_docker_context_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "docker-context-files",
+    "List the files of a directory that Docker would send as build context, honoring its .dockerignore",
+    "(docker-context-files dir)",
+    "  (docker-context-files \"dev/docker/web\")   ; Sorted relative paths, e.g. (\"Dockerfile\" \"src/main.rs\")",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "docker-context-files", "executing docker-context-files command");
+
+      let dir_arg = match args.as_slice() {
+        [Value::Str(dir)] => dir.clone(),
+        [_] => return Err("docker-context-files directory must be a string".to_string()),
+        _ => return Err("docker-context-files expects exactly one argument (directory)".to_string()),
+      };
+
+      let dir = ctx.get_basedir().join(&dir_arg);
+      if !dir.is_dir() {
+        return Err(format!("Directory does not exist: {}", dir.display()));
+      }
+
+      let files = docker_context_files(&dir)?;
+      debug_log(ctx, LogLevel::Info, "docker-context-files", &format!("{} files in context {}", files.len(), dir.display()));
+      Ok(Value::List(files.into_iter().map(Value::Str).collect()))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Context;
+
+  fn ignored(content: &str, path: &str) -> bool {
+    is_ignored(&parse_dockerignore(content).unwrap(), path)
+  }
+
+  #[test]
+  fn test_dockerignore_patterns() {
+    assert!(ignored("*.log", "debug.log"));
+    assert!(!ignored("*.log", "logs/debug.log"));
+    assert!(ignored("**/*.log", "logs/deep/debug.log"));
+    assert!(ignored("**/*.log", "debug.log"));
+    assert!(ignored("node_modules", "node_modules/pkg/index.js"));
+    assert!(ignored("/build/", "build/out.bin"));
+    assert!(ignored("tmp?", "tmp1"));
+    assert!(!ignored("tmp?", "tmp12"));
+    assert!(ignored("file[0-9].txt", "file3.txt"));
+    assert!(!ignored("file[!0-9].txt", "file3.txt"));
+    assert!(!ignored("# comment\n\n", "anything"));
+  }
+
+  #[test]
+  fn test_dockerignore_negation_last_match_wins() {
+    let content = "*.md\n!README.md\ndocs\n";
+    assert!(ignored(content, "CHANGELOG.md"));
+    assert!(!ignored(content, "README.md"));
+    assert!(ignored(content, "docs/README.md"));
+    assert!(ignored("!README.md\n*.md", "README.md"));
+  }
+
+  #[test]
+  fn test_docker_context_files_command() {
+    let dir = std::env::temp_dir().join("dpm_test_docker_context_files");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::create_dir_all(dir.join("logs")).unwrap();
+    fs::write(dir.join(".dockerignore"), "# build context\nlogs\n*.md\n!README.md\n**/*.tmp\n").unwrap();
+    for file in ["Dockerfile", "README.md", "NOTES.md", "src/main.rs", "src/cache.tmp", "logs/app.log"] {
+      fs::write(dir.join(file), file).unwrap();
+    }
+
+    let mut registry = CommandRegistry::new();
+    register_docker_context_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![Value::Str(".".to_string())];
+    let result = ctx.registry.get("docker-context-files").unwrap().execute(args, &mut ctx).unwrap();
+    let expected = [".dockerignore", "Dockerfile", "README.md", "src/main.rs"];
+    assert_eq!(result, Value::List(expected.iter().map(|f| Value::Str(f.to_string())).collect()));
+
+    let args = vec![Value::Str("missing".to_string())];
+    assert!(ctx.registry.get("docker-context-files").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
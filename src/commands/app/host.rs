@@ -0,0 +1,60 @@
+use crate::utils::get_user_ids;
+use crate::{CommandRegistry, Value, tags};
+
+/// Register host-user-info command
+pub fn register_host_user_info_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "host-user-info",
+    "Host user id, group id and name, as used for HOST_UID/HOST_GID/HOST_USER (0, 0 and USERNAME on Windows)",
+    "(host-user-info)",
+    "  (host-user-info)   ; Returns ((\"uid\" 1000) (\"gid\" 1000) (\"user\" \"dev\"))",
+    &tags::COMMANDS,
+    |args, _ctx| {
+      if !args.is_empty() {
+        return Err("host-user-info takes no arguments".to_string());
+      }
+
+      let (uid, gid, user) = get_user_ids();
+      let entry = |name: &str, value: Value| Value::List(vec![Value::Str(name.to_string()), value]);
+      Ok(Value::List(vec![
+        entry("uid", Value::Int(uid as i64)),
+        entry("gid", Value::Int(gid as i64)),
+        entry("user", Value::Str(user)),
+      ]))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Context;
+
+  #[test]
+  fn test_host_user_info_has_three_keys() {
+    let mut registry = CommandRegistry::new();
+    register_host_user_info_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = ctx.registry.get("host-user-info").unwrap().execute(vec![], &mut ctx).unwrap();
+    let entries = match result {
+      Value::List(entries) => entries,
+      other => panic!("unexpected result: {:?}", other),
+    };
+
+    let keys: Vec<String> = entries
+      .iter()
+      .map(|entry| match entry {
+        Value::List(pair) if pair.len() == 2 => pair[0].to_string(),
+        other => panic!("unexpected entry: {:?}", other),
+      })
+      .collect();
+    assert_eq!(keys, vec!["uid", "gid", "user"]);
+
+    let (uid, gid, _) = get_user_ids();
+    assert_eq!(entries[0], Value::List(vec![Value::Str("uid".to_string()), Value::Int(uid as i64)]));
+    assert_eq!(entries[1], Value::List(vec![Value::Str("gid".to_string()), Value::Int(gid as i64)]));
+
+    assert!(ctx.registry.get("host-user-info").unwrap().execute(vec![Value::Nil], &mut ctx).is_err());
+  }
+}
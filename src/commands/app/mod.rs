@@ -1,5 +1,9 @@
 pub mod write_env;
 pub mod version_check;
 pub mod docker;
+pub mod docker_context;
+pub mod host;
+pub mod rebuild;
+pub mod clean;
 
 pub use crate::commands::core::register_app_commands;
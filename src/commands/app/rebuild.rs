@@ -0,0 +1,137 @@
+use crate::context::LogLevel;
+use crate::file_ops::{compute_dir_md5, read_env_file, write_env_file};
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Context, Value, tags};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Key holding the directory checksum in a stamp file
+const STAMP_CHECKSUM_KEY: &str = "CHECKSUM";
+
+/// Resolves the `src-dir` and `stamp-file` arguments against basedir
+fn stamp_paths(command_name: &str, args: &[Value], ctx: &Context) -> Result<(PathBuf, PathBuf), String> {
+  match args {
+    [Value::Str(dir), Value::Str(stamp)] => Ok((ctx.get_basedir().join(dir), ctx.get_basedir().join(stamp))),
+    [_, _] => Err(format!("{} paths must be strings", command_name)),
+    _ => Err(format!("{} expects exactly two arguments (src-dir, stamp-file)", command_name)),
+  }
+}
+
+fn dir_checksum(dir: &Path) -> Result<String, String> {
+  compute_dir_md5(&dir.to_string_lossy())
+    .map_err(|e| format!("Failed to compute checksum of {}: {}", dir.display(), e))
+}
+
+/// Register the needs-rebuild and write-stamp commands
+pub fn register_rebuild_commands(registry: &mut CommandRegistry) {
+  // needs-rebuild command
+  registry.register_closure_with_help_and_tag(
+    "needs-rebuild",
+    "Check whether a directory changed since its checksum was recorded in a stamp file",
+    "(needs-rebuild src-dir stamp-file)",
+    "  (cond ((needs-rebuild \"dev/docker/web\" \".stamps/web\")\n         (docker \"build\" \"dev/docker/web\")))   ; Build only when the inputs changed",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "needs-rebuild", "executing needs-rebuild command");
+
+      let (dir, stamp) = stamp_paths("needs-rebuild", &args, ctx)?;
+      let current = dir_checksum(&dir)?;
+
+      // A missing or unreadable stamp means the directory was never built
+      let recorded = read_env_file(&stamp.to_string_lossy())
+        .ok()
+        .and_then(|mut vars| vars.remove(STAMP_CHECKSUM_KEY));
+      let changed = recorded.as_deref() != Some(current.as_str());
+
+      debug_log(
+        ctx,
+        LogLevel::Info,
+        "needs-rebuild",
+        &format!("{}: current {}, recorded {}", dir.display(), current, recorded.as_deref().unwrap_or("none")),
+      );
+
+      Ok(Value::Bool(changed))
+    },
+  );
+
+  // write-stamp command
+  registry.register_closure_with_help_and_tag(
+    "write-stamp",
+    "Record the current checksum of a directory in a stamp file",
+    "(write-stamp src-dir stamp-file)",
+    "  (write-stamp \"dev/docker/web\" \".stamps/web\")   ; Call after a successful build",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "write-stamp", "executing write-stamp command");
+
+      let (dir, stamp) = stamp_paths("write-stamp", &args, ctx)?;
+      let current = dir_checksum(&dir)?;
+
+      if let Some(parent) = stamp.parent() {
+        fs::create_dir_all(parent)
+          .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+      }
+      let vars = HashMap::from([(STAMP_CHECKSUM_KEY.to_string(), current.clone())]);
+      write_env_file(&stamp.to_string_lossy(), &vars)
+        .map_err(|e| format!("Failed to write stamp file {}: {}", stamp.display(), e))?;
+
+      debug_log(ctx, LogLevel::Info, "write-stamp", &format!("{} stamped with {}", dir.display(), current));
+
+      Ok(Value::Str(current))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn run(ctx: &mut Context, name: &str) -> Result<Value, String> {
+    let args = vec![Value::Str("src".to_string()), Value::Str("stamps/src.stamp".to_string())];
+    ctx.registry.get(name).unwrap().execute(args, ctx)
+  }
+
+  #[test]
+  fn test_needs_rebuild_with_stamp() {
+    let dir = PathBuf::from("target").join("test_needs_rebuild");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("src/Dockerfile"), "FROM alpine\n").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_rebuild_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(dir.clone());
+
+    // Missing stamp
+    assert_eq!(run(&mut ctx, "needs-rebuild").unwrap(), Value::Bool(true));
+
+    // Unchanged
+    let checksum = run(&mut ctx, "write-stamp").unwrap();
+    assert_eq!(
+      fs::read_to_string(dir.join("stamps/src.stamp")).unwrap(),
+      format!("CHECKSUM={}\n", checksum)
+    );
+    assert_eq!(run(&mut ctx, "needs-rebuild").unwrap(), Value::Bool(false));
+
+    // Changed
+    fs::write(dir.join("src/Dockerfile"), "FROM debian\n").unwrap();
+    assert_eq!(run(&mut ctx, "needs-rebuild").unwrap(), Value::Bool(true));
+    run(&mut ctx, "write-stamp").unwrap();
+    assert_eq!(run(&mut ctx, "needs-rebuild").unwrap(), Value::Bool(false));
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_needs_rebuild_requires_directory() {
+    let mut registry = CommandRegistry::new();
+    register_rebuild_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(PathBuf::from("target").join("test_needs_rebuild_missing"));
+
+    assert!(run(&mut ctx, "needs-rebuild").is_err());
+    assert!(run(&mut ctx, "write-stamp").is_err());
+  }
+}
@@ -1,134 +1,356 @@
-use crate::file_ops::{compute_dir_md5, read_env_file, write_env_file};
+use crate::file_ops::{compute_dir_md5, compute_dir_md5_full, read_env_file, write_env_file};
 use crate::context::VersionInfo;
+use crate::context::LogLevel;
 use crate::utils::debug_log;
-use crate::{CommandRegistry, Value, tags};
+use crate::{CommandRegistry, Context, Value, tags};
 use std::fs;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Version bump level applied to components whose checksum changed
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BumpLevel {
+  Major,
+  Minor,
+  Patch,
+}
 
-/// Register version-check command
-pub fn register_version_check_command(registry: &mut CommandRegistry) {
-  registry.register_closure_with_help_and_tag(
-    "version-check",
-    "Process subdirectories and create version check data structure",
-    "(version-check path)",
-    "  (version-check \"docker\")        ; Process subdirectories in docker folder\n  (version-check \"configs\")       ; Process subdirectories in configs folder",
-    &tags::COMMANDS,
-    |args, ctx| {
-      debug_log(ctx, "version-check", "executing version-check command");
+impl BumpLevel {
+  fn parse(level: &str) -> Option<Self> {
+    match level {
+      "major" => Some(BumpLevel::Major),
+      "minor" => Some(BumpLevel::Minor),
+      "patch" => Some(BumpLevel::Patch),
+      _ => None,
+    }
+  }
+}
 
-      if args.len() != 1 {
-        return Err("version-check expects exactly one argument (path)".to_string());
+/// Length of the checksum stored in `*_CHECKSUM` entries
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ChecksumMode {
+  /// First 8 characters of the MD5 (default)
+  Short,
+  /// Full 32-character MD5
+  Full,
+}
+
+impl ChecksumMode {
+  fn parse(mode: &str) -> Option<Self> {
+    match mode {
+      "short" => Some(ChecksumMode::Short),
+      "full" => Some(ChecksumMode::Full),
+      _ => None,
+    }
+  }
+}
+
+/// Parses the optional arguments following the path: a bump level and/or
+/// a checksum mode, in any order
+fn parse_version_options(command_name: &str, args: &[Value]) -> Result<(BumpLevel, ChecksumMode), String> {
+  let usage = format!(
+    "{} expects a path, an optional bump level (major, minor or patch) and an optional checksum mode (short or full)",
+    command_name
+  );
+  if args.is_empty() || args.len() > 3 {
+    return Err(usage);
+  }
+
+  let mut bump_level = None;
+  let mut checksum_mode = None;
+  for arg in &args[1..] {
+    match arg {
+      Value::Str(option) => {
+        if let Some(level) = BumpLevel::parse(option) {
+          if bump_level.replace(level).is_some() {
+            return Err(usage);
+          }
+        } else if let Some(mode) = ChecksumMode::parse(option) {
+          if checksum_mode.replace(mode).is_some() {
+            return Err(usage);
+          }
+        } else {
+          return Err(format!(
+            "{} option must be a bump level (major, minor or patch) or a checksum mode (short or full), got: {}",
+            command_name, option
+          ));
+        }
       }
+      _ => return Err(format!("{} options must be strings", command_name)),
+    }
+  }
 
-      let path_arg = match &args[0] {
-        Value::Str(s) => s.clone(),
-        _ => return Err("version-check path must be a string".to_string()),
-      };
+  Ok((bump_level.unwrap_or(BumpLevel::Patch), checksum_mode.unwrap_or(ChecksumMode::Short)))
+}
+
+/// Compares checksums like-for-like: a short checksum matches a full one
+/// when it is its prefix, so switching checksum mode does not count as a change
+fn checksums_match(stored: &str, current: &str) -> bool {
+  if stored.is_empty() || current.is_empty() {
+    return stored == current;
+  }
+  if stored.len() <= current.len() {
+    current.starts_with(stored)
+  } else {
+    stored.starts_with(current)
+  }
+}
+
+/// MAJOR.MINOR.PATCH version stored in `*_VERSION` entries
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SemanticVersion {
+  major: u32,
+  minor: u32,
+  patch: u32,
+}
+
+impl SemanticVersion {
+  /// Parses a semver string; a plain integer (the previous format) is read
+  /// as the patch number with major and minor set to 0
+  fn parse(version: &str) -> Option<Self> {
+    let parts: Vec<&str> = version.trim().split('.').collect();
+    match parts.as_slice() {
+      [patch] => Some(SemanticVersion { major: 0, minor: 0, patch: patch.parse().ok()? }),
+      [major, minor, patch] => Some(SemanticVersion {
+        major: major.parse().ok()?,
+        minor: minor.parse().ok()?,
+        patch: patch.parse().ok()?,
+      }),
+      _ => None,
+    }
+  }
+
+  fn bump(self, level: BumpLevel) -> Self {
+    match level {
+      BumpLevel::Major => SemanticVersion { major: self.major + 1, minor: 0, patch: 0 },
+      BumpLevel::Minor => SemanticVersion { major: self.major, minor: self.minor + 1, patch: 0 },
+      BumpLevel::Patch => SemanticVersion { patch: self.patch + 1, ..self },
+    }
+  }
+}
+
+impl std::fmt::Display for SemanticVersion {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+  }
+}
+
+/// Version assigned to components seen for the first time
+const INITIAL_VERSION: SemanticVersion = SemanticVersion { major: 0, minor: 0, patch: 1 };
+
+/// Result of comparing a component's current checksum against stored data
+#[derive(Debug, Clone, PartialEq)]
+struct VersionComparison {
+  /// Stored version, None for components not yet tracked
+  old_version: Option<SemanticVersion>,
+  /// Version after applying the bump (unchanged if the checksum matches)
+  new_version: SemanticVersion,
+  /// Checksum stored in versions.properties (empty if none)
+  stored_checksum: String,
+  /// Whether the checksum differs from the stored one
+  changed: bool,
+}
+
+/// Variable name used for a versioned directory: uppercase with
+/// non-alphanumeric chars replaced by underscore (`my-dir` -> `MY_DIR`)
+pub fn normalize_var_name(name: &str) -> String {
+  name
+    .to_uppercase()
+    .chars()
+    .map(|c| if c.is_alphanumeric() { c } else { '_' })
+    .collect()
+}
 
-      debug_log(ctx, "version-check", &format!("processing path argument: {}", path_arg));
+/// Resolves a version-check path relative to basedir and checks it is a directory
+fn resolve_version_dir(ctx: &Context, path_arg: &str) -> Result<PathBuf, String> {
+  // Resolve path relative to basedir
+  let version_check_base_dir = ctx.get_basedir().join(path_arg);
 
-      // Resolve path relative to basedir
-      let basedir = ctx.get_basedir();
-      let version_check_base_dir = basedir.join(&path_arg);
+  // Check if directory exists
+  if !version_check_base_dir.exists() {
+    return Err(format!("Directory does not exist: {}", version_check_base_dir.display()));
+  }
 
-      debug_log(ctx, "version-check", &format!("resolved path: {}", version_check_base_dir.display()));
+  if !version_check_base_dir.is_dir() {
+    return Err(format!("Path is not a directory: {}", version_check_base_dir.display()));
+  }
 
-      // Check if directory exists
-      if !version_check_base_dir.exists() {
-        return Err(format!("Directory does not exist: {}", version_check_base_dir.display()));
+  Ok(version_check_base_dir)
+}
+
+/// Computes version data for every subdirectory of `base_dir`
+/// Entries that cannot be read or checksummed are skipped
+fn scan_version_dirs(ctx: &Context, command_name: &str, base_dir: &Path, checksum_mode: ChecksumMode) -> Result<Vec<VersionInfo>, String> {
+  // Read subdirectories
+  let entries = match fs::read_dir(base_dir) {
+    Ok(entries) => entries,
+    Err(e) => return Err(format!("Failed to read directory {}: {}", base_dir.display(), e)),
+  };
+
+  debug_log(ctx, LogLevel::Debug, command_name, "processing subdirectories");
+
+  let mut infos = Vec::new();
+
+  for entry in entries {
+    let entry = match entry {
+      Ok(entry) => entry,
+      Err(e) => {
+        debug_log(ctx, LogLevel::Error, command_name, &format!("skipping entry due to error: {}", e));
+        continue;
       }
+    };
+
+    let entry_path = entry.path();
+
+    // Only process directories
+    if !entry_path.is_dir() {
+      debug_log(ctx, LogLevel::Debug, command_name, &format!("skipping non-directory: {}", entry_path.display()));
+      continue;
+    }
 
-      if !version_check_base_dir.is_dir() {
-        return Err(format!("Path is not a directory: {}", version_check_base_dir.display()));
+    let real_name = match entry.file_name().to_str() {
+      Some(name) => name.to_string(),
+      None => {
+        debug_log(ctx, LogLevel::Error, command_name, &format!("skipping directory with invalid name: {}", entry_path.display()));
+        continue;
       }
+    };
+
+    debug_log(ctx, LogLevel::Debug, command_name, &format!("processing directory: {}", real_name));
+
+    let v_name = normalize_var_name(&real_name);
+
+    // Calculate checksum
+    let checksum_result = match checksum_mode {
+      ChecksumMode::Short => compute_dir_md5(&entry_path.to_string_lossy()),
+      ChecksumMode::Full => compute_dir_md5_full(&entry_path.to_string_lossy()),
+    };
+    let checksum = match checksum_result {
+      Ok(checksum) => checksum,
+      Err(e) => {
+        debug_log(ctx, LogLevel::Error, command_name, &format!("failed to compute checksum for {}: {}", real_name, e));
+        continue;
+      }
+    };
 
-      // Read subdirectories
-      let entries = match fs::read_dir(&version_check_base_dir) {
-        Ok(entries) => entries,
-        Err(e) => return Err(format!("Failed to read directory {}: {}", version_check_base_dir.display(), e)),
-      };
+    debug_log(ctx, LogLevel::Debug, command_name, &format!("computed data for {}: v_name={}, checksum={}", real_name, v_name, checksum));
 
-      debug_log(ctx, "version-check", "processing subdirectories");
+    infos.push(VersionInfo {
+      v_name,
+      real_name,
+      checksum,
+    });
+  }
 
-      let mut processed_count = 0;
+  Ok(infos)
+}
 
-      for entry in entries {
-        let entry = match entry {
-          Ok(entry) => entry,
-          Err(e) => {
-            debug_log(ctx, "version-check", &format!("skipping entry due to error: {}", e));
-            continue;
-          }
-        };
+/// Reads versions.properties, returning an empty map if it is missing or unreadable
+fn read_stored_versions(ctx: &Context, command_name: &str, versions_file_path: &Path) -> HashMap<String, String> {
+  if versions_file_path.exists() {
+    debug_log(ctx, LogLevel::Debug, command_name, "reading existing versions.properties file");
+    match read_env_file(&versions_file_path.to_string_lossy()) {
+      Ok(versions) => versions,
+      Err(e) => {
+        debug_log(ctx, LogLevel::Error, command_name, &format!("failed to read versions.properties: {}", e));
+        HashMap::new()
+      }
+    }
+  } else {
+    debug_log(ctx, LogLevel::Debug, command_name, "versions.properties file does not exist, starting fresh");
+    HashMap::new()
+  }
+}
 
-        let entry_path = entry.path();
+/// Compares a component's current checksum with the stored version data and
+/// computes the version it should get, bumping at `bump_level` on change
+fn compare_version(
+  existing_versions: &HashMap<String, String>,
+  v_name: &str,
+  current_checksum: &str,
+  bump_level: BumpLevel,
+) -> VersionComparison {
+  // Parse existing version and checksum entries (separate keys)
+  let version_key = format!("{}_VERSION", v_name);
+  let checksum_key = format!("{}_CHECKSUM", v_name);
+
+  // Old format (version.checksum) is still read for backward compatibility
+  let legacy_entry = existing_versions
+    .get(v_name)
+    .and_then(|entry| entry.split_once('.'));
+
+  let old_version = if let Some(version_str) = existing_versions.get(&version_key) {
+    Some(SemanticVersion::parse(version_str).unwrap_or(INITIAL_VERSION))
+  } else {
+    legacy_entry.map(|(version_str, _)| SemanticVersion::parse(version_str).unwrap_or(INITIAL_VERSION))
+  };
+
+  let stored_checksum = if let Some(checksum_str) = existing_versions.get(&checksum_key) {
+    checksum_str.clone()
+  } else {
+    legacy_entry.map(|(_, checksum_str)| checksum_str.to_string()).unwrap_or_default()
+  };
+
+  // Check if checksum has changed
+  let changed = !checksums_match(&stored_checksum, current_checksum);
+  let version = old_version.unwrap_or(INITIAL_VERSION);
+  let new_version = if !changed {
+    version
+  } else if stored_checksum.is_empty() {
+    // New element
+    INITIAL_VERSION
+  } else {
+    // Increment version at the requested level
+    version.bump(bump_level)
+  };
+
+  VersionComparison {
+    old_version,
+    new_version,
+    stored_checksum,
+    changed,
+  }
+}
 
-        // Only process directories
-        if !entry_path.is_dir() {
-          debug_log(ctx, "version-check", &format!("skipping non-directory: {}", entry_path.display()));
-          continue;
-        }
+/// Register version-check command
+pub fn register_version_check_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "version-check",
+    "Process subdirectories and create version check data structure",
+    "(version-check path [major|minor|patch] [short|full])",
+    "  (version-check \"docker\")          ; Process subdirectories in docker folder, bumping patch on change\n  (version-check \"configs\")         ; Process subdirectories in configs folder\n  (version-check \"docker\" \"minor\")  ; Bump minor version of changed components\n  (version-check \"docker\" \"full\")   ; Store full 32-character MD5 checksums",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "version-check", "executing version-check command");
 
-        let real_name = match entry.file_name().to_str() {
-          Some(name) => name.to_string(),
-          None => {
-            debug_log(ctx, "version-check", &format!("skipping directory with invalid name: {}", entry_path.display()));
-            continue;
-          }
-        };
-
-        debug_log(ctx, "version-check", &format!("processing directory: {}", real_name));
-
-        // Create v_name: uppercase with non-alphanumeric chars replaced by underscore
-        let v_name = real_name
-          .to_uppercase()
-          .chars()
-          .map(|c| if c.is_alphanumeric() { c } else { '_' })
-          .collect::<String>();
-
-        // Calculate checksum
-        let checksum = match compute_dir_md5(&entry_path.to_string_lossy()) {
-          Ok(checksum) => checksum,
-          Err(e) => {
-            debug_log(ctx, "version-check", &format!("failed to compute checksum for {}: {}", real_name, e));
-            continue;
-          }
-        };
+      let (bump_level, checksum_mode) = parse_version_options("version-check", &args)?;
 
-        debug_log(ctx, "version-check", &format!("computed data for {}: v_name={}, checksum={}", real_name, v_name, checksum));
+      let path_arg = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("version-check path must be a string".to_string()),
+      };
+
+      debug_log(ctx, LogLevel::Debug, "version-check", &format!("processing path argument: {}", path_arg));
+
+      let version_check_base_dir = resolve_version_dir(ctx, &path_arg)?;
+      debug_log(ctx, LogLevel::Debug, "version-check", &format!("resolved path: {}", version_check_base_dir.display()));
 
-        // Create VersionInfo object
-        let version_info = VersionInfo {
-          v_name: v_name.clone(),
-          real_name: real_name.clone(),
-          checksum: checksum.clone(),
-        };
+      let infos = scan_version_dirs(ctx, "version-check", &version_check_base_dir, checksum_mode)?;
+      let processed_count = infos.len();
 
-        // Store in versions HashMap using v_name as key
-        ctx.set_version(v_name, version_info);
-        processed_count += 1;
+      // Store in versions HashMap using v_name as key
+      for version_info in infos {
+        ctx.set_version(version_info.v_name.clone(), version_info);
       }
 
       // Version tracking functionality
-      debug_log(ctx, "version-check", "starting version tracking");
+      debug_log(ctx, LogLevel::Debug, "version-check", "starting version tracking");
 
       let versions_file_path = version_check_base_dir.join("versions.properties");
-      debug_log(ctx, "version-check", &format!("versions file path: {}", versions_file_path.display()));
+      debug_log(ctx, LogLevel::Debug, "version-check", &format!("versions file path: {}", versions_file_path.display()));
 
       // Read existing versions from versions.properties file if it exists
-      let existing_versions = if versions_file_path.exists() {
-        debug_log(ctx, "version-check", "reading existing versions.properties file");
-        match read_env_file(&versions_file_path.to_string_lossy()) {
-          Ok(versions) => versions,
-          Err(e) => {
-            debug_log(ctx, "version-check", &format!("failed to read versions.properties: {}", e));
-            HashMap::new()
-          }
-        }
-      } else {
-        debug_log(ctx, "version-check", "versions.properties file does not exist, starting fresh");
-        HashMap::new()
-      };
+      let existing_versions = read_stored_versions(ctx, "version-check", &versions_file_path);
 
       // Prepare updated versions data
       let mut updated_versions = HashMap::new();
@@ -137,80 +359,42 @@ pub fn register_version_check_command(registry: &mut CommandRegistry) {
       // Process each versioned element
       for (v_name, version_info) in ctx.get_all_versions() {
         let current_checksum = &version_info.checksum;
+        let comparison = compare_version(&existing_versions, v_name, current_checksum, bump_level);
 
-        // Parse existing version and checksum entries (separate keys)
-        let version_key = format!("{}_VERSION", v_name);
-        let checksum_key = format!("{}_CHECKSUM", v_name);
-
-        let version_number = if let Some(version_str) = existing_versions.get(&version_key) {
-          version_str.parse::<u32>().unwrap_or(1)
-        } else {
-          // Check for old format (version.checksum) for backward compatibility
-          if let Some(existing_entry) = existing_versions.get(v_name) {
-            if let Some(dot_pos) = existing_entry.find('.') {
-              let version_str = &existing_entry[..dot_pos];
-              version_str.parse::<u32>().unwrap_or(1)
-            } else {
-              1
-            }
-          } else {
-            1
-          }
-        };
-
-        let stored_checksum = if let Some(checksum_str) = existing_versions.get(&checksum_key) {
-          checksum_str.clone()
-        } else {
-          // Check for old format (version.checksum) for backward compatibility
-          if let Some(existing_entry) = existing_versions.get(v_name) {
-            if let Some(dot_pos) = existing_entry.find('.') {
-              let checksum_str = &existing_entry[dot_pos + 1..];
-              checksum_str.to_string()
-            } else {
-              String::new()
-            }
-          } else {
-            String::new()
-          }
-        };
-
-        // Check if checksum has changed
-        let new_version_number = if stored_checksum != *current_checksum {
-          debug_log(ctx, "version-check", &format!("checksum changed for {}: {} -> {}", v_name, stored_checksum, current_checksum));
+        if comparison.changed {
+          debug_log(ctx, LogLevel::Debug, "version-check", &format!("checksum changed for {}: {} -> {}", v_name, comparison.stored_checksum, current_checksum));
           version_changes += 1;
-          if stored_checksum.is_empty() {
-            // New element
-            1
-          } else {
-            // Increment version
-            version_number + 1
-          }
         } else {
-          debug_log(ctx, "version-check", &format!("checksum unchanged for {}: {}", v_name, current_checksum));
-          version_number
-        };
+          debug_log(ctx, LogLevel::Debug, "version-check", &format!("checksum unchanged for {}: {}", v_name, current_checksum));
+        }
 
         // Store updated version and checksum entries (separate keys)
         let version_key = format!("{}_VERSION", v_name);
         let checksum_key = format!("{}_CHECKSUM", v_name);
-        updated_versions.insert(version_key, new_version_number.to_string());
+        updated_versions.insert(version_key, comparison.new_version.to_string());
         updated_versions.insert(checksum_key, current_checksum.clone());
 
-        debug_log(ctx, "version-check", &format!("version entry for {}: version={}, checksum={}", v_name, new_version_number, current_checksum));
+        debug_log(ctx, LogLevel::Debug, "version-check", &format!("version entry for {}: version={}, checksum={}", v_name, comparison.new_version, current_checksum));
       }
 
       // Write updated versions.properties file
-      debug_log(ctx, "version-check", &format!("writing versions.properties with {} entries ({} elements with version and checksum)", updated_versions.len(), updated_versions.len() / 2));
+      debug_log(ctx, LogLevel::Debug, "version-check", &format!("writing versions.properties with {} entries ({} elements with version and checksum)", updated_versions.len(), updated_versions.len() / 2));
       match write_env_file(&versions_file_path.to_string_lossy(), &updated_versions) {
         Ok(_) => {
-          debug_log(ctx, "version-check", "successfully wrote versions.properties file");
+          debug_log(ctx, LogLevel::Info, "version-check", "successfully wrote versions.properties file");
         }
         Err(e) => {
-          debug_log(ctx, "version-check", &format!("failed to write versions.properties: {}", e));
+          debug_log(ctx, LogLevel::Error, "version-check", &format!("failed to write versions.properties: {}", e));
           return Err(format!("Failed to write versions.properties file: {}", e));
         }
       }
 
+      // Expose <VNAME>_VERSION and <VNAME>_CHECKSUM as context variables
+      // so write-env and docker pick them up with the other string variables
+      for (key, value) in updated_versions {
+        ctx.set_variable(key, Value::Str(value));
+      }
+
       let result_msg = format!(
         "Processed {} directories from {} and stored version check data. Version tracking: {} changes detected, versions.properties updated.",
         processed_count,
@@ -218,12 +402,73 @@ pub fn register_version_check_command(registry: &mut CommandRegistry) {
         version_changes
       );
 
-      debug_log(ctx, "version-check", &format!("completed: {}", result_msg));
+      debug_log(ctx, LogLevel::Info, "version-check", &format!("completed: {}", result_msg));
       Ok(Value::Str(result_msg))
     },
   );
 }
 
+/// Register version-report command
+pub fn register_version_report_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "version-report",
+    "Preview version changes of subdirectories without writing versions.properties",
+    "(version-report path [major|minor|patch] [short|full])",
+    "  (version-report \"docker\")          ; List (name old-version new-version changed?) per component\n  (version-report \"docker\" \"minor\")  ; Preview a minor bump",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "version-report", "executing version-report command");
+
+      let (bump_level, checksum_mode) = parse_version_options("version-report", &args)?;
+
+      let path_arg = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("version-report path must be a string".to_string()),
+      };
+
+      let base_dir = resolve_version_dir(ctx, &path_arg)?;
+      let mut infos = scan_version_dirs(ctx, "version-report", &base_dir, checksum_mode)?;
+      infos.sort_by(|a, b| a.v_name.cmp(&b.v_name));
+
+      let existing_versions = read_stored_versions(ctx, "version-report", &base_dir.join("versions.properties"));
+
+      let entries = infos
+        .iter()
+        .map(|info| {
+          let comparison = compare_version(&existing_versions, &info.v_name, &info.checksum, bump_level);
+          Value::List(vec![
+            Value::Str(info.v_name.clone()),
+            comparison.old_version.map(|v| Value::Str(v.to_string())).unwrap_or(Value::Nil),
+            Value::Str(comparison.new_version.to_string()),
+            Value::Bool(comparison.changed),
+          ])
+        })
+        .collect::<Vec<Value>>();
+
+      debug_log(ctx, LogLevel::Debug, "version-report", &format!("reported {} components", entries.len()));
+      Ok(Value::List(entries))
+    },
+  );
+}
+
+/// Register normalize-var-name command
+pub fn register_normalize_var_name_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "normalize-var-name",
+    "Convert a directory name to the variable name used by version-check",
+    "(normalize-var-name name)",
+    "  (normalize-var-name \"my-dir\")   ; Returns \"MY_DIR\", as in ${MY_DIR_VERSION}",
+    &tags::COMMANDS,
+    |args, _ctx| {
+      match args.as_slice() {
+        [Value::Str(name)] => Ok(Value::Str(normalize_var_name(name))),
+        [_] => Err("normalize-var-name argument must be a string".to_string()),
+        _ => Err("normalize-var-name expects exactly one argument (name)".to_string()),
+      }
+    },
+  );
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -299,7 +544,7 @@ mod tests {
     assert!(result.is_err());
     assert_eq!(
       result.unwrap_err(),
-      "version-check expects exactly one argument (path)"
+      "version-check expects a path, an optional bump level (major, minor or patch) and an optional checksum mode (short or full)"
     );
   }
 
@@ -336,7 +581,7 @@ mod tests {
     let mut ctx = Context::new(registry);
     ctx.set_basedir(temp_dir.parent().unwrap().to_path_buf());
 
-    // First run - should create versions.properties with version 1
+    // First run - should create versions.properties with version 0.0.1
     let args = vec![Value::Str("version_tracking_test".to_string())];
     let result1 = ctx
       .registry
@@ -351,7 +596,7 @@ mod tests {
 
     // Read and verify the versions.properties content
     let versions_content = fs::read_to_string(&versions_file).unwrap();
-    assert!(versions_content.contains("TEST_DIR_VERSION=1"), "Should contain TEST_DIR_VERSION with version 1");
+    assert!(versions_content.contains("TEST_DIR_VERSION=0.0.1"), "Should contain TEST_DIR_VERSION with version 0.0.1");
     assert!(versions_content.contains("TEST_DIR_CHECKSUM="), "Should contain TEST_DIR_CHECKSUM");
 
     // Extract the initial checksum
@@ -366,7 +611,7 @@ mod tests {
     // Modify the file to change the checksum
     fs::write(subdir1.join("test.txt"), "modified content").unwrap();
 
-    // Second run - should increment version to 2
+    // Second run - should increment version to 0.0.2
     let result2 = ctx
       .registry
       .get("version-check")
@@ -379,7 +624,7 @@ mod tests {
 
     // Read and verify the updated versions.properties content
     let updated_versions_content = fs::read_to_string(&versions_file).unwrap();
-    assert!(updated_versions_content.contains("TEST_DIR_VERSION=2"), "Should contain TEST_DIR_VERSION with version 2");
+    assert!(updated_versions_content.contains("TEST_DIR_VERSION=0.0.2"), "Should contain TEST_DIR_VERSION with version 0.0.2");
 
     // Extract the new checksum and verify it's different
     let new_checksum = updated_versions_content
@@ -392,7 +637,7 @@ mod tests {
 
     assert_ne!(initial_checksum, new_checksum, "Checksum should be different after file modification");
 
-    // Third run with no changes - should keep version 2
+    // Third run with no changes - should keep version 0.0.2
     let result3 = ctx
       .registry
       .get("version-check")
@@ -403,11 +648,231 @@ mod tests {
     // Check that no changes were detected
     assert!(result3.to_string().contains("0 changes detected"), "Should detect 0 changes");
 
-    // Verify version is still 2
+    // Verify version is still 0.0.2
     let final_versions_content = fs::read_to_string(&versions_file).unwrap();
-    assert!(final_versions_content.contains("TEST_DIR_VERSION=2"), "Should still contain TEST_DIR_VERSION with version 2");
+    assert!(final_versions_content.contains("TEST_DIR_VERSION=0.0.2"), "Should still contain TEST_DIR_VERSION with version 0.0.2");
 
     // Clean up
     let _ = fs::remove_dir_all(&temp_dir);
   }
+
+  #[test]
+  fn test_version_check_upgrades_integer_format() {
+    let temp_dir = std::env::temp_dir().join("version_upgrade_test");
+    let _ = fs::remove_dir_all(&temp_dir); // Clean up if exists
+    let subdir = temp_dir.join("app");
+    fs::create_dir_all(&subdir).unwrap();
+    fs::write(subdir.join("test.txt"), "content").unwrap();
+
+    // Stored data in the previous integer format with a stale checksum
+    fs::write(temp_dir.join("versions.properties"), "APP_VERSION=4\nAPP_CHECKSUM=00000000\n").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_version_check_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(temp_dir.parent().unwrap().to_path_buf());
+    let versions_file = temp_dir.join("versions.properties");
+
+    // Default bump level is patch: 4 -> 0.0.5
+    let args = vec![Value::Str("version_upgrade_test".to_string())];
+    ctx.registry.get("version-check").unwrap().execute(args, &mut ctx).unwrap();
+    let versions = read_env_file(&versions_file.to_string_lossy()).unwrap();
+    assert_eq!(versions.get("APP_VERSION").unwrap(), "0.0.5");
+
+    // Minor bump resets patch
+    fs::write(subdir.join("test.txt"), "changed").unwrap();
+    let args = vec![Value::Str("version_upgrade_test".to_string()), Value::Str("minor".to_string())];
+    ctx.registry.get("version-check").unwrap().execute(args, &mut ctx).unwrap();
+    let versions = read_env_file(&versions_file.to_string_lossy()).unwrap();
+    assert_eq!(versions.get("APP_VERSION").unwrap(), "0.1.0");
+
+    // Major bump resets minor and patch
+    fs::write(subdir.join("test.txt"), "breaking").unwrap();
+    let args = vec![Value::Str("version_upgrade_test".to_string()), Value::Str("major".to_string())];
+    ctx.registry.get("version-check").unwrap().execute(args, &mut ctx).unwrap();
+    let versions = read_env_file(&versions_file.to_string_lossy()).unwrap();
+    assert_eq!(versions.get("APP_VERSION").unwrap(), "1.0.0");
+
+    // Invalid bump level
+    let args = vec![Value::Str("version_upgrade_test".to_string()), Value::Str("huge".to_string())];
+    assert!(ctx.registry.get("version-check").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&temp_dir);
+  }
+
+  #[test]
+  fn test_semantic_version_parse_and_bump() {
+    assert_eq!(SemanticVersion::parse("3"), Some(SemanticVersion { major: 0, minor: 0, patch: 3 }));
+    assert_eq!(SemanticVersion::parse("1.2.3"), Some(SemanticVersion { major: 1, minor: 2, patch: 3 }));
+    assert_eq!(SemanticVersion::parse("1.2"), None);
+    assert_eq!(SemanticVersion::parse("abc"), None);
+    assert_eq!(SemanticVersion::parse("1.2.3").unwrap().bump(BumpLevel::Patch).to_string(), "1.2.4");
+  }
+
+  #[test]
+  fn test_version_report_is_read_only() {
+    let temp_dir = std::env::temp_dir().join("version_report_test");
+    let _ = fs::remove_dir_all(&temp_dir); // Clean up if exists
+    fs::create_dir_all(temp_dir.join("api")).unwrap();
+    fs::create_dir_all(temp_dir.join("web")).unwrap();
+    fs::write(temp_dir.join("api").join("main.txt"), "api").unwrap();
+    fs::write(temp_dir.join("web").join("index.txt"), "web").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_version_check_command(&mut registry);
+    register_version_report_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(temp_dir.parent().unwrap().to_path_buf());
+    let versions_file = temp_dir.join("versions.properties");
+
+    let args = vec![Value::Str("version_report_test".to_string())];
+    ctx.registry.get("version-check").unwrap().execute(args.clone(), &mut ctx).unwrap();
+    let stored = fs::read_to_string(&versions_file).unwrap();
+
+    // Change only the api component
+    fs::write(temp_dir.join("api").join("main.txt"), "api v2").unwrap();
+
+    let report = ctx.registry.get("version-report").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(
+      report,
+      Value::List(vec![
+        Value::List(vec![
+          Value::Str("API".to_string()),
+          Value::Str("0.0.1".to_string()),
+          Value::Str("0.0.2".to_string()),
+          Value::Bool(true),
+        ]),
+        Value::List(vec![
+          Value::Str("WEB".to_string()),
+          Value::Str("0.0.1".to_string()),
+          Value::Str("0.0.1".to_string()),
+          Value::Bool(false),
+        ]),
+      ])
+    );
+
+    // Nothing was written
+    assert_eq!(fs::read_to_string(&versions_file).unwrap(), stored);
+
+    let _ = fs::remove_dir_all(&temp_dir);
+  }
+
+  #[test]
+  fn test_version_check_full_checksum_mode() {
+    let temp_dir = std::env::temp_dir().join("version_full_checksum_test");
+    let _ = fs::remove_dir_all(&temp_dir); // Clean up if exists
+    fs::create_dir_all(temp_dir.join("app")).unwrap();
+    fs::write(temp_dir.join("app").join("test.txt"), "content").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_version_check_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(temp_dir.parent().unwrap().to_path_buf());
+    let versions_file = temp_dir.join("versions.properties");
+    let path = Value::Str("version_full_checksum_test".to_string());
+
+    // Short mode (default) stores 8 characters
+    ctx.registry.get("version-check").unwrap().execute(vec![path.clone()], &mut ctx).unwrap();
+    let versions = read_env_file(&versions_file.to_string_lossy()).unwrap();
+    let short = versions.get("APP_CHECKSUM").unwrap().clone();
+    assert_eq!(short.len(), 8);
+
+    // Switching to full mode stores 32 characters without bumping the version
+    let args = vec![path.clone(), Value::Str("full".to_string())];
+    let result = ctx.registry.get("version-check").unwrap().execute(args, &mut ctx).unwrap();
+    assert!(result.to_string().contains("0 changes detected"));
+    let versions = read_env_file(&versions_file.to_string_lossy()).unwrap();
+    let full = versions.get("APP_CHECKSUM").unwrap();
+    assert_eq!(full.len(), 32);
+    assert!(full.starts_with(&short));
+    assert_eq!(versions.get("APP_VERSION").unwrap(), "0.0.1");
+
+    // Switching back to short mode is not a change either
+    let result = ctx.registry.get("version-check").unwrap().execute(vec![path], &mut ctx).unwrap();
+    assert!(result.to_string().contains("0 changes detected"));
+
+    let _ = fs::remove_dir_all(&temp_dir);
+  }
+
+  #[test]
+  fn test_parse_version_options() {
+    let path = Value::Str("docker".to_string());
+    assert_eq!(parse_version_options("version-check", std::slice::from_ref(&path)), Ok((BumpLevel::Patch, ChecksumMode::Short)));
+    assert_eq!(
+      parse_version_options("version-check", &[path.clone(), Value::Str("full".to_string()), Value::Str("major".to_string())]),
+      Ok((BumpLevel::Major, ChecksumMode::Full))
+    );
+    assert!(parse_version_options("version-check", &[path.clone(), Value::Str("minor".to_string()), Value::Str("major".to_string())]).is_err());
+    assert!(parse_version_options("version-check", &[path, Value::Int(1)]).is_err());
+  }
+
+  #[test]
+  fn test_normalize_var_name_matches_version_check() {
+    let names = ["my-dir", "api", "web.v2", "Front End", "db_1"];
+    let temp_dir = std::env::temp_dir().join("normalize_var_name_test");
+    let _ = fs::remove_dir_all(&temp_dir); // Clean up if exists
+    for name in names {
+      fs::create_dir_all(temp_dir.join(name)).unwrap();
+      fs::write(temp_dir.join(name).join("file.txt"), name).unwrap();
+    }
+
+    let mut registry = CommandRegistry::new();
+    register_version_report_command(&mut registry);
+    register_normalize_var_name_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(temp_dir.parent().unwrap().to_path_buf());
+
+    let args = vec![Value::Str("normalize_var_name_test".to_string())];
+    let report = ctx.registry.get("version-report").unwrap().execute(args, &mut ctx).unwrap();
+    let reported: Vec<Value> = match report {
+      Value::List(entries) => entries
+        .into_iter()
+        .map(|entry| match entry {
+          Value::List(fields) => fields[0].clone(),
+          other => panic!("unexpected entry: {:?}", other),
+        })
+        .collect(),
+      other => panic!("unexpected report: {:?}", other),
+    };
+
+    let mut predicted = Vec::new();
+    for name in names {
+      let args = vec![Value::Str(name.to_string())];
+      predicted.push(ctx.registry.get("normalize-var-name").unwrap().execute(args, &mut ctx).unwrap());
+    }
+    predicted.sort_by_key(|v| v.to_string());
+
+    assert_eq!(reported, predicted);
+    assert_eq!(normalize_var_name("my-dir"), "MY_DIR");
+    assert_eq!(normalize_var_name("Front End"), "FRONT_END");
+
+    let _ = fs::remove_dir_all(&temp_dir);
+  }
+
+  #[test]
+  fn test_version_check_sets_context_variables() {
+    let temp_dir = std::env::temp_dir().join("version_check_variables_test");
+    let _ = fs::remove_dir_all(&temp_dir); // Clean up if exists
+    fs::create_dir_all(temp_dir.join("my-app")).unwrap();
+    fs::write(temp_dir.join("my-app").join("Dockerfile"), "FROM scratch").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_version_check_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(temp_dir.parent().unwrap().to_path_buf());
+
+    let args = vec![Value::Str("version_check_variables_test".to_string())];
+    ctx.registry.get("version-check").unwrap().execute(args.clone(), &mut ctx).unwrap();
+
+    let checksum = ctx.get_version("MY_APP").unwrap().checksum.clone();
+    assert_eq!(ctx.variables.get("MY_APP_VERSION"), Some(&Value::Str("0.0.1".to_string())));
+    assert_eq!(ctx.variables.get("MY_APP_CHECKSUM"), Some(&Value::Str(checksum)));
+
+    // A content change bumps the variable on the next run
+    fs::write(temp_dir.join("my-app").join("Dockerfile"), "FROM alpine").unwrap();
+    ctx.registry.get("version-check").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(ctx.variables.get("MY_APP_VERSION"), Some(&Value::Str("0.0.2".to_string())));
+
+    let _ = fs::remove_dir_all(&temp_dir);
+  }
 }
@@ -1,5 +1,9 @@
+use crate::env_ops::create_dir_env_map_and_calculate_md5;
+use crate::file_ops::quote_env_value;
+use crate::context::LogLevel;
 use crate::utils::debug_log;
-use crate::{CommandRegistry, Value, tags};
+use crate::{CommandRegistry, Context, Value, tags};
+use std::collections::HashMap;
 use std::fs;
 
 /// Register write-env command
@@ -11,7 +15,7 @@ pub fn register_write_env_command(registry: &mut CommandRegistry) {
     "  (write-env \"config.env\")     ; Write to config.env relative to basedir\n  (write-env \"../shared.env\")  ; Write to parent directory",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "write-env", "executing write-env command");
+      debug_log(ctx, LogLevel::Debug, "write-env", "executing write-env command");
 
       if args.len() != 1 {
         return Err("write-env expects exactly one argument (path)".to_string());
@@ -22,13 +26,13 @@ pub fn register_write_env_command(registry: &mut CommandRegistry) {
         _ => return Err("write-env path must be a string".to_string()),
       };
 
-      debug_log(ctx, "write-env", &format!("processing path argument: {}", path_arg));
+      debug_log(ctx, LogLevel::Debug, "write-env", &format!("processing path argument: {}", path_arg));
 
       // Resolve path relative to basedir
       let basedir = ctx.get_basedir();
       let file_path = basedir.join(&path_arg);
 
-      debug_log(ctx, "write-env", &format!("resolved file path: {}", file_path.display()));
+      debug_log(ctx, LogLevel::Debug, "write-env", &format!("resolved file path: {}", file_path.display()));
 
       // Create parent directories if they don't exist
       if let Some(parent) = file_path.parent() {
@@ -47,12 +51,17 @@ pub fn register_write_env_command(registry: &mut CommandRegistry) {
       content.push_str("# Environment variables written by write-env command\n");
       content.push_str("# Generated automatically - do not edit manually\n\n");
 
-      // Write all context variables
-      for (key, value) in &ctx.variables {
-        let line = format!("{}={}\n", key, value.to_string());
+      // Collect and sort keys alphabetically for reproducible output
+      let mut keys: Vec<&String> = ctx.variables.keys().collect();
+      keys.sort();
+
+      // Write all context variables, quoting values that need protection
+      for key in keys {
+        let value = &ctx.variables[key];
+        let line = format!("{}={}\n", key, quote_env_value(&value.to_string()));
         content.push_str(&line);
         variables_written += 1;
-        debug_log(ctx, "write-env", &format!("writing variable: {} = {}", key, value.to_string()));
+        debug_log(ctx, LogLevel::Debug, "write-env", &format!("writing variable: {} = {}", key, ctx.display_value(key, value)));
       }
 
       // If no variables, add a comment
@@ -60,7 +69,7 @@ pub fn register_write_env_command(registry: &mut CommandRegistry) {
         content.push_str("# No variables to write\n");
       }
 
-      debug_log(ctx, "write-env", &format!("writing {} variables to file", variables_written));
+      debug_log(ctx, LogLevel::Debug, "write-env", &format!("writing {} variables to file", variables_written));
 
       // Write content to file
       match fs::write(&file_path, content) {
@@ -70,7 +79,7 @@ pub fn register_write_env_command(registry: &mut CommandRegistry) {
             variables_written,
             file_path.display()
           );
-          debug_log(ctx, "write-env", &format!("completed: {}", result_msg));
+          debug_log(ctx, LogLevel::Info, "write-env", &format!("completed: {}", result_msg));
           Ok(Value::Str(result_msg))
         }
         Err(e) => Err(format!("Failed to write file {}: {}", file_path.display(), e)),
@@ -79,6 +88,85 @@ pub fn register_write_env_command(registry: &mut CommandRegistry) {
   );
 }
 
+/// Register write-docker-env command
+pub fn register_write_docker_env_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "write-docker-env",
+    "Write context variables plus the computed Docker variables (MD5_* directory hashes, HOST_PROJECT_PATH, HOST_UID/GID/USER) to a file",
+    "(write-docker-env path [docker-dir])",
+    "  (write-docker-env \".env.docker\")                 ; Hashes the DOCKER_DEV_PATH subdirectories\n  (write-docker-env \".env.docker\" \"docker\")        ; Hashes the subdirectories of docker/",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "write-docker-env", "executing write-docker-env command");
+
+      let (path_arg, docker_dir_arg) = match args.as_slice() {
+        [Value::Str(path)] => (path.clone(), ctx.config.docker_dev_path().clone()),
+        [Value::Str(path), Value::Str(dir)] => (path.clone(), dir.clone()),
+        [_] | [_, _] => return Err("write-docker-env path and docker directory must be strings".to_string()),
+        _ => return Err("write-docker-env expects a path and an optional docker directory".to_string()),
+      };
+
+      let file_path = ctx.get_basedir().join(&path_arg);
+      let env_vars = collect_docker_env_vars(ctx, &docker_dir_arg)?;
+
+      if let Some(parent) = file_path.parent() {
+        if !parent.exists() {
+          if let Err(e) = fs::create_dir_all(parent) {
+            return Err(format!("Failed to create parent directories for {}: {}", file_path.display(), e));
+          }
+        }
+      }
+
+      let mut content = String::new();
+      content.push_str("# Environment variables written by write-docker-env command\n");
+      content.push_str("# Generated automatically - do not edit manually\n\n");
+
+      let mut keys: Vec<&String> = env_vars.keys().collect();
+      keys.sort();
+      for key in &keys {
+        content.push_str(&format!("{}={}\n", key, quote_env_value(&env_vars[*key])));
+      }
+
+      match fs::write(&file_path, content) {
+        Ok(_) => {
+          let result_msg = format!("Wrote {} variables to {}", keys.len(), file_path.display());
+          debug_log(ctx, LogLevel::Info, "write-docker-env", &format!("completed: {}", result_msg));
+          Ok(Value::Str(result_msg))
+        }
+        Err(e) => Err(format!("Failed to write file {}: {}", file_path.display(), e)),
+      }
+    },
+  );
+}
+
+/// Merges the context variables with the variables computed for Docker
+/// Computed variables take precedence over context variables with the same name
+fn collect_docker_env_vars(ctx: &Context, docker_dir_arg: &str) -> Result<HashMap<String, String>, String> {
+  let basedir = ctx.get_basedir();
+  let docker_dir = basedir.join(docker_dir_arg);
+  if !docker_dir.is_dir() {
+    return Err(format!("Docker directory does not exist: {}", docker_dir.display()));
+  }
+
+  let host_project_path = fs::canonicalize(basedir)
+    .map_err(|e| format!("Failed to resolve project path {}: {}", basedir.display(), e))?;
+
+  let (_, docker_vars, _) = create_dir_env_map_and_calculate_md5(
+    &docker_dir,
+    &host_project_path.to_string_lossy(),
+    ctx.get_debug_print(),
+  )
+  .map_err(|e| format!("Failed to compute Docker variables: {}", e))?;
+
+  let mut env_vars: HashMap<String, String> = ctx
+    .variables
+    .iter()
+    .map(|(key, value)| (key.clone(), value.to_string()))
+    .collect();
+  env_vars.extend(docker_vars);
+  Ok(env_vars)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -187,4 +275,129 @@ mod tests {
     let _ = fs::remove_file(&file_path);
     let _ = fs::remove_dir(&test_dir);
   }
+
+  #[test]
+  fn test_write_env_round_trip_with_special_characters() {
+    let mut registry = CommandRegistry::new();
+    register_write_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let test_dir = std::env::current_dir()
+      .unwrap()
+      .join("target")
+      .join("test_write_env_round_trip");
+    fs::create_dir_all(&test_dir).unwrap();
+    ctx.set_basedir(test_dir.clone());
+
+    let tricky = "value with # hash and space";
+    let multiline = "line1\nline2 \"quoted\" a=b";
+    ctx.set_variable("TRICKY".to_string(), Value::Str(tricky.to_string()));
+    ctx.set_variable("MULTI".to_string(), Value::Str(multiline.to_string()));
+    ctx.set_variable("PLAIN".to_string(), Value::Str("plain".to_string()));
+
+    let args = vec![Value::Str("round_trip.env".to_string())];
+    ctx
+      .registry
+      .get("write-env")
+      .unwrap()
+      .execute(args, &mut ctx)
+      .unwrap();
+
+    let file_path = test_dir.join("round_trip.env");
+    let content = fs::read_to_string(&file_path).unwrap();
+    assert!(content.contains("PLAIN=plain\n"));
+    assert!(content.contains("TRICKY=\"value with # hash and space\""));
+
+    let read_back =
+      crate::file_ops::read_env_file(&file_path.to_string_lossy()).unwrap();
+    assert_eq!(read_back.get("TRICKY").unwrap(), tricky);
+    assert_eq!(read_back.get("MULTI").unwrap(), multiline);
+    assert_eq!(read_back.get("PLAIN").unwrap(), "plain");
+
+    // Clean up
+    let _ = fs::remove_file(&file_path);
+    let _ = fs::remove_dir(&test_dir);
+  }
+
+  #[test]
+  fn test_write_env_output_is_sorted_and_reproducible() {
+    let mut registry = CommandRegistry::new();
+    register_write_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let test_dir = std::env::current_dir()
+      .unwrap()
+      .join("target")
+      .join("test_write_env_sorted");
+    fs::create_dir_all(&test_dir).unwrap();
+    ctx.set_basedir(test_dir.clone());
+
+    for key in ["ZETA", "ALPHA", "MIKE", "BRAVO", "YANKEE"] {
+      ctx.set_variable(key.to_string(), Value::Str(key.to_lowercase()));
+    }
+
+    let write = |ctx: &mut Context, name: &str| -> String {
+      let args = vec![Value::Str(name.to_string())];
+      ctx
+        .registry
+        .get("write-env")
+        .unwrap()
+        .execute(args, ctx)
+        .unwrap();
+      fs::read_to_string(test_dir.join(name)).unwrap()
+    };
+
+    let first = write(&mut ctx, "first.env");
+    let second = write(&mut ctx, "second.env");
+    assert_eq!(first, second);
+
+    let keys: Vec<&str> = first
+      .lines()
+      .filter(|line| !line.is_empty() && !line.starts_with('#'))
+      .map(|line| line.split('=').next().unwrap())
+      .collect();
+    assert_eq!(keys, vec!["ALPHA", "BRAVO", "MIKE", "YANKEE", "ZETA"]);
+
+    // Clean up
+    let _ = fs::remove_dir_all(&test_dir);
+  }
+
+  #[test]
+  fn test_write_docker_env_includes_computed_variables() {
+    let mut registry = CommandRegistry::new();
+    register_write_docker_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let project = std::env::temp_dir().join("dpm_test_write_docker_env");
+    let _ = fs::remove_dir_all(&project);
+    fs::create_dir_all(project.join("dev/docker/web")).unwrap();
+    fs::create_dir_all(project.join("dev/docker/db")).unwrap();
+    fs::write(project.join("dev/docker/web/Dockerfile"), "FROM nginx").unwrap();
+    fs::write(project.join("dev/docker/db/Dockerfile"), "FROM postgres").unwrap();
+    ctx.set_basedir(project.clone());
+    ctx.set_variable("PROJECT_NAME".to_string(), Value::Str("demo app".to_string()));
+
+    let args = vec![Value::Str("out/.env.docker".to_string())];
+    ctx.registry.get("write-docker-env").unwrap().execute(args, &mut ctx).unwrap();
+
+    let written = crate::file_ops::read_env_file(&project.join("out/.env.docker").to_string_lossy()).unwrap();
+    let web_md5 = crate::file_ops::compute_dir_md5(&project.join("dev/docker/web").to_string_lossy()).unwrap();
+    assert_eq!(written.get("MD5_WEB"), Some(&web_md5));
+    assert!(written.contains_key("MD5_DB"));
+    assert_eq!(written.get("PROJECT_NAME"), Some(&"demo app".to_string()));
+    assert_eq!(
+      written.get("HOST_PROJECT_PATH"),
+      Some(&fs::canonicalize(&project).unwrap().to_string_lossy().to_string())
+    );
+    if cfg!(target_os = "linux") || cfg!(target_os = "macos") {
+      assert!(written.contains_key("HOST_UID"));
+      assert!(written.contains_key("HOST_GID"));
+    }
+
+    // A missing docker directory is an error
+    let args = vec![Value::Str("out.env".to_string()), Value::Str("missing".to_string())];
+    assert!(ctx.registry.get("write-docker-env").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&project);
+  }
 }
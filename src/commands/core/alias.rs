@@ -0,0 +1,76 @@
+use crate::{CommandRegistry, Value};
+
+/// Register alias commands
+pub fn register_alias_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help(
+    "alias",
+    "Register a short name that resolves to an existing command",
+    "(alias alias-name command-name)",
+    "  (alias \"dca\" \"docker-compose-args\")  ; (dca ...) now runs docker-compose-args",
+    |args, ctx| {
+      if args.len() != 2 {
+        return Err("alias expects exactly two arguments: alias name and command name".to_string());
+      }
+
+      let alias = match &args[0] {
+        Value::Str(name) => name.clone(),
+        _ => return Err("Alias name must be a string".to_string()),
+      };
+      let target = match &args[1] {
+        Value::Str(name) => name.clone(),
+        _ => return Err("Command name must be a string".to_string()),
+      };
+
+      ctx.registry.register_alias(&alias, &target)?;
+      Ok(Value::Str(format!("{} -> {}", alias, ctx.registry.resolve_alias(&alias))))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::commands::PipeCommand;
+  use crate::commands::core::math::SumCommand;
+  use crate::context::Context;
+  use crate::evaluate_string;
+
+  #[test]
+  fn test_alias_call() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    registry.register(PipeCommand);
+    register_alias_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = evaluate_string("(alias \"s\" \"sum\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("s -> sum".to_string()));
+    assert_eq!(evaluate_string("(s 1 2 3)", &mut ctx).unwrap(), Value::Int(6));
+
+    // Aliases work as pipe stages and can point at other aliases
+    assert_eq!(evaluate_string("(pipe (s 1 2) (s 10))", &mut ctx).unwrap(), Value::Int(13));
+    let result = evaluate_string("(alias \"ss\" \"s\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("ss -> sum".to_string()));
+    assert_eq!(evaluate_string("(ss 4 5)", &mut ctx).unwrap(), Value::Int(9));
+  }
+
+  #[test]
+  fn test_alias_listed_and_errors() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    registry.register(PipeCommand);
+    register_alias_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    evaluate_string("(alias \"s\" \"sum\")", &mut ctx).unwrap();
+
+    let names = ctx.registry.list_commands();
+    assert!(names.contains(&"sum".to_string()));
+    assert!(names.contains(&"s (alias of sum)".to_string()));
+    assert!(!names.contains(&"s".to_string()));
+
+    let err = evaluate_string("(alias \"x\" \"missing\")", &mut ctx).unwrap_err();
+    assert!(err.contains("unknown command 'missing'"));
+    assert!(evaluate_string("(alias \"sum\" \"pipe\")", &mut ctx).is_err());
+    assert!(evaluate_string("(alias \"s\")", &mut ctx).is_err());
+  }
+}
@@ -0,0 +1,88 @@
+use crate::{Command, Value};
+use crate::context::Context;
+use crate::lisp_interpreter::unknown_command_error;
+
+/// Apply command - calls a command by name with a list spread as its arguments
+///
+/// `(apply "sum" (list 1 2 3))` is the same as `(sum 1 2 3)`. Unlike `eval`,
+/// the arguments are already values and are passed as they are.
+pub struct ApplyCommand;
+
+impl Command for ApplyCommand {
+    fn execute(&self, args: Vec<Value>, ctx: &mut Context) -> Result<Value, String> {
+        let (name, arguments) = match args.as_slice() {
+            [Value::Str(name), Value::List(items)] => (name, items.clone()),
+            [Value::Str(name), Value::Nil] => (name, Vec::new()),
+            [Value::Str(_), _] => return Err("apply expects a list of arguments as second argument".to_string()),
+            [_, _] => return Err("apply expects a command name as first argument".to_string()),
+            _ => return Err("apply expects exactly two arguments: command name and argument list".to_string()),
+        };
+
+        let command = ctx
+            .registry
+            .get(name)
+            .ok_or_else(|| unknown_command_error(&ctx.registry, name))?;
+        if command.is_special_form() {
+            return Err(format!("apply cannot call special form '{}'", name));
+        }
+
+        command.execute(arguments, ctx)
+    }
+
+    fn name(&self) -> &'static str {
+        "apply"
+    }
+
+    fn description(&self) -> &'static str {
+        "Call a command with the elements of a list as its arguments"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(apply command-name argument-list)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (apply \"sum\" (list 1 2 3))                ; Returns 6\n  (apply \"concat\" (list \"a\" \"b\" \"c\"))     ; Returns \"abc\""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{ConcatCommand, TryCommand};
+    use crate::commands::core::math::SumCommand;
+    use crate::commands::core::list_utils::register_list_commands;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_apply_spreads_arguments() {
+        let mut registry = CommandRegistry::new();
+        registry.register(ApplyCommand);
+        registry.register(SumCommand);
+        registry.register(ConcatCommand);
+        registry.register(TryCommand);
+        register_list_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(apply \"sum\" (list 1 2 3))", &mut ctx).unwrap(), Value::Int(6));
+        // Variadic commands take any number of spread arguments
+        let result = evaluate_string("(apply \"concat\" (list \"a\" \"b\" \"c\" \"d\"))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Str("abcd".to_string()));
+        assert_eq!(evaluate_string("(apply \"sum\" (list))", &mut ctx).unwrap(), Value::Int(0));
+    }
+
+    #[test]
+    fn test_apply_errors() {
+        let mut registry = CommandRegistry::new();
+        registry.register(ApplyCommand);
+        registry.register(SumCommand);
+        registry.register(ConcatCommand);
+        registry.register(TryCommand);
+        register_list_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let err = evaluate_string("(apply \"no-such-command\" (list 1))", &mut ctx).unwrap_err();
+        assert!(err.contains("Unknown command: no-such-command"), "{}", err);
+        assert!(evaluate_string("(apply \"sum\" 1)", &mut ctx).is_err());
+        assert!(evaluate_string("(apply 1 (list 1))", &mut ctx).is_err());
+        assert!(evaluate_string("(apply \"try\" (list 1))", &mut ctx).unwrap_err().contains("special form"));
+    }
+}
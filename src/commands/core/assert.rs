@@ -0,0 +1,128 @@
+use crate::{Command, Value};
+use crate::context::Context;
+
+/// Assert command - fails the script when a condition is not truthy
+pub struct AssertCommand;
+
+impl Command for AssertCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        if args.is_empty() || args.len() > 2 {
+            return Err("assert expects a condition and an optional message".to_string());
+        }
+
+        if args[0].is_truthy() {
+            return Ok(Value::Nil);
+        }
+
+        match args.get(1) {
+            Some(message) => Err(format!("Assertion failed: {}", message)),
+            None => Err(format!("Assertion failed: {} is not truthy", args[0])),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "assert"
+    }
+
+    fn description(&self) -> &'static str {
+        "Return nil when the condition is truthy, otherwise fail with the given message"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(assert condition [message])"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (assert (has-var \"PROJECT\") \"PROJECT must be set\")\n  (assert (rust-path-exists \"docker\"))               ; Fails with a generic message"
+    }
+}
+
+/// Assert-eq command - fails the script when two values differ
+pub struct AssertEqCommand;
+
+impl AssertEqCommand {
+    /// Renders a value with its type, so that e.g. 1 and "1" are told apart
+    fn describe(value: &Value) -> String {
+        format!("{:?}", value)
+    }
+}
+
+impl Command for AssertEqCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        if args.len() != 2 {
+            return Err("assert-eq expects exactly two arguments: actual and expected".to_string());
+        }
+
+        if args[0] == args[1] {
+            return Ok(Value::Nil);
+        }
+
+        Err(format!(
+            "Assertion failed: values differ\n  actual:   {}\n  expected: {}",
+            Self::describe(&args[0]),
+            Self::describe(&args[1])
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "assert-eq"
+    }
+
+    fn description(&self) -> &'static str {
+        "Return nil when actual equals expected, otherwise fail showing both values"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(assert-eq actual expected)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (assert-eq (sum 1 2) 3)                      ; Passes\n  (assert-eq (get-var \"ENV\") \"production\")     ; Fails showing both values"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::core::math::SumCommand;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_assert() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AssertCommand);
+        registry.register(AssertEqCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(assert #t \"never shown\")", &mut ctx).unwrap(), Value::Nil);
+        assert_eq!(evaluate_string("(assert (sum 1 2))", &mut ctx).unwrap(), Value::Nil);
+
+        let err = evaluate_string("(assert #f \"config missing\")", &mut ctx).unwrap_err();
+        assert_eq!(err, "Assertion failed: config missing");
+
+        let err = evaluate_string("(assert 0)", &mut ctx).unwrap_err();
+        assert_eq!(err, "Assertion failed: 0 is not truthy");
+
+        assert!(evaluate_string("(assert)", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_assert_eq() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AssertCommand);
+        registry.register(AssertEqCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(assert-eq (sum 1 2) 3)", &mut ctx).unwrap(), Value::Nil);
+        assert_eq!(evaluate_string("(assert-eq \"a\" \"a\")", &mut ctx).unwrap(), Value::Nil);
+
+        let err = evaluate_string("(assert-eq (sum 1 2) 4)", &mut ctx).unwrap_err();
+        assert_eq!(err, "Assertion failed: values differ\n  actual:   Int(3)\n  expected: Int(4)");
+
+        // Same display but different types
+        let err = evaluate_string("(assert-eq 1 \"1\")", &mut ctx).unwrap_err();
+        assert!(err.contains("Int(1)") && err.contains("Str(\"1\")"));
+
+        assert!(evaluate_string("(assert-eq 1)", &mut ctx).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::context::LogLevel;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Value, tags};
 use std::env;
@@ -13,7 +14,7 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
     "  (basedir \"/home/user/project\")  ; Set absolute path\n  (basedir \"../project\")         ; Set relative path",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "basedir", "executing basedir command");
+      debug_log(ctx, LogLevel::Debug, "basedir", "executing basedir command");
 
       if args.len() != 1 {
         return Err("basedir expects exactly one argument (path)".to_string());
@@ -24,14 +25,14 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
         _ => return Err("basedir path must be a string".to_string()),
       };
 
-      debug_log(ctx, "basedir", &format!("processing path argument: {}", path_arg));
+      debug_log(ctx, LogLevel::Debug, "basedir", &format!("processing path argument: {}", path_arg));
 
       // Handle relative paths - make them relative to the current executable
       let base_path = if std::path::Path::new(&path_arg).is_absolute() {
-        debug_log(ctx, "basedir", "path is absolute, using as-is");
+        debug_log(ctx, LogLevel::Debug, "basedir", "path is absolute, using as-is");
         path_arg.clone()
       } else {
-        debug_log(ctx, "basedir", "path is relative, resolving against executable directory");
+        debug_log(ctx, LogLevel::Debug, "basedir", "path is relative, resolving against executable directory");
         // Get the directory of the current executable
         match env::current_exe() {
           Ok(exe_path) => {
@@ -42,7 +43,7 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
             }
           }
           Err(_) => {
-            debug_log(ctx, "basedir", "fallback to current working directory");
+            debug_log(ctx, LogLevel::Debug, "basedir", "fallback to current working directory");
             // Fallback to current working directory
             match env::current_dir() {
               Ok(cwd) => cwd.join(&path_arg).to_string_lossy().to_string(),
@@ -52,19 +53,19 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
         }
       };
 
-      debug_log(ctx, "basedir", &format!("resolved base path: {}", base_path));
+      debug_log(ctx, LogLevel::Debug, "basedir", &format!("resolved base path: {}", base_path));
 
       // Verify the path exists
       if !std::path::Path::new(&base_path).exists() {
         return Err(format!("Path does not exist: {}", base_path));
       }
 
-      debug_log(ctx, "basedir", "path exists, setting as base directory");
+      debug_log(ctx, LogLevel::Debug, "basedir", "path exists, setting as base directory");
       // Store the base directory in the context
       ctx.set_basedir(PathBuf::from(&base_path));
 
       let result_msg = format!("Base directory set to: {}", base_path);
-      debug_log(ctx, "basedir", "base directory successfully set");
+      debug_log(ctx, LogLevel::Info, "basedir", "base directory successfully set");
 
       Ok(Value::Str(result_msg))
     },
@@ -78,7 +79,7 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
     "  (get-basedir)                 ; Get the current base directory path",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "get-basedir", "executing get-basedir command");
+      debug_log(ctx, LogLevel::Debug, "get-basedir", "executing get-basedir command");
 
       if !args.is_empty() {
         return Err("get-basedir expects no arguments".to_string());
@@ -89,6 +90,7 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
 
       debug_log(
         ctx,
+        LogLevel::Debug,
         "get-basedir",
         &format!("returning basedir: {}", basedir_str),
       );
@@ -105,16 +107,16 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
     "  (basedir-root)           ; Search for .git folder (default)\n  (basedir-root \".git\")    ; Search for .git folder\n  (basedir-root \"package.json\") ; Search for package.json file\n  (basedir-root \"src\")      ; Search for src folder",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "basedir", "executing basedir-root command");
+      debug_log(ctx, LogLevel::Debug, "basedir", "executing basedir-root command");
 
       // Default target is ".git"
       let target = if args.is_empty() {
-        debug_log(ctx, "basedir", "using default target: .git");
+        debug_log(ctx, LogLevel::Debug, "basedir", "using default target: .git");
         ".git".to_string()
       } else if args.len() == 1 {
         match &args[0] {
           Value::Str(s) => {
-            debug_log(ctx, "basedir", &format!("using specified target: {}", s));
+            debug_log(ctx, LogLevel::Debug, "basedir", &format!("using specified target: {}", s));
             s.clone()
           }
           _ => return Err("basedir-root target must be a string".to_string()),
@@ -126,7 +128,7 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
       // Start from current working directory
       let mut current_dir = match env::current_dir() {
         Ok(dir) => {
-          debug_log(ctx, "basedir", &format!("starting search from: {}", dir.display()));
+          debug_log(ctx, LogLevel::Debug, "basedir", &format!("starting search from: {}", dir.display()));
           dir
         }
         Err(e) => return Err(format!("Failed to get current directory: {}", e)),
@@ -135,10 +137,10 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
       // Search up the filesystem
       loop {
         let target_path = current_dir.join(&target);
-        debug_log(ctx, "basedir", &format!("checking for target at: {}", target_path.display()));
+        debug_log(ctx, LogLevel::Debug, "basedir", &format!("checking for target at: {}", target_path.display()));
 
         if target_path.exists() {
-          debug_log(ctx, "basedir", &format!("target found at: {}", target_path.display()));
+          debug_log(ctx, LogLevel::Debug, "basedir", &format!("target found at: {}", target_path.display()));
           // Found the target, update basedir
           ctx.set_basedir(current_dir.clone());
 
@@ -149,7 +151,7 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
             current_dir.display()
           );
 
-          debug_log(ctx, "basedir", "base directory successfully set from root search");
+          debug_log(ctx, LogLevel::Info, "basedir", "base directory successfully set from root search");
           return Ok(Value::Str(result_msg));
         }
 
@@ -157,10 +159,10 @@ pub fn register_basedir_commands(registry: &mut CommandRegistry) {
         match current_dir.parent() {
           Some(parent) => {
             current_dir = parent.to_path_buf();
-            debug_log(ctx, "basedir", &format!("moving up to parent directory: {}", current_dir.display()));
+            debug_log(ctx, LogLevel::Debug, "basedir", &format!("moving up to parent directory: {}", current_dir.display()));
           }
           None => {
-            debug_log(ctx, "basedir", "reached filesystem root, target not found");
+            debug_log(ctx, LogLevel::Debug, "basedir", "reached filesystem root, target not found");
             return Err(format!(
               "Target '{}' not found in any parent directory from current working directory",
               target
@@ -0,0 +1,102 @@
+use crate::context::LogLevel;
+use crate::env_ops::combine_env_files;
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Value, tags};
+
+/// Register the combine-env command
+pub fn register_combine_env_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "combine-env",
+    "Layer .env, .env.local and an input file from the base directory, expand ${VAR} references and store the result in the context",
+    "(combine-env input-file)",
+    "  (combine-env \".env.docker\")   ; .env < .env.local < .env.docker, returns the number of variables loaded",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "combine-env", "executing combine-env command");
+
+      if args.len() != 1 {
+        return Err("combine-env expects exactly one argument (input file)".to_string());
+      }
+
+      let input_file = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("combine-env input file must be a string".to_string()),
+      };
+
+      let basedir = ctx.get_basedir().clone();
+      let combined = combine_env_files(&basedir, &input_file, ctx.get_debug_print(), false)
+        .map_err(|e| format!("combine-env failed: {}", e))?;
+
+      let count = combined.len();
+      for (key, value) in combined {
+        ctx.set_variable(key, Value::Str(value));
+      }
+
+      debug_log(
+        ctx,
+        LogLevel::Info,
+        "combine-env",
+        &format!("loaded {} variables from {}", count, basedir.display()),
+      );
+      Ok(Value::Int(count as i64))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Context;
+  use std::fs;
+  use std::path::PathBuf;
+
+  #[test]
+  fn test_combine_env_layer_precedence() {
+    let mut registry = CommandRegistry::new();
+    register_combine_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_combine_env");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(".env"), "PROJECT_NAME=demo\nA=env\nB=env\nC=env\n").unwrap();
+    fs::write(dir.join(".env.local"), "B=local\nC=local\n").unwrap();
+    fs::write(dir.join("input.env"), "C=input\nD=input\n").unwrap();
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![Value::Str("input.env".to_string())];
+    let result = ctx.registry.get("combine-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(result, Value::Int(5));
+
+    let get = |ctx: &Context, key: &str| ctx.get_variable(key);
+    assert_eq!(get(&ctx, "PROJECT_NAME"), Some(Value::Str("demo".to_string())));
+    assert_eq!(get(&ctx, "A"), Some(Value::Str("env".to_string())));
+    assert_eq!(get(&ctx, "B"), Some(Value::Str("local".to_string())));
+    assert_eq!(get(&ctx, "C"), Some(Value::Str("input".to_string())));
+    assert_eq!(get(&ctx, "D"), Some(Value::Str("input".to_string())));
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_combine_env_defaults_project_name() {
+    let mut registry = CommandRegistry::new();
+    register_combine_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_combine_env_defaults");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    ctx.set_basedir(dir.clone());
+
+    // No layer present: only the default project name is loaded
+    let args = vec![Value::Str("missing.env".to_string())];
+    let result = ctx.registry.get("combine-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(result, Value::Int(1));
+    assert_eq!(ctx.get_variable("PROJECT_NAME"), Some(Value::Str("NoName".to_string())));
+
+    assert!(ctx.registry.get("combine-env").unwrap().execute(vec![Value::Int(1)], &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
@@ -0,0 +1,122 @@
+use crate::{CommandRegistry, Value};
+
+/// Register the config-get, config-set and config-define commands
+/// They expose the legacy `Config` held in the context to scripts
+pub fn register_config_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help(
+    "config-get",
+    "Get the value of a configuration variable (DOCKER_DEV_PATH, VERSIONS_FOLDER)",
+    "(config-get key)",
+    "  (config-get \"DOCKER_DEV_PATH\")   ; Returns \"./dev/docker\" unless changed",
+    |args, ctx| {
+      if args.len() != 1 {
+        return Err("config-get expects exactly one argument (key)".to_string());
+      }
+
+      let key = match &args[0] {
+        Value::Str(s) => s,
+        _ => return Err("config-get key must be a string".to_string()),
+      };
+
+      ctx
+        .config
+        .get(key)
+        .map(|value| Value::Str(value.clone()))
+        .ok_or_else(|| format!("Unknown configuration variable: {}", key))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "config-set",
+    "Set a configuration variable; unknown variables are rejected",
+    "(config-set key value)",
+    "  (config-set \"DOCKER_DEV_PATH\" \"./docker\")   ; Returns \"./docker\"",
+    |args, ctx| {
+      if args.len() != 2 {
+        return Err("config-set expects exactly two arguments: key and value".to_string());
+      }
+
+      let (key, value) = match (&args[0], &args[1]) {
+        (Value::Str(key), Value::Str(value)) => (key, value),
+        _ => return Err("config-set key and value must be strings".to_string()),
+      };
+
+      ctx.config.set(key, value)?;
+      Ok(Value::Str(value.clone()))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "config-define",
+    "Declare a new configuration variable with a default value; existing variables are left unchanged",
+    "(config-define key default)",
+    "  (config-define \"MY_KEY\" \"default\")          ; MY_KEY can now be used with config-get/config-set\n  (config-define \"DOCKER_DEV_PATH\" \"./x\")     ; No-op, returns the current value",
+    |args, ctx| {
+      if args.len() != 2 {
+        return Err("config-define expects exactly two arguments: key and default value".to_string());
+      }
+
+      let (key, default) = match (&args[0], &args[1]) {
+        (Value::Str(key), Value::Str(default)) => (key, default),
+        _ => return Err("config-define key and default value must be strings".to_string()),
+      };
+
+      ctx.config.add_variable(key, default);
+      Ok(Value::Str(ctx.config.get(key).cloned().unwrap_or_default()))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::{DOCKER_DEV_PATH_DEFAULT_VALUE, DOCKER_DEV_PATH_KEY};
+  use crate::{Context, evaluate_string};
+
+  #[test]
+  fn test_config_get_default_and_set() {
+    let mut registry = CommandRegistry::new();
+    register_config_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let result = evaluate_string("(config-get \"DOCKER_DEV_PATH\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str(DOCKER_DEV_PATH_DEFAULT_VALUE.to_string()));
+
+    evaluate_string("(config-set \"DOCKER_DEV_PATH\" \"./x\")", &mut ctx).unwrap();
+    assert_eq!(ctx.config.get(DOCKER_DEV_PATH_KEY), Some(&"./x".to_string()));
+    let result = evaluate_string("(config-get \"DOCKER_DEV_PATH\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("./x".to_string()));
+  }
+
+  #[test]
+  fn test_config_rejects_unknown_key() {
+    let mut registry = CommandRegistry::new();
+    register_config_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let err = evaluate_string("(config-set \"NOT_A_KEY\" \"value\")", &mut ctx).unwrap_err();
+    assert!(err.contains("Unknown configuration variable: NOT_A_KEY"));
+    assert!(ctx.config.get("NOT_A_KEY").is_none());
+
+    assert!(evaluate_string("(config-get \"NOT_A_KEY\")", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_config_define_new_key() {
+    let mut registry = CommandRegistry::new();
+    register_config_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert!(evaluate_string("(config-set \"MY_KEY\" \"value\")", &mut ctx).is_err());
+
+    let result = evaluate_string("(config-define \"MY_KEY\" \"default\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("default".to_string()));
+
+    evaluate_string("(config-set \"MY_KEY\" \"value\")", &mut ctx).unwrap();
+    let result = evaluate_string("(config-get \"MY_KEY\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("value".to_string()));
+
+    // Redefining keeps the current value
+    let result = evaluate_string("(config-define \"MY_KEY\" \"other\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("value".to_string()));
+    let result = evaluate_string("(config-define \"DOCKER_DEV_PATH\" \"./x\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str(DOCKER_DEV_PATH_DEFAULT_VALUE.to_string()));
+  }
+}
@@ -1,4 +1,4 @@
-use crate::context::Context;
+use crate::context::{Context, LogLevel};
 use crate::{Command, Value};
 /// Debug command - prints the current state of the program
 pub struct DebugCommand;
@@ -12,7 +12,7 @@ impl Command for DebugCommand {
     // Check if we have arguments to set debugPrint variable
     if !args.is_empty() {
       if args.len() != 1 {
-        return Err("{EmojiCatalog::} debug command accepts either no arguments or exactly one argument (true/false)".to_string());
+        return Err("{EmojiCatalog::} debug command accepts either no arguments or exactly one argument (true/false or a log level)".to_string());
       }
 
       let arg = &args[0];
@@ -20,23 +20,33 @@ impl Command for DebugCommand {
         Value::Str(s) => match s.to_lowercase().as_str() {
           "true" => {
             ctx.set_debug_print(true);
-            println!("🐛 Debug printing enabled");
+            eprintln!("🐛 Debug printing enabled");
             return Ok(Value::Str("Debug printing enabled".to_string()));
           }
           "false" => {
             ctx.set_debug_print(false);
-            println!("🐛 Debug printing disabled");
+            eprintln!("🐛 Debug printing disabled");
             return Ok(Value::Str("Debug printing disabled".to_string()));
           }
-          _ => {
-            return Err(
-              "debug command argument must be 'true' or 'false'".to_string(),
-            );
-          }
+          other => match LogLevel::parse(other) {
+            Some(level) => {
+              ctx.set_log_level(level);
+              eprintln!("🐛 Log level set to: {}", level.as_str());
+              return Ok(Value::Str(format!(
+                "Log level set to: {}",
+                level.as_str()
+              )));
+            }
+            None => {
+              return Err(
+                "debug command argument must be 'true' or 'false' or a log level (off, error, info, debug)".to_string(),
+              );
+            }
+          },
         },
         _ => {
           return Err(
-            "debug command argument must be a string ('true' or 'false')"
+            "debug command argument must be a string ('true', 'false' or a log level)"
               .to_string(),
           );
         }
@@ -46,7 +56,7 @@ impl Command for DebugCommand {
     // Original behavior: print session variables - delegated to context
     let output = ctx.print_debug_info();
 
-    print!("{}", output);
+    eprint!("{}", output);
     Ok(Value::Str(output))
   }
 
@@ -55,14 +65,14 @@ impl Command for DebugCommand {
   }
 
   fn description(&self) -> &'static str {
-    "Print current program state or set debug printing true/false"
+    "Print current program state, set debug printing true/false or set the log level"
   }
 
   fn syntax(&self) -> &'static str {
-    "(debug) or (debug \"true\"|\"false\")"
+    "(debug) or (debug \"true\"|\"false\") or (debug \"off\"|\"error\"|\"info\"|\"debug\")"
   }
 
   fn examples(&self) -> &'static str {
-    "  (debug)          ; Print session variables\n  (debug \"true\")    ; Enable debug printing\n  (debug \"false\")   ; Disable debug printing"
+    "  (debug)          ; Print session variables\n  (debug \"true\")    ; Enable debug printing\n  (debug \"false\")   ; Disable debug printing\n  (debug \"info\")    ; Print only error and info messages"
   }
 }
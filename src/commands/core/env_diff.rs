@@ -0,0 +1,123 @@
+use crate::context::LogLevel;
+use crate::file_ops::read_env_file;
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Value, tags};
+use std::collections::{BTreeSet, HashMap};
+
+/// Register the env-diff command
+pub fn register_env_diff_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "env-diff",
+    "Compare two env files, listing keys only in the first, only in the second, and keys whose values differ",
+    "(env-diff file-a file-b)",
+    "  (env-diff \".env\" \".env.local\")\n  ; => ((\"only-a\" (\"A\")) (\"only-b\" (\"B\")) (\"changed\" ((\"PORT\" \"80\" \"8080\"))))",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "env-diff", "executing env-diff command");
+
+      if args.len() != 2 {
+        return Err("env-diff expects exactly two arguments (file paths)".to_string());
+      }
+
+      let mut envs = Vec::new();
+      for arg in &args {
+        let path = match arg {
+          Value::Str(s) => ctx.get_basedir().join(s),
+          _ => return Err("env-diff file paths must be strings".to_string()),
+        };
+        let env = read_env_file(&path.to_string_lossy())
+          .map_err(|e| format!("Failed to read file {}: {}", path.display(), e))?;
+        envs.push(env);
+      }
+
+      let diff = diff_env(&envs[0], &envs[1]);
+      debug_log(ctx, LogLevel::Info, "env-diff", &format!("diff: {}", diff));
+      Ok(diff)
+    },
+  );
+}
+
+/// Compares two parsed env files
+///
+/// # Returns
+/// * `Value` - `(("only-a" keys) ("only-b" keys) ("changed" ((key old new) ...)))`,
+///   each section sorted by key
+fn diff_env(a: &HashMap<String, String>, b: &HashMap<String, String>) -> Value {
+  let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+  let mut only_a = Vec::new();
+  let mut only_b = Vec::new();
+  let mut changed = Vec::new();
+
+  for key in keys {
+    match (a.get(key), b.get(key)) {
+      (Some(_), None) => only_a.push(Value::Str(key.clone())),
+      (None, Some(_)) => only_b.push(Value::Str(key.clone())),
+      (Some(old), Some(new)) if old != new => changed.push(Value::List(vec![
+        Value::Str(key.clone()),
+        Value::Str(old.clone()),
+        Value::Str(new.clone()),
+      ])),
+      _ => {}
+    }
+  }
+
+  let section = |name: &str, items: Vec<Value>| Value::List(vec![Value::Str(name.to_string()), Value::List(items)]);
+  Value::List(vec![
+    section("only-a", only_a),
+    section("only-b", only_b),
+    section("changed", changed),
+  ])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Context;
+  use std::fs;
+  use std::path::PathBuf;
+
+  fn s(value: &str) -> Value {
+    Value::Str(value.to_string())
+  }
+
+  #[test]
+  fn test_env_diff_overlapping_and_divergent_keys() {
+    let mut registry = CommandRegistry::new();
+    register_env_diff_command(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_env_diff");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("a.env"), "SHARED=same\nPORT=80\nHOST=a\nONLY_A=1\n").unwrap();
+    fs::write(dir.join("b.env"), "SHARED=same\nPORT=8080\nHOST=b\nONLY_B=2\nALSO_B=3\n").unwrap();
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![s("a.env"), s("b.env")];
+    let result = ctx.registry.get("env-diff").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(
+      result,
+      Value::List(vec![
+        Value::List(vec![s("only-a"), Value::List(vec![s("ONLY_A")])]),
+        Value::List(vec![s("only-b"), Value::List(vec![s("ALSO_B"), s("ONLY_B")])]),
+        Value::List(vec![
+          s("changed"),
+          Value::List(vec![
+            Value::List(vec![s("HOST"), s("a"), s("b")]),
+            Value::List(vec![s("PORT"), s("80"), s("8080")]),
+          ]),
+        ]),
+      ])
+    );
+
+    // Identical files produce empty sections
+    let args = vec![s("a.env"), s("a.env")];
+    let result = ctx.registry.get("env-diff").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(result.to_string(), "((only-a ()) (only-b ()) (changed ()))");
+
+    let args = vec![s("a.env"), s("missing.env")];
+    assert!(ctx.registry.get("env-diff").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
@@ -0,0 +1,108 @@
+use crate::{Command, Value, evaluate};
+use crate::context::Context;
+
+/// Eval command - runs a value built as data as an expression
+///
+/// The counterpart of `quote`: a list whose first element is a string is a
+/// call to the command of that name, nested lists are calls as well, and any
+/// other value evaluates to itself.
+pub struct EvalCommand;
+
+impl EvalCommand {
+    /// Converts a value back into the AST `evaluate` expects, turning the
+    /// head of every list into a command symbol
+    fn to_expression(value: &Value) -> Result<lexpr::Value, String> {
+        match value {
+            Value::List(items) if !items.is_empty() => {
+                let command = match &items[0] {
+                    Value::Str(name) => lexpr::Value::symbol(name.as_str()),
+                    other => {
+                        return Err(format!(
+                            "eval expects a command name as first element of {}, got {}",
+                            value, other
+                        ));
+                    }
+                };
+
+                let mut expression = lexpr::Value::Null;
+                for item in items[1..].iter().rev() {
+                    expression = lexpr::Value::cons(Self::to_expression(item)?, expression);
+                }
+                Ok(lexpr::Value::cons(command, expression))
+            }
+            other => Ok(other.to_lexpr()),
+        }
+    }
+}
+
+impl Command for EvalCommand {
+    fn execute(&self, args: Vec<Value>, ctx: &mut Context) -> Result<Value, String> {
+        match args.as_slice() {
+            [value] => evaluate(&Self::to_expression(value)?, ctx),
+            _ => Err("eval expects exactly one argument (expression)".to_string()),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "eval"
+    }
+
+    fn description(&self) -> &'static str {
+        "Evaluate a quoted or constructed expression"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(eval expression)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (eval (list \"sum\" 1 2 3))    ; Returns 6\n  (eval '(print \"hello\"))       ; Runs the quoted print"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::QuoteCommand;
+    use crate::commands::core::math::SumCommand;
+    use crate::commands::core::list_utils::register_list_commands;
+    use crate::commands::core::vars::register_var_commands;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_eval_constructed_expression() {
+        let mut registry = CommandRegistry::new();
+        registry.register(EvalCommand);
+        registry.register(QuoteCommand);
+        registry.register(SumCommand);
+        register_list_commands(&mut registry);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(eval (list \"sum\" 1 2 3))", &mut ctx).unwrap(), Value::Int(6));
+
+        // Round trip with quote, nested calls included
+        assert_eq!(evaluate_string("(eval '(sum 1 (sum 2 3)))", &mut ctx).unwrap(), Value::Int(6));
+        evaluate_string("(eval (list \"set-var\" \"STATUS\" \"done\"))", &mut ctx).unwrap();
+        assert_eq!(ctx.get_variable("STATUS"), Some(Value::Str("done".to_string())));
+
+        // Non-list values evaluate to themselves
+        assert_eq!(evaluate_string("(eval 5)", &mut ctx).unwrap(), Value::Int(5));
+        assert_eq!(evaluate_string("(eval \"text\")", &mut ctx).unwrap(), Value::Str("text".to_string()));
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let mut registry = CommandRegistry::new();
+        registry.register(EvalCommand);
+        registry.register(QuoteCommand);
+        registry.register(SumCommand);
+        register_list_commands(&mut registry);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let err = evaluate_string("(eval (list 1 2))", &mut ctx).unwrap_err();
+        assert!(err.contains("eval expects a command name"), "{}", err);
+        let err = evaluate_string("(eval (list \"no-such-command\"))", &mut ctx).unwrap_err();
+        assert!(err.contains("Unknown command: no-such-command"), "{}", err);
+        assert!(evaluate_string("(eval)", &mut ctx).is_err());
+    }
+}
@@ -1,8 +1,12 @@
+use crate::commands::core::read_env::interpolate_variables;
+use crate::context::LogLevel;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Value, tags};
 use regex::Regex;
 use std::fs;
 use std::path::Path;
+use std::time::SystemTime;
+use walkdir::WalkDir;
 
 /// Register filesystem-related core commands
 pub fn register_file_commands(registry: &mut CommandRegistry) {
@@ -14,7 +18,7 @@ pub fn register_file_commands(registry: &mut CommandRegistry) {
     "  (fs-list \"*.rs\"); List Rust source files in current dir\n  (fs-list \"config.*\")    ; List files starting with 'config.'",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "fs-list", "executing fs-list command");
+      debug_log(ctx, LogLevel::Debug, "fs-list", "executing fs-list command");
 
       if args.len() != 1 {
         return Err("fs-list expects exactly one argument (pattern string)".to_string());
@@ -25,7 +29,7 @@ pub fn register_file_commands(registry: &mut CommandRegistry) {
         _ => return Err("fs-list pattern must be a string".to_string()),
       };
 
-      debug_log(ctx, "fs-list", &format!("received pattern: {}", pattern));
+      debug_log(ctx, LogLevel::Debug, "fs-list", &format!("received pattern: {}", pattern));
 
       // Convert wildcard pattern (* and ?) to a regex
       let regex_str = wildcard_to_regex(&pattern);
@@ -34,7 +38,7 @@ pub fn register_file_commands(registry: &mut CommandRegistry) {
         Err(e) => return Err(format!("Invalid pattern after conversion to regex: {}", e)),
       };
 
-      debug_log(ctx, "fs-list", &format!("converted to regex: {}", regex_str));
+      debug_log(ctx, LogLevel::Debug, "fs-list", &format!("converted to regex: {}", regex_str));
 
       // Read current directory entries
       let mut results: Vec<Value> = Vec::new();
@@ -65,15 +69,147 @@ pub fn register_file_commands(registry: &mut CommandRegistry) {
             }
           }
           Err(e) => {
-            debug_log(ctx, "fs-list", &format!("failed to read a directory entry: {}", e));
+            debug_log(ctx, LogLevel::Error, "fs-list", &format!("failed to read a directory entry: {}", e));
           }
         }
       }
 
-      debug_log(ctx, "fs-list", &format!("matched {} files", count));
+      debug_log(ctx, LogLevel::Debug, "fs-list", &format!("matched {} files", count));
       Ok(Value::List(results))
     },
   );
+
+  // dir-stats command
+  registry.register_closure_with_help_and_tag(
+    "dir-stats",
+    "Count files, directories and total bytes of a directory tree",
+    "(dir-stats path)",
+    "  (dir-stats \"dev/docker/web\")   ; Returns ((\"files\" 12) (\"dirs\" 3) (\"bytes\" 40960))",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "dir-stats", "executing dir-stats command");
+
+      let path = match args.as_slice() {
+        [Value::Str(path)] => ctx.get_basedir().join(path),
+        [_] => return Err("dir-stats path must be a string".to_string()),
+        _ => return Err("dir-stats expects exactly one argument (directory path)".to_string()),
+      };
+
+      let (files, dirs, bytes) = dir_stats(&path)?;
+      debug_log(ctx, LogLevel::Info, "dir-stats", &format!("{}: {} files, {} dirs, {} bytes", path.display(), files, dirs, bytes));
+
+      let pair = |name: &str, count: u64| Value::List(vec![Value::Str(name.to_string()), Value::Int(count as i64)]);
+      Ok(Value::List(vec![pair("files", files), pair("dirs", dirs), pair("bytes", bytes)]))
+    },
+  );
+
+  // touch command
+  registry.register_closure_with_help_and_tag(
+    "touch",
+    "Create an empty file, or set the modification time of an existing one to now",
+    "(touch path)",
+    "  (touch \"build/.done\")   ; Creates build/ and the marker file if missing",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "touch", "executing touch command");
+
+      let path = match args.as_slice() {
+        [Value::Str(path)] => ctx.get_basedir().join(path),
+        [_] => return Err("touch path must be a string".to_string()),
+        _ => return Err("touch expects exactly one argument (file path)".to_string()),
+      };
+
+      let created = touch_file(&path)?;
+      let message = if created {
+        format!("Created {}", path.display())
+      } else {
+        format!("Updated {}", path.display())
+      };
+      debug_log(ctx, LogLevel::Info, "touch", &message);
+
+      Ok(Value::Str(message))
+    },
+  );
+
+  // render-template command
+  registry.register_closure_with_help_and_tag(
+    "render-template",
+    "Render a template file with ${KEY} interpolation into a destination file",
+    "(render-template source destination)",
+    "  (render-template \"compose.tpl.yml\" \"docker-compose.yml\")   ; Fill ${KEY} from context variables, then the process env",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "render-template", "executing render-template command");
+
+      let (source, destination) = match args.as_slice() {
+        [Value::Str(source), Value::Str(destination)] => {
+          (ctx.get_basedir().join(source), ctx.get_basedir().join(destination))
+        }
+        [_, _] => return Err("render-template paths must be strings".to_string()),
+        _ => return Err("render-template expects exactly two arguments (source, destination)".to_string()),
+      };
+
+      let template = fs::read_to_string(&source)
+        .map_err(|e| format!("Failed to read template {}: {}", source.display(), e))?;
+      let rendered = interpolate_variables(&template, ctx)?;
+
+      if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)
+          .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+      }
+      fs::write(&destination, &rendered)
+        .map_err(|e| format!("Failed to write {}: {}", destination.display(), e))?;
+
+      let message = format!("Rendered {} to {}", source.display(), destination.display());
+      debug_log(ctx, LogLevel::Info, "render-template", &message);
+
+      Ok(Value::Str(message))
+    },
+  );
+}
+
+/// Creates `path` (and its parent directories) or bumps its modification time
+/// Returns whether the file was created
+fn touch_file(path: &Path) -> Result<bool, String> {
+  if path.is_dir() {
+    return Err(format!("'{}' is a directory", path.display()));
+  }
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)
+      .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+  }
+
+  let created = !path.exists();
+  let file = fs::OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(path)
+    .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+  file
+    .set_modified(SystemTime::now())
+    .map_err(|e| format!("Failed to update modification time of {}: {}", path.display(), e))?;
+
+  Ok(created)
+}
+
+/// Counts files, subdirectories and total file size below `dir` in a single
+/// walk; `dir` itself is not counted and unreadable entries are skipped
+fn dir_stats(dir: &Path) -> Result<(u64, u64, u64), String> {
+  if !dir.is_dir() {
+    return Err(format!("'{}' is not a directory", dir.display()));
+  }
+
+  let (mut files, mut dirs, mut bytes) = (0, 0, 0);
+  for entry in WalkDir::new(dir).min_depth(1).into_iter().filter_map(|e| e.ok()) {
+    if entry.file_type().is_dir() {
+      dirs += 1;
+    } else if entry.file_type().is_file() {
+      files += 1;
+      bytes += entry.metadata().map(|m| m.len()).unwrap_or(0);
+    }
+  }
+
+  Ok((files, dirs, bytes))
 }
 
 /// Convert a shell-like wildcard pattern to a regular expression string.
@@ -98,3 +234,93 @@ fn wildcard_to_regex(pattern: &str) -> String {
   regex.push('$');
   regex
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Context;
+  use std::path::PathBuf;
+
+  #[test]
+  fn test_dir_stats_command() {
+    let dir = PathBuf::from("target").join("test_dir_stats");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("a/b")).unwrap();
+    fs::create_dir_all(dir.join("empty")).unwrap();
+    fs::write(dir.join("root.txt"), "12345").unwrap();
+    fs::write(dir.join("a/one.txt"), "abc").unwrap();
+    fs::write(dir.join("a/b/two.txt"), "").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_file_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![Value::Str(".".to_string())];
+    let result = ctx.registry.get("dir-stats").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(result.to_string(), "((files 3) (dirs 3) (bytes 8))");
+
+    let args = vec![Value::Str("root.txt".to_string())];
+    let err = ctx.registry.get("dir-stats").unwrap().execute(args, &mut ctx).unwrap_err();
+    assert!(err.contains("is not a directory"), "{}", err);
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_touch_creates_and_updates() {
+    let dir = PathBuf::from("target").join("test_touch");
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut registry = CommandRegistry::new();
+    register_file_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(dir.clone());
+
+    // Missing file and parent directory are created
+    let args = vec![Value::Str("nested/.done".to_string())];
+    let result = ctx.registry.get("touch").unwrap().execute(args.clone(), &mut ctx).unwrap();
+    assert!(result.to_string().starts_with("Created"), "{}", result);
+    let marker = dir.join("nested/.done");
+    assert_eq!(fs::read(&marker).unwrap(), Vec::<u8>::new());
+
+    // An existing file keeps its content and gets a fresh mtime
+    fs::write(&marker, "content").unwrap();
+    let old = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(946_684_800);
+    fs::File::options().write(true).open(&marker).unwrap().set_modified(old).unwrap();
+
+    let result = ctx.registry.get("touch").unwrap().execute(args, &mut ctx).unwrap();
+    assert!(result.to_string().starts_with("Updated"), "{}", result);
+    assert_eq!(fs::read_to_string(&marker).unwrap(), "content");
+    assert!(fs::metadata(&marker).unwrap().modified().unwrap() > old);
+
+    let args = vec![Value::Str("nested".to_string())];
+    assert!(ctx.registry.get("touch").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_render_template() {
+    let dir = PathBuf::from("target").join("test_render_template");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("app.tpl"), "image: ${IMAGE}:${TAG}\nports: ${DPM_TEST_UNSET_PORTS:-80}\n").unwrap();
+
+    let mut registry = CommandRegistry::new();
+    register_file_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(dir.clone());
+    ctx.set_variable("IMAGE".to_string(), Value::Str("nginx".to_string()));
+    ctx.set_variable("TAG".to_string(), Value::Str("1.27".to_string()));
+
+    let args = vec![Value::Str("app.tpl".to_string()), Value::Str("out/app.yml".to_string())];
+    ctx.registry.get("render-template").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(fs::read_to_string(dir.join("out/app.yml")).unwrap(), "image: nginx:1.27\nports: 80\n");
+
+    let args = vec![Value::Str("missing.tpl".to_string()), Value::Str("out.yml".to_string())];
+    assert!(ctx.registry.get("render-template").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
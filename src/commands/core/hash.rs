@@ -0,0 +1,150 @@
+use crate::file_ops::compute_file_md5;
+use crate::{CommandRegistry, Value};
+use md5::{Digest, Md5};
+use sha2::Sha256;
+
+/// Register the string and file hashing commands
+pub fn register_hash_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help(
+    "md5-string",
+    "Hex MD5 digest of a string, optionally truncated to the first length characters",
+    "(md5-string text [length])",
+    "  (md5-string \"hello\")     ; Returns \"5d41402abc4b2a76b9719d911017c592\"\n  (md5-string \"hello\" 8)   ; Returns \"5d41402a\", like directory checksums",
+    |args, _ctx| {
+      let (text, length) = hash_args("md5-string", &args)?;
+      Ok(Value::Str(truncate_digest(format!("{:x}", Md5::digest(text.as_bytes())), length)))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "sha256-string",
+    "Hex SHA-256 digest of a string, optionally truncated to the first length characters",
+    "(sha256-string text [length])",
+    "  (sha256-string \"hello\")      ; Returns the 64-character digest\n  (sha256-string \"hello\" 12)   ; Returns \"2cf24dba5fb0\"",
+    |args, _ctx| {
+      let (text, length) = hash_args("sha256-string", &args)?;
+      Ok(Value::Str(truncate_digest(format!("{:x}", Sha256::digest(text.as_bytes())), length)))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "md5-file",
+    "Hex MD5 digest of a file's content, relative to the base directory, optionally truncated",
+    "(md5-file path [length])",
+    "  (md5-file \"docker/app/Dockerfile\")     ; Full 32-character digest\n  (md5-file \"docker/app/Dockerfile\" 8)   ; Short form, like directory checksums",
+    |args, ctx| {
+      let (path, length) = hash_args("md5-file", &args)?;
+      let file_path = ctx.get_basedir().join(path);
+      let digest = compute_file_md5(&file_path.to_string_lossy())
+        .map_err(|e| format!("md5-file failed: {}", e))?;
+      Ok(Value::Str(truncate_digest(digest, length)))
+    },
+  );
+}
+
+/// Validates `(command text [length])` arguments
+fn hash_args<'a>(name: &str, args: &'a [Value]) -> Result<(&'a str, Option<usize>), String> {
+  let (text, length) = match args {
+    [text] => (text, None),
+    [text, length] => (text, Some(length)),
+    _ => return Err(format!("{} expects a string and an optional length", name)),
+  };
+
+  let text = match text {
+    Value::Str(s) => s.as_str(),
+    _ => return Err(format!("{} expects a string as first argument", name)),
+  };
+
+  let length = match length {
+    None => None,
+    Some(Value::Int(n)) if *n > 0 => Some(*n as usize),
+    Some(_) => return Err(format!("{} length must be a positive integer", name)),
+  };
+
+  Ok((text, length))
+}
+
+/// Keeps the first `length` characters of a hex digest; longer lengths return it whole
+fn truncate_digest(digest: String, length: Option<usize>) -> String {
+  match length {
+    Some(n) if n < digest.len() => digest[..n].to_string(),
+    _ => digest,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Context, evaluate_string};
+
+  fn s(value: &str) -> Value {
+    Value::Str(value.to_string())
+  }
+
+  #[test]
+  fn test_md5_string_known_digest() {
+    let mut registry = CommandRegistry::new();
+    register_hash_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(
+      evaluate_string("(md5-string \"hello\")", &mut ctx).unwrap(),
+      s("5d41402abc4b2a76b9719d911017c592")
+    );
+    assert_eq!(evaluate_string("(md5-string \"hello\" 8)", &mut ctx).unwrap(), s("5d41402a"));
+    assert_eq!(
+      evaluate_string("(md5-string \"\")", &mut ctx).unwrap(),
+      s("d41d8cd98f00b204e9800998ecf8427e")
+    );
+  }
+
+  #[test]
+  fn test_sha256_string_known_digest() {
+    let mut registry = CommandRegistry::new();
+    register_hash_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(
+      evaluate_string("(sha256-string \"hello\")", &mut ctx).unwrap(),
+      s("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824")
+    );
+    assert_eq!(evaluate_string("(sha256-string \"hello\" 12)", &mut ctx).unwrap(), s("2cf24dba5fb0"));
+    // Lengths beyond the digest return it whole
+    let full = evaluate_string("(sha256-string \"hello\" 100)", &mut ctx).unwrap();
+    assert_eq!(full.to_string().len(), 64);
+  }
+
+  #[test]
+  fn test_hash_string_errors() {
+    let mut registry = CommandRegistry::new();
+    register_hash_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert!(evaluate_string("(md5-string)", &mut ctx).is_err());
+    assert!(evaluate_string("(md5-string 42)", &mut ctx).is_err());
+    assert!(evaluate_string("(sha256-string \"a\" 0)", &mut ctx).is_err());
+    assert!(evaluate_string("(sha256-string \"a\" \"8\")", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_md5_file_known_content() {
+    let mut registry = CommandRegistry::new();
+    register_hash_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let dir = std::env::temp_dir().join("dpm_test_md5_file");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("Dockerfile"), "hello").unwrap();
+    ctx.set_basedir(dir.clone());
+
+    assert_eq!(
+      evaluate_string("(md5-file \"Dockerfile\")", &mut ctx).unwrap(),
+      s("5d41402abc4b2a76b9719d911017c592")
+    );
+    assert_eq!(evaluate_string("(md5-file \"Dockerfile\" 8)", &mut ctx).unwrap(), s("5d41402a"));
+
+    // Directories and missing paths are rejected
+    let err = evaluate_string("(md5-file \".\")", &mut ctx).unwrap_err();
+    assert!(err.contains("is not a file"), "{}", err);
+    assert!(evaluate_string("(md5-file \"missing\")", &mut ctx).is_err());
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
@@ -1,4 +1,85 @@
 use crate::{CommandRegistry, Value};
+use serde_json::json;
+
+/// Formats the detailed command reference printed by `help-long`
+pub fn format_help_long(registry: &CommandRegistry) -> String {
+  let tag_groups = registry.get_commands_grouped_by_tags_with_help();
+
+  let mut help_text =
+    String::from("=== DETAILED COMMAND REFERENCE ===\n\n");
+
+  for (tag, commands) in tag_groups {
+    help_text.push_str(&format!("=== {} ===\n\n", tag.text));
+    for (name, description, syntax, examples) in commands {
+      help_text.push_str(&format!("Command: {}\n", name));
+      help_text.push_str(&format!("Description: {}\n", description));
+      help_text.push_str(&format!("Syntax: {}\n", syntax));
+      help_text.push_str("Examples:\n");
+      help_text.push_str(&format!("{}\n", examples));
+      help_text.push_str("\n");
+    }
+    help_text.push_str("\n");
+  }
+
+  help_text.push_str("=== GENERAL USAGE ===\n");
+  help_text
+    .push_str("All commands use Lisp-style syntax with parentheses:\n");
+  help_text.push_str("  (command-name arg1 arg2 ...)\n\n");
+  help_text.push_str("Commands can be nested:\n");
+  help_text
+    .push_str("  (print (sum 1 2 3))  ; Prints the result of sum\n\n");
+  help_text.push_str("Multiple expressions can be evaluated:\n");
+  help_text.push_str("  ./dpm '(sum 1 2 3)' '(print \"Hello\")'\n");
+
+  help_text
+}
+
+/// Formats the command overview printed by `help` and `--help`
+pub fn format_help_short(registry: &CommandRegistry) -> String {
+  let tag_groups = registry.get_commands_grouped_by_tags();
+
+  let mut help_text = String::from("Available commands:\n\n");
+  for (tag, commands) in tag_groups {
+    help_text.push_str(&format!("=== {} ===\n", tag.text));
+    for (name, description) in commands {
+      help_text.push_str(&format!("  {:<12} - {}\n", name, description));
+    }
+    help_text.push_str("\n");
+  }
+  help_text.push_str("Use (help \"command-name\") for detailed help on a specific command.\n");
+
+  help_text
+}
+
+/// Serializes the command catalog printed by `help-json`
+pub fn format_help_json(registry: &CommandRegistry) -> Result<String, String> {
+  let tag_groups = registry.get_commands_grouped_by_tags_with_help();
+
+  let catalog: Vec<serde_json::Value> = tag_groups
+    .into_iter()
+    .map(|(tag, commands)| {
+      let commands: Vec<serde_json::Value> = commands
+        .into_iter()
+        .map(|(name, description, syntax, examples)| {
+          json!({
+            "name": name,
+            "description": description,
+            "syntax": syntax,
+            "examples": examples,
+          })
+        })
+        .collect();
+      json!({
+        "tag": tag.name,
+        "order": tag.order,
+        "commands": commands,
+      })
+    })
+    .collect();
+
+  serde_json::to_string_pretty(&catalog)
+    .map_err(|e| format!("Failed to serialize command catalog: {}", e))
+}
 
 /// Register help commands
 pub fn register_help_commands(registry: &mut CommandRegistry) {
@@ -30,7 +111,7 @@ pub fn register_help_commands(registry: &mut CommandRegistry) {
               command.syntax(),
               command.examples()
             );
-            println!("{}", help_text);
+            eprintln!("{}", help_text);
             Ok(Value::Str(help_text))
           }
           None => {
@@ -40,21 +121,106 @@ pub fn register_help_commands(registry: &mut CommandRegistry) {
         }
       } else {
         // No arguments provided, show short help for all commands
-        let tag_groups = ctx.registry.get_commands_grouped_by_tags();
+        let help_text = format_help_short(&ctx.registry);
+        eprintln!("{}", help_text);
+        Ok(Value::Str(help_text))
+      }
+    },
+  );
 
-        let mut help_text = String::from("Available commands:\n\n");
-        for (tag, commands) in tag_groups {
-          help_text.push_str(&format!("=== {} ===\n", tag.text));
-          for (name, description) in commands {
-            help_text.push_str(&format!("  {:<12} - {}\n", name, description));
-          }
-          help_text.push_str("\n");
+  registry.register_closure_with_help(
+    "help-search",
+    "Search command names and descriptions for a case-insensitive substring",
+    "(help-search query)",
+    "  (help-search \"docker\")  ; Shows the commands mentioning docker, grouped by tag",
+    |args, ctx| {
+      if args.len() != 1 {
+        return Err("help-search expects exactly one argument".to_string());
+      }
+
+      let query = match &args[0] {
+        Value::Str(query) => query.to_lowercase(),
+        _ => return Err("Search query must be a string".to_string()),
+      };
+
+      let tag_groups = ctx.registry.get_commands_grouped_by_tags();
+
+      let mut help_text = format!("Commands matching '{}':\n\n", query);
+      let mut matches = 0;
+      for (tag, commands) in tag_groups {
+        let matching: Vec<(String, String)> = commands
+          .into_iter()
+          .filter(|(name, description)| {
+            name.to_lowercase().contains(&query)
+              || description.to_lowercase().contains(&query)
+          })
+          .collect();
+        if matching.is_empty() {
+          continue;
         }
-        help_text.push_str("Use (help \"command-name\") for detailed help on a specific command.\n");
 
-        println!("{}", help_text);
-        Ok(Value::Str(help_text))
+        help_text.push_str(&format!("=== {} ===\n", tag.text));
+        for (name, description) in matching {
+          help_text.push_str(&format!("  {:<12} - {}\n", name, description));
+          matches += 1;
+        }
+        help_text.push('\n');
+      }
+
+      if matches == 0 {
+        help_text.push_str("  (no matching commands)\n");
+      }
+
+      eprintln!("{}", help_text);
+      Ok(Value::Str(help_text))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "help-json",
+    "Print the command catalog as JSON, grouped by tag",
+    "(help-json)",
+    "  (help-json)         ; Prints [{\"tag\", \"order\", \"commands\": [{\"name\", \"description\", \"syntax\", \"examples\"}]}]",
+    |args, ctx| {
+      if !args.is_empty() {
+        return Err("help-json expects no arguments".to_string());
+      }
+
+      let output = format_help_json(&ctx.registry)?;
+
+      // The catalog is the command's result, so it goes to stdout
+      println!("{}", output);
+      Ok(Value::Str(output))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "registry-stats",
+    "Count registered commands, in total and per tag",
+    "(registry-stats)",
+    "  (registry-stats)    ; Returns ((\"total\" 120) (\"by-tag\" ((\"commands\" 12) (\"core\" 80) ...)))",
+    |args, ctx| {
+      if !args.is_empty() {
+        return Err("registry-stats expects no arguments".to_string());
       }
+
+      let tag_groups = ctx.registry.get_commands_grouped_by_tags();
+
+      let total: usize = tag_groups.iter().map(|(_, commands)| commands.len()).sum();
+      let by_tag: Vec<Value> = tag_groups
+        .iter()
+        .map(|(tag, commands)| {
+          Value::List(vec![
+            Value::Str(tag.name.to_string()),
+            Value::Int(commands.len() as i64),
+          ])
+        })
+        .collect();
+
+      Ok(Value::List(vec![
+        Value::List(vec![Value::Str("total".to_string()), Value::Int(total as i64)]),
+        Value::List(vec![Value::Str("by-tag".to_string()), Value::List(by_tag)]),
+      ]))
     },
   );
 
@@ -68,36 +234,107 @@ pub fn register_help_commands(registry: &mut CommandRegistry) {
         return Err("help-long expects no arguments".to_string());
       }
 
-      let tag_groups = ctx.registry.get_commands_grouped_by_tags_with_help();
-
-      let mut help_text =
-        String::from("=== DETAILED COMMAND REFERENCE ===\n\n");
-
-      for (tag, commands) in tag_groups {
-        help_text.push_str(&format!("=== {} ===\n\n", tag.text));
-        for (name, description, syntax, examples) in commands {
-          help_text.push_str(&format!("Command: {}\n", name));
-          help_text.push_str(&format!("Description: {}\n", description));
-          help_text.push_str(&format!("Syntax: {}\n", syntax));
-          help_text.push_str("Examples:\n");
-          help_text.push_str(&format!("{}\n", examples));
-          help_text.push_str("\n");
-        }
-        help_text.push_str("\n");
-      }
+      let help_text = format_help_long(&ctx.registry);
 
-      help_text.push_str("=== GENERAL USAGE ===\n");
-      help_text
-        .push_str("All commands use Lisp-style syntax with parentheses:\n");
-      help_text.push_str("  (command-name arg1 arg2 ...)\n\n");
-      help_text.push_str("Commands can be nested:\n");
-      help_text
-        .push_str("  (print (sum 1 2 3))  ; Prints the result of sum\n\n");
-      help_text.push_str("Multiple expressions can be evaluated:\n");
-      help_text.push_str("  ./dpm '(sum 1 2 3)' '(print \"Hello\")'\n");
-
-      println!("{}", help_text);
+      eprintln!("{}", help_text);
       Ok(Value::Str(help_text))
     },
   );
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::commands::core::math::SumCommand;
+  use crate::context::Context;
+  use crate::evaluate_string;
+
+  #[test]
+  fn test_help_search_matches_names_and_descriptions() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_help_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = evaluate_string("(help-search \"SUM\")", &mut ctx).unwrap();
+    assert!(result.to_string().contains("sum "));
+    assert!(!result.to_string().contains("help-long"));
+
+    // "detailed" only appears in the descriptions of the help commands
+    let result = evaluate_string("(help-search \"detailed\")", &mut ctx).unwrap();
+    assert!(result.to_string().contains("help-long"));
+    assert!(!result.to_string().contains("sum "));
+  }
+
+  #[test]
+  fn test_help_json_round_trip() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_help_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = evaluate_string("(help-json)", &mut ctx).unwrap();
+    let catalog: serde_json::Value =
+      serde_json::from_str(&result.to_string()).unwrap();
+
+    let groups = catalog.as_array().unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]["tag"], "core");
+    assert_eq!(groups[0]["order"], 1000);
+    assert_eq!(groups[1]["tag"], "math");
+    assert_eq!(groups[1]["order"], 1001);
+
+    let math_commands = groups[1]["commands"].as_array().unwrap();
+    let sum = math_commands.iter().find(|c| c["name"] == "sum").unwrap();
+    assert_eq!(sum["description"], "Sum a list of integers");
+
+    // Multi-line examples survive escaping intact
+    let commands = groups[0]["commands"].as_array().unwrap();
+    let help = commands.iter().find(|c| c["name"] == "help").unwrap();
+    let examples = help["examples"].as_str().unwrap();
+    assert!(examples.contains('\n'));
+    assert_eq!(examples, ctx.registry.get("help").unwrap().examples());
+
+    assert!(evaluate_string("(help-json 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_registry_stats_counts_commands() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    registry.register_closure_with_help_and_tag(
+      "rusty",
+      "A command tagged rust",
+      "(rusty)",
+      "",
+      &crate::tags::RUST,
+      |_args, _ctx| Ok(Value::Nil),
+    );
+    register_help_commands(&mut registry);
+    let registered = registry.list_commands().len();
+    let mut ctx = Context::new(registry);
+
+    // Everything but "sum" and "rusty" is tagged core
+    let result = evaluate_string("(registry-stats)", &mut ctx).unwrap();
+    assert_eq!(
+      result.to_string(),
+      format!("((total {}) (by-tag ((core {}) (math 1) (rust 1))))", registered, registered - 2)
+    );
+    assert!(evaluate_string("(registry-stats 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_help_search_without_matches() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_help_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = evaluate_string("(help-search \"no-such-thing\")", &mut ctx).unwrap();
+    assert!(result.to_string().contains("(no matching commands)"));
+    assert!(!result.to_string().contains("sum "));
+
+    assert!(evaluate_string("(help-search)", &mut ctx).is_err());
+    assert!(evaluate_string("(help-search 1)", &mut ctx).is_err());
+  }
+}
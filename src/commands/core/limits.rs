@@ -0,0 +1,53 @@
+use crate::context::LogLevel;
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Value, tags};
+
+/// Register interpreter limit commands
+pub fn register_limit_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "max-eval-depth",
+    "Get or set the maximum nesting depth of evaluated expressions",
+    "(max-eval-depth [depth])",
+    "  (max-eval-depth)        ; Returns the current limit (1000 by default)\n  (max-eval-depth 5000)   ; Allow deeper recursion, returns the new limit",
+    &tags::CORE,
+    |args, ctx| {
+      match args.as_slice() {
+        [] => {}
+        [Value::Int(depth)] if *depth > 0 => {
+          ctx.max_eval_depth = *depth as usize;
+          debug_log(ctx, LogLevel::Debug, "max-eval-depth", &format!("evaluation depth limit set to {}", depth));
+        }
+        [_] => return Err("max-eval-depth expects a positive integer".to_string()),
+        _ => return Err("max-eval-depth expects at most one argument".to_string()),
+      }
+      Ok(Value::Int(ctx.max_eval_depth as i64))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::commands::core::math::SumCommand;
+  use crate::context::{Context, DEFAULT_MAX_EVAL_DEPTH};
+  use crate::evaluate_string;
+
+  #[test]
+  fn test_max_eval_depth() {
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_limit_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = evaluate_string("(max-eval-depth)", &mut ctx).unwrap();
+    assert_eq!(result, Value::Int(DEFAULT_MAX_EVAL_DEPTH as i64));
+
+    assert_eq!(evaluate_string("(max-eval-depth 3)", &mut ctx).unwrap(), Value::Int(3));
+    assert_eq!(evaluate_string("(sum (sum (sum 1)))", &mut ctx).unwrap(), Value::Int(1));
+    assert!(evaluate_string("(sum (sum (sum (sum 1))))", &mut ctx).is_err());
+
+    assert!(evaluate_string("(max-eval-depth 0)", &mut ctx).is_err());
+    assert!(evaluate_string("(max-eval-depth \"10\")", &mut ctx).is_err());
+    assert_eq!(ctx.get_variable("CTX:max_eval_depth"), Some(Value::Int(3)));
+  }
+}
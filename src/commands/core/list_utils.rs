@@ -1,4 +1,74 @@
+use crate::context::Context;
 use crate::{CommandRegistry, Value};
+use std::cmp::Ordering;
+
+/// Stable merge sort with a comparator that may fail
+///
+/// Unlike `slice::sort_by` it never panics on an inconsistent comparator,
+/// and it stops at the first comparator error.
+fn merge_sort_by<F>(items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>, String>
+where
+  F: FnMut(&Value, &Value) -> Result<Ordering, String>,
+{
+  if items.len() <= 1 {
+    return Ok(items);
+  }
+
+  let mut left = items;
+  let right = left.split_off(left.len() / 2);
+  let left = merge_sort_by(left, compare)?;
+  let right = merge_sort_by(right, compare)?;
+
+  let mut merged = Vec::with_capacity(left.len() + right.len());
+  let mut left = left.into_iter().peekable();
+  let mut right = right.into_iter().peekable();
+  while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+    // Take from the left on ties to keep the sort stable
+    if compare(r, l)? == Ordering::Less {
+      merged.push(right.next().unwrap());
+    } else {
+      merged.push(left.next().unwrap());
+    }
+  }
+  merged.extend(left);
+  merged.extend(right);
+  Ok(merged)
+}
+
+/// Natural ordering for `list-sort`: integers with integers, strings with strings
+fn compare_values(a: &Value, b: &Value) -> Result<Ordering, String> {
+  match (a, b) {
+    (Value::Int(a), Value::Int(b)) => Ok(a.cmp(b)),
+    (Value::Str(a), Value::Str(b)) => Ok(a.cmp(b)),
+    _ => Err(format!(
+      "list-sort expects a list of only integers or only strings, got {} and {}",
+      a, b
+    )),
+  }
+}
+
+/// Orders two values with a named comparator command returning -1, 0 or 1
+fn compare_with_command(
+  comparator: &str,
+  a: &Value,
+  b: &Value,
+  ctx: &mut Context,
+) -> Result<Ordering, String> {
+  let command = ctx
+    .registry
+    .get(comparator)
+    .ok_or_else(|| format!("Unknown comparator command: {}", comparator))?;
+
+  match command.execute(vec![a.clone(), b.clone()], ctx)? {
+    Value::Int(n) if n < 0 => Ok(Ordering::Less),
+    Value::Int(0) => Ok(Ordering::Equal),
+    Value::Int(_) => Ok(Ordering::Greater),
+    other => Err(format!(
+      "Comparator '{}' must return an integer (-1, 0 or 1), got {}",
+      comparator, other
+    )),
+  }
+}
 
 /// Register list utility commands
 pub fn register_list_commands(registry: &mut CommandRegistry) {
@@ -57,4 +127,428 @@ pub fn register_list_commands(registry: &mut CommandRegistry) {
             }
         }
     );
+
+  registry.register_closure_with_help(
+        "list-sort",
+        "Sort a list of integers or a list of strings in ascending order",
+        "(list-sort list)",
+        "  (list-sort (list 3 1 2))           ; Returns [1, 2, 3]\n  (list-sort (fs-list \"*.env\"))      ; Reproducible file order",
+        |args, _ctx| {
+            if args.len() != 1 {
+                return Err("list-sort expects exactly one argument".to_string());
+            }
+
+            match &args[0] {
+                Value::List(list) => {
+                    // Type errors are reported even for lists too short to compare
+                    if let Some(first) = list.first() {
+                        for item in list {
+                            compare_values(first, item)?;
+                        }
+                    }
+                    Ok(Value::List(merge_sort_by(list.clone(), &mut compare_values)?))
+                }
+                _ => Err("list-sort expects a list argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-sort-by",
+        "Sort a list using a named comparator command that returns -1, 0 or 1",
+        "(list-sort-by comparator-name list)",
+        "  (list-sort-by \"compare-length\" (list \"ccc\" \"a\" \"bb\"))  ; Sort with a custom comparator",
+        |args, ctx| {
+            if args.len() != 2 {
+                return Err("list-sort-by expects exactly two arguments (comparator name and list)".to_string());
+            }
+
+            let comparator = match &args[0] {
+                Value::Str(name) => name.clone(),
+                _ => return Err("list-sort-by comparator name must be a string".to_string()),
+            };
+
+            match &args[1] {
+                Value::List(list) => {
+                    let mut compare = |a: &Value, b: &Value| compare_with_command(&comparator, a, b, ctx);
+                    Ok(Value::List(merge_sort_by(list.clone(), &mut compare)?))
+                }
+                _ => Err("list-sort-by expects a list as second argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-contains",
+        "Check whether a list contains a value",
+        "(list-contains list value)",
+        "  (list-contains (list 1 2 3) 2)              ; Returns true\n  (list-contains (fs-list \"*.env\") \".env\")    ; Check for a file",
+        |args, _ctx| {
+            if args.len() != 2 {
+                return Err("list-contains expects exactly two arguments (list and value)".to_string());
+            }
+
+            match &args[0] {
+                Value::List(list) => Ok(Value::Bool(list.contains(&args[1]))),
+                _ => Err("list-contains expects a list as first argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-index-of",
+        "Get the zero-based index of the first occurrence of a value in a list, or -1",
+        "(list-index-of list value)",
+        "  (list-index-of (list \"a\" \"b\") \"b\")  ; Returns 1\n  (list-index-of (list 1 2) 3)         ; Returns -1",
+        |args, _ctx| {
+            if args.len() != 2 {
+                return Err("list-index-of expects exactly two arguments (list and value)".to_string());
+            }
+
+            match &args[0] {
+                Value::List(list) => Ok(Value::Int(
+                    list.iter()
+                        .position(|item| *item == args[1])
+                        .map(|index| index as i64)
+                        .unwrap_or(-1),
+                )),
+                _ => Err("list-index-of expects a list as first argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-append",
+        "Return a new list with a value added at the end",
+        "(list-append list value)",
+        "  (list-append (list 1 2) 3)        ; Returns [1, 2, 3]\n  (list-append (list) \"a\")          ; Returns [\"a\"]",
+        |args, _ctx| {
+            if args.len() != 2 {
+                return Err("list-append expects exactly two arguments (list and value)".to_string());
+            }
+
+            match &args[0] {
+                Value::List(list) => {
+                    let mut result = list.clone();
+                    result.push(args[1].clone());
+                    Ok(Value::List(result))
+                }
+                _ => Err("list-append expects a list as first argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-concat",
+        "Concatenate several lists into a single new list",
+        "(list-concat list1 list2 ...)",
+        "  (list-concat (list 1 2) (list 3) (list 4 5))  ; Returns [1, 2, 3, 4, 5]",
+        |args, _ctx| {
+            let mut result = Vec::new();
+            for (index, arg) in args.iter().enumerate() {
+                match arg {
+                    Value::List(list) => result.extend(list.iter().cloned()),
+                    _ => return Err(format!("list-concat expects only list arguments, argument {} is {}", index + 1, arg)),
+                }
+            }
+            Ok(Value::List(result))
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-reverse",
+        "Return a new list with the elements in reverse order",
+        "(list-reverse list)",
+        "  (list-reverse (list 1 2 3))  ; Returns [3, 2, 1]",
+        |args, _ctx| {
+            if args.len() != 1 {
+                return Err("list-reverse expects exactly one argument".to_string());
+            }
+
+            match &args[0] {
+                Value::List(list) => Ok(Value::List(list.iter().rev().cloned().collect())),
+                _ => Err("list-reverse expects a list argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-unique",
+        "Remove duplicate values from a list, keeping the first occurrence of each",
+        "(list-unique list)",
+        "  (list-unique (list 3 1 3 2 1))  ; Returns [3, 1, 2]",
+        |args, _ctx| {
+            if args.len() != 1 {
+                return Err("list-unique expects exactly one argument".to_string());
+            }
+
+            match &args[0] {
+                Value::List(list) => {
+                    // Value is not hashable, so duplicates are found by equality
+                    let mut result: Vec<Value> = Vec::new();
+                    for item in list {
+                        if !result.contains(item) {
+                            result.push(item.clone());
+                        }
+                    }
+                    Ok(Value::List(result))
+                }
+                _ => Err("list-unique expects a list argument".to_string()),
+            }
+        }
+    );
+
+  registry.register_closure_with_help(
+        "list-reduce",
+        "Fold a list from left to right with a named two-argument command",
+        "(list-reduce command-name initial list)",
+        "  (list-reduce \"sum\" 0 (list 1 2 3))          ; Returns 6\n  (list-reduce \"concat\" \"\" (list \"a\" \"b\"))   ; Returns \"ab\"",
+        |args, ctx| {
+            if args.len() != 3 {
+                return Err("list-reduce expects exactly three arguments (command name, initial value and list)".to_string());
+            }
+
+            let reducer_name = match &args[0] {
+                Value::Str(name) => name.clone(),
+                _ => return Err("list-reduce command name must be a string".to_string()),
+            };
+            let list = match &args[2] {
+                Value::List(list) => list.clone(),
+                _ => return Err("list-reduce expects a list as third argument".to_string()),
+            };
+
+            let reducer = ctx
+                .registry
+                .get(&reducer_name)
+                .ok_or_else(|| format!("Unknown reducer command: {}", reducer_name))?;
+
+            let mut accumulator = args[1].clone();
+            for (index, element) in list.into_iter().enumerate() {
+                accumulator = reducer
+                    .execute(vec![accumulator, element], ctx)
+                    .map_err(|e| format!("list-reduce failed at element {}: {}", index, e))?;
+            }
+            Ok(accumulator)
+        }
+    );
+
+  registry.register_closure_with_help(
+        "range",
+        "Create a list of integers from start (inclusive) to end (exclusive) with an optional step",
+        "(range start end [step])",
+        "  (range 0 5)        ; Returns [0, 1, 2, 3, 4]\n  (range 10 0 -3)    ; Returns [10, 7, 4, 1]\n  (range 5 0)        ; Returns [] (the default step is 1)",
+        |args, _ctx| {
+            if args.len() < 2 || args.len() > 3 {
+                return Err("range expects a start, an end and an optional step".to_string());
+            }
+
+            let mut bounds = Vec::new();
+            for arg in &args {
+                match arg {
+                    Value::Int(i) => bounds.push(*i),
+                    _ => return Err(format!("range expects integer arguments, got {}", arg)),
+                }
+            }
+            let (start, end) = (bounds[0], bounds[1]);
+            let step = bounds.get(2).copied().unwrap_or(1);
+            if step == 0 {
+                return Err("range step must not be zero".to_string());
+            }
+
+            let mut result = Vec::new();
+            let mut current = start;
+            while (step > 0 && current < end) || (step < 0 && current > end) {
+                result.push(Value::Int(current));
+                current = match current.checked_add(step) {
+                    Some(next) => next,
+                    None => break,
+                };
+            }
+            Ok(Value::List(result))
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::evaluate_string;
+
+  fn ints(values: &[i64]) -> Value {
+    Value::List(values.iter().map(|v| Value::Int(*v)).collect())
+  }
+
+  #[test]
+  fn test_list_sort_numbers_and_strings() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let result = evaluate_string("(list-sort (list 3 -1 2 10 2))", &mut ctx).unwrap();
+    assert_eq!(result, ints(&[-1, 2, 2, 3, 10]));
+
+    let result = evaluate_string("(list-sort (list \"b\" \"B\" \"a\" \"c\"))", &mut ctx).unwrap();
+    assert_eq!(
+      result,
+      Value::List(vec![
+        Value::Str("B".to_string()),
+        Value::Str("a".to_string()),
+        Value::Str("b".to_string()),
+        Value::Str("c".to_string()),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_list_sort_empty_and_mixed() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(evaluate_string("(list-sort (list))", &mut ctx).unwrap(), Value::List(Vec::new()));
+    assert_eq!(evaluate_string("(list-sort (list 1))", &mut ctx).unwrap(), ints(&[1]));
+
+    let err = evaluate_string("(list-sort (list 1 \"a\"))", &mut ctx).unwrap_err();
+    assert!(err.contains("only integers or only strings"));
+    assert!(evaluate_string("(list-sort 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_list_sort_by_is_stable() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    // Orders pairs by their first element, ignoring the second
+    registry.register_closure("compare-first", "Compare the first elements of two pairs", |args, _ctx| {
+      match (&args[0], &args[1]) {
+        (Value::List(a), Value::List(b)) => Ok(Value::Int(match compare_values(&a[0], &b[0])? {
+          Ordering::Less => -1,
+          Ordering::Equal => 0,
+          Ordering::Greater => 1,
+        })),
+        _ => Err("compare-first expects two lists".to_string()),
+      }
+    });
+    let mut ctx = Context::new(registry);
+    let result = evaluate_string(
+      "(list-sort-by \"compare-first\" (list (list 2 \"x\") (list 1 \"y\") (list 2 \"a\") (list 1 \"b\")))",
+      &mut ctx,
+    )
+    .unwrap();
+    assert_eq!(result.to_string(), "((1 y) (1 b) (2 x) (2 a))");
+
+    assert!(evaluate_string("(list-sort-by \"missing\" (list 1 2))", &mut ctx).is_err());
+    // A comparator returning a non-integer is rejected
+    assert!(evaluate_string("(list-sort-by \"list\" (list 1 2))", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_list_contains_and_index_of() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let mixed = "(list 1 \"1\" #t (list 1 2) \"a\" 1)";
+
+    assert_eq!(evaluate_string(&format!("(list-contains {} \"a\")", mixed), &mut ctx).unwrap(), Value::Bool(true));
+    assert_eq!(evaluate_string(&format!("(list-contains {} (list 1 2))", mixed), &mut ctx).unwrap(), Value::Bool(true));
+    assert_eq!(evaluate_string(&format!("(list-contains {} \"b\")", mixed), &mut ctx).unwrap(), Value::Bool(false));
+
+    // Equality is type-aware: 1 and "1" are different values
+    assert_eq!(evaluate_string(&format!("(list-index-of {} 1)", mixed), &mut ctx).unwrap(), Value::Int(0));
+    assert_eq!(evaluate_string(&format!("(list-index-of {} \"1\")", mixed), &mut ctx).unwrap(), Value::Int(1));
+    assert_eq!(evaluate_string(&format!("(list-index-of {} #t)", mixed), &mut ctx).unwrap(), Value::Int(2));
+    assert_eq!(evaluate_string(&format!("(list-index-of {} (list 1 2))", mixed), &mut ctx).unwrap(), Value::Int(3));
+    assert_eq!(evaluate_string(&format!("(list-index-of {} #f)", mixed), &mut ctx).unwrap(), Value::Int(-1));
+    assert_eq!(evaluate_string("(list-index-of (list) 1)", &mut ctx).unwrap(), Value::Int(-1));
+
+    assert!(evaluate_string("(list-contains 1 1)", &mut ctx).is_err());
+    assert!(evaluate_string("(list-index-of (list 1))", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_list_append_and_concat() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    registry.register(crate::commands::ConcatCommand);
+    let mut ctx = Context::new(registry);
+    assert_eq!(evaluate_string("(list-append (list) 1)", &mut ctx).unwrap(), ints(&[1]));
+    assert_eq!(evaluate_string("(list-append (list 1 2) (list 3))", &mut ctx).unwrap().to_string(), "(1 2 (3))");
+    assert!(evaluate_string("(list-append 1 2)", &mut ctx).is_err());
+
+    let result = evaluate_string("(list-concat (list 1 2) (list) (list 3 4))", &mut ctx).unwrap();
+    assert_eq!(result, ints(&[1, 2, 3, 4]));
+    assert_eq!(evaluate_string("(list-concat)", &mut ctx).unwrap(), Value::List(Vec::new()));
+    assert!(evaluate_string("(list-concat (list 1) 2)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_list_reverse_and_unique() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(evaluate_string("(list-reverse (list 1 2 3))", &mut ctx).unwrap(), ints(&[3, 2, 1]));
+    assert_eq!(evaluate_string("(list-reverse (list))", &mut ctx).unwrap(), Value::List(Vec::new()));
+    assert!(evaluate_string("(list-reverse \"abc\")", &mut ctx).is_err());
+
+    let result = evaluate_string("(list-unique (list 2 \"2\" 1 2 #t \"2\" (list 1) #t (list 1)))", &mut ctx).unwrap();
+    assert_eq!(
+      result,
+      Value::List(vec![
+        Value::Int(2),
+        Value::Str("2".to_string()),
+        Value::Int(1),
+        Value::Bool(true),
+        Value::List(vec![Value::Int(1)]),
+      ])
+    );
+
+    // An already-unique list keeps its order
+    assert_eq!(evaluate_string("(list-unique (list 3 1 2))", &mut ctx).unwrap(), ints(&[3, 1, 2]));
+    assert!(evaluate_string("(list-unique 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_list_reduce() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    registry.register(crate::commands::core::math::SumCommand);
+    registry.register(crate::commands::ConcatCommand);
+    // Keeps the larger of the accumulator and the element
+    registry.register_closure("max-of", "Return the larger of two integers", |args, _ctx| {
+      match (&args[0], &args[1]) {
+        (Value::Int(a), Value::Int(b)) => Ok(Value::Int(*a.max(b))),
+        _ => Err("max-of expects two integers".to_string()),
+      }
+    });
+    let mut ctx = Context::new(registry);
+    assert_eq!(evaluate_string("(list-reduce \"sum\" 0 (list 1 2 3))", &mut ctx).unwrap(), Value::Int(6));
+    assert_eq!(
+      evaluate_string("(list-reduce \"concat\" \"\" (list \"a\" \"b\"))", &mut ctx).unwrap(),
+      Value::Str("ab".to_string())
+    );
+    assert_eq!(evaluate_string("(list-reduce \"max-of\" 0 (list 4 9 2))", &mut ctx).unwrap(), Value::Int(9));
+    // An empty list returns the initial value untouched
+    assert_eq!(evaluate_string("(list-reduce \"sum\" 7 (list))", &mut ctx).unwrap(), Value::Int(7));
+
+    let err = evaluate_string("(list-reduce \"max-of\" 0 (list 1 \"x\" 3))", &mut ctx).unwrap_err();
+    assert_eq!(err, "list-reduce failed at element 1: max-of expects two integers");
+    assert!(evaluate_string("(list-reduce \"missing\" 0 (list 1))", &mut ctx).is_err());
+    assert!(evaluate_string("(list-reduce \"sum\" 0 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_range() {
+    let mut registry = CommandRegistry::new();
+    register_list_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(evaluate_string("(range 0 5)", &mut ctx).unwrap(), ints(&[0, 1, 2, 3, 4]));
+    assert_eq!(evaluate_string("(range -2 7 3)", &mut ctx).unwrap(), ints(&[-2, 1, 4]));
+    assert_eq!(evaluate_string("(range 10 0 -3)", &mut ctx).unwrap(), ints(&[10, 7, 4, 1]));
+
+    // Empty ranges
+    assert_eq!(evaluate_string("(range 3 3)", &mut ctx).unwrap(), ints(&[]));
+    assert_eq!(evaluate_string("(range 5 0)", &mut ctx).unwrap(), ints(&[]));
+    assert_eq!(evaluate_string("(range 0 5 -1)", &mut ctx).unwrap(), ints(&[]));
+
+    assert!(evaluate_string("(range 0 5 0)", &mut ctx).unwrap_err().contains("zero"));
+    assert!(evaluate_string("(range 0 \"5\")", &mut ctx).is_err());
+    assert!(evaluate_string("(range 0)", &mut ctx).is_err());
+  }
 }
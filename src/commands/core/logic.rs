@@ -0,0 +1,415 @@
+use crate::{Command, Value, evaluate};
+use crate::context::Context;
+
+/// And command - logical conjunction, short-circuiting on the first falsy argument
+pub struct AndCommand;
+
+impl Command for AndCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Ok(Value::Bool(args.iter().all(|arg| arg.is_truthy())))
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        for arg in &args {
+            if !evaluate(arg, ctx)?.is_truthy() {
+                return Ok(Value::Bool(false));
+            }
+        }
+        Ok(Value::Bool(true))
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "and"
+    }
+
+    fn description(&self) -> &'static str {
+        "Return true if all arguments are truthy; stops evaluating at the first falsy one"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(and expr1 expr2 ...)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (and #t 1 \"x\")           ; Returns true\n  (and #f (docker \"build\")) ; Returns false without running docker"
+    }
+}
+
+/// Or command - logical disjunction, short-circuiting on the first truthy argument
+pub struct OrCommand;
+
+impl Command for OrCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Ok(Value::Bool(args.iter().any(|arg| arg.is_truthy())))
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        for arg in &args {
+            if evaluate(arg, ctx)?.is_truthy() {
+                return Ok(Value::Bool(true));
+            }
+        }
+        Ok(Value::Bool(false))
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "or"
+    }
+
+    fn description(&self) -> &'static str {
+        "Return true if any argument is truthy; stops evaluating at the first truthy one"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(or expr1 expr2 ...)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (or #f 0 \"x\")                         ; Returns true\n  (or (has-var \"A\") (set-var \"A\" \"1\")) ; Set A only if missing"
+    }
+}
+
+/// Not command - logical negation of a single argument
+pub struct NotCommand;
+
+impl Command for NotCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        if args.len() != 1 {
+            return Err("not expects exactly one argument".to_string());
+        }
+        Ok(Value::Bool(!args[0].is_truthy()))
+    }
+
+    fn name(&self) -> &'static str {
+        "not"
+    }
+
+    fn description(&self) -> &'static str {
+        "Return the logical negation of the argument"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(not expr)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (not #f) ; Returns true\n  (not 1)  ; Returns false"
+    }
+}
+
+/// Cond command - runs the body of the first clause whose test is truthy
+///
+/// Tests are evaluated in order up to the first truthy one and only that
+/// clause's body is evaluated. `else` always matches. A clause without a
+/// body returns its test value; nil is returned when no clause matches.
+pub struct CondCommand;
+
+impl Command for CondCommand {
+    fn execute(&self, _args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Err("cond needs unevaluated clauses and cannot be used as a pipe stage".to_string())
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        for (index, clause) in args.iter().enumerate() {
+            let cons = clause
+                .as_cons()
+                .ok_or_else(|| format!("cond clause {} must be a list (test body...)", index + 1))?;
+
+            let test = cons.car();
+            let matched = if test.as_symbol() == Some("else") {
+                Value::Bool(true)
+            } else {
+                evaluate(test, ctx)?
+            };
+            if !matched.is_truthy() {
+                continue;
+            }
+
+            let mut result = matched;
+            let mut current = cons.cdr();
+            while let lexpr::Value::Cons(body) = current {
+                result = evaluate(body.car(), ctx)?;
+                current = body.cdr();
+            }
+            return Ok(result);
+        }
+        Ok(Value::Nil)
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "cond"
+    }
+
+    fn description(&self) -> &'static str {
+        "Evaluate the body of the first clause whose test is truthy, with an optional else clause"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(cond (test body...) ... (else body...))"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (cond ((has-var \"CI\") \"ci\")\n        ((has-var \"DEV\") \"dev\")\n        (else \"local\"))   ; Returns the first matching environment"
+    }
+}
+
+/// Case command - runs the body of the first clause whose literal equals a value
+///
+/// The value is evaluated once; clause literals are not evaluated. A clause
+/// may list several literals, as in `(("dev" "local") body...)`. `else`
+/// always matches and nil is returned when no clause matches.
+pub struct CaseCommand;
+
+impl Command for CaseCommand {
+    fn execute(&self, _args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Err("case needs unevaluated clauses and cannot be used as a pipe stage".to_string())
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        let (key, clauses) = args
+            .split_first()
+            .ok_or_else(|| "case expects a value followed by (literal body...) clauses".to_string())?;
+        let value = evaluate(key, ctx)?;
+
+        for (index, clause) in clauses.iter().enumerate() {
+            let cons = clause
+                .as_cons()
+                .ok_or_else(|| format!("case clause {} must be a list (literal body...)", index + 1))?;
+
+            let matched = match cons.car() {
+                literal if literal.as_symbol() == Some("else") => true,
+                lexpr::Value::Cons(_) => match Value::from_lexpr(cons.car())? {
+                    Value::List(literals) => literals.contains(&value),
+                    literal => literal == value,
+                },
+                literal => Value::from_lexpr(literal)? == value,
+            };
+            if !matched {
+                continue;
+            }
+
+            let mut result = Value::Nil;
+            let mut current = cons.cdr();
+            while let lexpr::Value::Cons(body) = current {
+                result = evaluate(body.car(), ctx)?;
+                current = body.cdr();
+            }
+            return Ok(result);
+        }
+        Ok(Value::Nil)
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "case"
+    }
+
+    fn description(&self) -> &'static str {
+        "Evaluate the body of the first clause whose literal equals the value, with an optional else clause"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(case value (literal body...) ((literal1 literal2) body...) ... (else body...))"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (case (get-var \"ENV\")\n        (\"prod\" \"docker-compose.prod.yml\")\n        ((\"dev\" \"local\") \"docker-compose.dev.yml\")\n        (else \"docker-compose.yml\"))"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::core::math::SumCommand;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_and_command() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(and #t 1 \"x\")", &mut ctx).unwrap(), Value::Bool(true));
+        assert_eq!(evaluate_string("(and #t 0)", &mut ctx).unwrap(), Value::Bool(false));
+        assert_eq!(evaluate_string("(and)", &mut ctx).unwrap(), Value::Bool(true));
+        assert_eq!(evaluate_string("(and (sum 1 2) (sum 0))", &mut ctx).unwrap(), Value::Bool(false));
+    }
+
+    #[test]
+    fn test_and_short_circuits() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        // The erroring expression is never evaluated
+        assert_eq!(evaluate_string("(and #f (sum \"x\"))", &mut ctx).unwrap(), Value::Bool(false));
+        assert_eq!(evaluate_string("(and 0 (unknown-command))", &mut ctx).unwrap(), Value::Bool(false));
+        // Once reached, errors propagate
+        assert!(evaluate_string("(and #t (sum \"x\"))", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_or_command() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(or #f 0 \"x\")", &mut ctx).unwrap(), Value::Bool(true));
+        assert_eq!(evaluate_string("(or #f 0)", &mut ctx).unwrap(), Value::Bool(false));
+        assert_eq!(evaluate_string("(or)", &mut ctx).unwrap(), Value::Bool(false));
+    }
+
+    #[test]
+    fn test_or_short_circuits() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(or #t (sum \"x\"))", &mut ctx).unwrap(), Value::Bool(true));
+        assert_eq!(evaluate_string("(or (sum 1) (unknown-command))", &mut ctx).unwrap(), Value::Bool(true));
+        assert!(evaluate_string("(or #f (unknown-command))", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_not_command() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(not #f)", &mut ctx).unwrap(), Value::Bool(true));
+        assert_eq!(evaluate_string("(not 1)", &mut ctx).unwrap(), Value::Bool(false));
+        assert_eq!(evaluate_string("(not (and #t #f))", &mut ctx).unwrap(), Value::Bool(true));
+        assert!(evaluate_string("(not)", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_execute_with_evaluated_arguments() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        let result = ctx.registry.get("and").unwrap().execute(vec![Value::Bool(true), Value::Int(0)], &mut ctx);
+        assert_eq!(result.unwrap(), Value::Bool(false));
+        let result = ctx.registry.get("or").unwrap().execute(vec![Value::Nil, Value::Str("x".to_string())], &mut ctx);
+        assert_eq!(result.unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_cond_middle_match() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        // Later tests and the other bodies are never evaluated
+        let result = evaluate_string("(cond (#f (unknown-command)) ((sum 1) \"second\" (sum 2 3)) ((unknown-test) 0))", &mut ctx);
+        assert_eq!(result.unwrap(), Value::Int(5));
+        // Without a body the test value is returned
+        assert_eq!(evaluate_string("(cond (0 1) ((sum 4 4)))", &mut ctx).unwrap(), Value::Int(8));
+    }
+
+    #[test]
+    fn test_cond_else_and_no_match() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(cond (#f 1) (0 2) (else \"fallback\"))", &mut ctx).unwrap(), Value::Str("fallback".to_string()));
+        assert_eq!(evaluate_string("(cond (#f 1) (0 2))", &mut ctx).unwrap(), Value::Nil);
+        assert_eq!(evaluate_string("(cond)", &mut ctx).unwrap(), Value::Nil);
+        assert!(evaluate_string("(cond #t)", &mut ctx).is_err());
+        assert!(evaluate_string("(cond ((sum \"x\") 1))", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_case_string_match() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        let script = "(case \"dev\" (\"prod\" (unknown-command)) ((\"dev\" \"local\") \"matched\" (sum 1 1)) (else 0))";
+        assert_eq!(evaluate_string(script, &mut ctx).unwrap(), Value::Int(2));
+    }
+
+    #[test]
+    fn test_case_else_and_no_match() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        let result = evaluate_string("(case \"qa\" (\"prod\" 1) (\"dev\" 2) (else \"fallback\"))", &mut ctx);
+        assert_eq!(result.unwrap(), Value::Str("fallback".to_string()));
+        assert_eq!(evaluate_string("(case \"qa\" (\"prod\" 1))", &mut ctx).unwrap(), Value::Nil);
+        assert!(evaluate_string("(case)", &mut ctx).is_err());
+        assert!(evaluate_string("(case 1 2)", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_case_numeric_match() {
+        let mut registry = CommandRegistry::new();
+        registry.register(AndCommand);
+        registry.register(OrCommand);
+        registry.register(NotCommand);
+        registry.register(CondCommand);
+        registry.register(CaseCommand);
+        registry.register(SumCommand);
+        let mut ctx = Context::new(registry);
+        // The value is evaluated, literals are compared with Value equality
+        assert_eq!(evaluate_string("(case (sum 1 2) (2 \"two\") (3 \"three\"))", &mut ctx).unwrap(), Value::Str("three".to_string()));
+        assert_eq!(evaluate_string("(case 3 (\"3\" \"string\") (else \"int\"))", &mut ctx).unwrap(), Value::Str("int".to_string()));
+    }
+}
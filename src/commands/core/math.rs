@@ -0,0 +1,330 @@
+use crate::context::{Context, LogLevel};
+use crate::utils::debug_log;
+use crate::{Command, CommandRegistry, Tag, Value, tags, value_to_int};
+
+/// Sum command - sums a list of integers
+pub struct SumCommand;
+
+impl Command for SumCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        let mut total = 0i64;
+
+        for arg in args {
+            match arg {
+                Value::Int(i) => total = total.checked_add(i).ok_or_else(|| overflow("sum"))?,
+                Value::List(list) => {
+                    for item in list {
+                        if let Value::Int(i) = item {
+                            total = total.checked_add(i).ok_or_else(|| overflow("sum"))?;
+                        } else {
+                            return Err(format!("Cannot sum non-integer value: {}", item));
+                        }
+                    }
+                }
+                _ => return Err(format!("Cannot sum non-integer value: {}", arg)),
+            }
+        }
+
+        Ok(Value::Int(total))
+    }
+
+    fn name(&self) -> &'static str {
+        "sum"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sum a list of integers"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(sum number1 number2 ...)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (sum 1 2 3)        ; Returns 6\n  (sum 10 20)        ; Returns 30"
+    }
+
+    fn tag(&self) -> &Tag {
+        &tags::MATH
+    }
+}
+
+/// Multiply command - multiplies two numbers
+pub struct MultiplyCommand;
+
+impl Command for MultiplyCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        if args.len() != 2 {
+            return Err("multiply expects exactly 2 arguments".to_string());
+        }
+
+        let a = value_to_int(&args[0])?;
+        let b = value_to_int(&args[1])?;
+        a.checked_mul(b).map(Value::Int).ok_or_else(|| overflow("multiply"))
+    }
+
+    fn name(&self) -> &'static str {
+        "multiply"
+    }
+
+    fn description(&self) -> &'static str {
+        "Multiply two numbers"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(multiply number1 number2)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (multiply 6 7)      ; Returns 42\n  (multiply 3 4)      ; Returns 12"
+    }
+
+    fn tag(&self) -> &Tag {
+        &tags::MATH
+    }
+}
+
+/// Flattens integer arguments, accepting lists of integers like `sum`
+fn int_args(command_name: &str, args: &[Value]) -> Result<Vec<i64>, String> {
+  let mut numbers = Vec::new();
+  for arg in args {
+    match arg {
+      Value::List(items) => {
+        for item in items {
+          numbers.push(value_to_int(item).map_err(|e| format!("{}: {}", command_name, e))?);
+        }
+      }
+      other => numbers.push(value_to_int(other).map_err(|e| format!("{}: {}", command_name, e))?),
+    }
+  }
+  Ok(numbers)
+}
+
+/// Extracts exactly two integer arguments
+fn int_pair(command_name: &str, args: &[Value]) -> Result<(i64, i64), String> {
+  match args {
+    [a, b] => Ok((value_to_int(a)?, value_to_int(b)?)),
+    _ => Err(format!("{} expects exactly 2 arguments", command_name)),
+  }
+}
+
+fn overflow(command_name: &str) -> String {
+  format!("{}: integer overflow", command_name)
+}
+
+/// Register arithmetic commands
+pub fn register_math_commands(registry: &mut CommandRegistry) {
+  registry.register(SumCommand);
+  registry.register(MultiplyCommand);
+
+  // subtract command
+  registry.register_closure_with_help_and_tag(
+    "subtract",
+    "Subtract the remaining numbers from the first one, or negate a single number",
+    "(subtract number1 number2 ...)",
+    "  (subtract 10 3 2)   ; Returns 5\n  (subtract 4)        ; Returns -4",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "subtract", "executing subtract command");
+
+      let (first, rest) = match args.split_first() {
+        Some((first, rest)) => (value_to_int(first)?, rest),
+        None => return Err("subtract expects at least one argument".to_string()),
+      };
+      if rest.is_empty() {
+        return first.checked_neg().map(Value::Int).ok_or_else(|| overflow("subtract"));
+      }
+
+      let mut result = first;
+      for arg in rest {
+        result = result.checked_sub(value_to_int(arg)?).ok_or_else(|| overflow("subtract"))?;
+      }
+      Ok(Value::Int(result))
+    },
+  );
+
+  // divide command
+  registry.register_closure_with_help_and_tag(
+    "divide",
+    "Divide two integers, truncating toward zero",
+    "(divide dividend divisor)",
+    "  (divide 20 4)       ; Returns 5\n  (divide 7 2)        ; Returns 3",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "divide", "executing divide command");
+
+      let (a, b) = int_pair("divide", &args)?;
+      if b == 0 {
+        return Err("divide: division by zero".to_string());
+      }
+      a.checked_div(b).map(Value::Int).ok_or_else(|| overflow("divide"))
+    },
+  );
+
+  // modulo command
+  registry.register_closure_with_help_and_tag(
+    "modulo",
+    "Remainder of an integer division, with the sign of the dividend",
+    "(modulo dividend divisor)",
+    "  (modulo 7 3)        ; Returns 1\n  (modulo -7 3)       ; Returns -1",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "modulo", "executing modulo command");
+
+      let (a, b) = int_pair("modulo", &args)?;
+      if b == 0 {
+        return Err("modulo: division by zero".to_string());
+      }
+      a.checked_rem(b).map(Value::Int).ok_or_else(|| overflow("modulo"))
+    },
+  );
+
+  // min command
+  registry.register_closure_with_help_and_tag(
+    "min",
+    "Smallest of the given integers",
+    "(min number1 number2 ...)",
+    "  (min 4 2 8)         ; Returns 2\n  (min (list 5 3))    ; Lists are flattened, returns 3",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "min", "executing min command");
+
+      let numbers = int_args("min", &args)?;
+      numbers.into_iter().min().map(Value::Int).ok_or_else(|| "min expects at least one number".to_string())
+    },
+  );
+
+  // max command
+  registry.register_closure_with_help_and_tag(
+    "max",
+    "Largest of the given integers",
+    "(max number1 number2 ...)",
+    "  (max 4 2 8)         ; Returns 8\n  (max (list 5 3))    ; Lists are flattened, returns 5",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "max", "executing max command");
+
+      let numbers = int_args("max", &args)?;
+      numbers.into_iter().max().map(Value::Int).ok_or_else(|| "max expects at least one number".to_string())
+    },
+  );
+
+  // abs command
+  registry.register_closure_with_help_and_tag(
+    "abs",
+    "Absolute value of an integer",
+    "(abs number)",
+    "  (abs -5)            ; Returns 5",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "abs", "executing abs command");
+
+      match args.as_slice() {
+        [value] => value_to_int(value)?.checked_abs().map(Value::Int).ok_or_else(|| overflow("abs")),
+        _ => Err("abs expects exactly 1 argument".to_string()),
+      }
+    },
+  );
+
+  // pow command
+  registry.register_closure_with_help_and_tag(
+    "pow",
+    "Raise an integer to a non-negative integer power",
+    "(pow base exponent)",
+    "  (pow 2 10)          ; Returns 1024\n  (pow 5 0)           ; Returns 1",
+    &tags::MATH,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "pow", "executing pow command");
+
+      let (base, exponent) = int_pair("pow", &args)?;
+      let exponent = u32::try_from(exponent).map_err(|_| "pow: exponent must be a non-negative integer".to_string())?;
+      base.checked_pow(exponent).map(Value::Int).ok_or_else(|| overflow("pow"))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::evaluate_string;
+
+  fn eval(ctx: &mut Context, input: &str) -> Result<Value, String> {
+    evaluate_string(input, ctx)
+  }
+
+  #[test]
+  fn test_math_commands_registered_under_math_tag() {
+    let mut registry = CommandRegistry::new();
+    register_math_commands(&mut registry);
+    let ctx = Context::new(registry);
+    for name in ["sum", "multiply", "subtract", "divide", "modulo", "min", "max", "abs", "pow"] {
+      let command = ctx.registry.get(name).unwrap_or_else(|| panic!("{} is not registered", name));
+      assert_eq!(command.tag().name, "math", "{}", name);
+    }
+  }
+
+  #[test]
+  fn test_sum_and_multiply() {
+    let mut registry = CommandRegistry::new();
+    register_math_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(sum 1 2 3)").unwrap(), Value::Int(6));
+    assert_eq!(eval(&mut ctx, "(sum)").unwrap(), Value::Int(0));
+    assert!(eval(&mut ctx, "(sum 1 \"2\")").is_err());
+    assert_eq!(eval(&mut ctx, "(multiply 6 7)").unwrap(), Value::Int(42));
+    assert!(eval(&mut ctx, "(multiply 6)").is_err());
+    assert_eq!(eval(&mut ctx, "(sum 9223372036854775807 1)").unwrap_err(), "sum: integer overflow");
+    assert_eq!(eval(&mut ctx, "(multiply 9223372036854775807 2)").unwrap_err(), "multiply: integer overflow");
+  }
+
+  #[test]
+  fn test_subtract() {
+    let mut registry = CommandRegistry::new();
+    register_math_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(subtract 10 3 2)").unwrap(), Value::Int(5));
+    assert_eq!(eval(&mut ctx, "(subtract 4)").unwrap(), Value::Int(-4));
+    assert!(eval(&mut ctx, "(subtract)").is_err());
+    assert!(eval(&mut ctx, "(subtract 1 \"a\")").is_err());
+  }
+
+  #[test]
+  fn test_divide_and_modulo() {
+    let mut registry = CommandRegistry::new();
+    register_math_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(divide 20 4)").unwrap(), Value::Int(5));
+    assert_eq!(eval(&mut ctx, "(divide -7 2)").unwrap(), Value::Int(-3));
+    assert!(eval(&mut ctx, "(divide 1 0)").unwrap_err().contains("division by zero"));
+    assert_eq!(eval(&mut ctx, "(modulo 7 3)").unwrap(), Value::Int(1));
+    assert_eq!(eval(&mut ctx, "(modulo -7 3)").unwrap(), Value::Int(-1));
+    assert!(eval(&mut ctx, "(modulo 1 0)").unwrap_err().contains("division by zero"));
+    assert!(eval(&mut ctx, "(modulo 1)").is_err());
+  }
+
+  #[test]
+  fn test_min_and_max() {
+    let mut registry = CommandRegistry::new();
+    register_math_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(min 4 2 8)").unwrap(), Value::Int(2));
+    assert_eq!(eval(&mut ctx, "(max 4 2 8)").unwrap(), Value::Int(8));
+    assert_eq!(eval(&mut ctx, "(max -1)").unwrap(), Value::Int(-1));
+    assert!(eval(&mut ctx, "(min)").is_err());
+    assert!(eval(&mut ctx, "(max 1 \"2\")").is_err());
+  }
+
+  #[test]
+  fn test_abs_and_pow() {
+    let mut registry = CommandRegistry::new();
+    register_math_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(abs -5)").unwrap(), Value::Int(5));
+    assert_eq!(eval(&mut ctx, "(abs 5)").unwrap(), Value::Int(5));
+    assert!(eval(&mut ctx, "(abs 1 2)").is_err());
+    assert_eq!(eval(&mut ctx, "(pow 2 10)").unwrap(), Value::Int(1024));
+    assert_eq!(eval(&mut ctx, "(pow 5 0)").unwrap(), Value::Int(1));
+    assert!(eval(&mut ctx, "(pow 2 -1)").is_err());
+    assert!(eval(&mut ctx, "(pow 10 30)").unwrap_err().contains("overflow"));
+  }
+}
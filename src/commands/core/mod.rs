@@ -1,25 +1,59 @@
+pub mod alias;
+pub mod apply;
+pub mod assert;
 pub mod basedir;
+pub mod combine_env;
 pub mod concat;
+pub mod config;
 pub mod debug;
+pub mod env_diff;
+pub mod eval;
+pub mod hash;
 pub mod help;
+pub mod limits;
 pub mod list_utils;
-pub mod multiply;
+pub mod logic;
+pub mod math;
 pub mod pipe;
 pub mod print;
+pub mod quote;
+pub mod random;
 pub mod read_env;
-pub mod sum;
+pub mod retry;
+pub mod source;
+pub mod strings;
+pub mod tee;
+pub mod time;
+pub mod try_catch;
+pub mod types;
+pub mod validate_env;
 pub mod vars;
 pub mod files;
 
+pub use alias::register_alias_commands;
+pub use apply::ApplyCommand;
+pub use assert::{AssertCommand, AssertEqCommand};
 pub use basedir::register_basedir_commands;
 pub use concat::ConcatCommand;
+pub use config::register_config_commands;
 pub use debug::DebugCommand;
-pub use help::register_help_commands;
+pub use eval::EvalCommand;
+pub use hash::register_hash_commands;
+pub use help::{format_help_json, format_help_long, format_help_short, register_help_commands};
+pub use limits::register_limit_commands;
 pub use list_utils::register_list_commands;
-pub use multiply::MultiplyCommand;
+pub use logic::{AndCommand, CaseCommand, CondCommand, NotCommand, OrCommand};
+pub use math::register_math_commands;
 pub use pipe::PipeCommand;
-pub use print::PrintCommand;
+pub use print::{PpCommand, PrintCommand, PrintRawCommand};
+pub use quote::QuoteCommand;
+pub use random::register_random_commands;
 pub use read_env::register_app_commands;
-pub use sum::SumCommand;
+pub use retry::RetryCommand;
+pub use strings::register_string_commands;
+pub use tee::TeeCommand;
+pub use time::register_time_commands;
+pub use try_catch::TryCommand;
+pub use types::{register_coercion_commands, register_type_commands};
 pub use vars::register_var_commands;
 pub use files::register_file_commands;
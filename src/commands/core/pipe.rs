@@ -1,9 +1,32 @@
 use crate::{Command, Value, evaluate};
+use crate::lisp_interpreter::unknown_command_error;
 use crate::context::Context;
 
-/// Pipe command - executes a pipeline of commands
+/// Pipe command - executes a pipeline of commands, threading each result
+/// into the next stage as its last argument
+///
+/// `(pipe (sum 1 2) (multiply 10) (print "Result:"))` runs
+/// `(multiply 10 3)` and then `(print "Result:" 30)`.
+/// Stage arguments are evaluated before the previous value is appended,
+/// so special forms used as a stage receive evaluated arguments.
+/// Stages run strictly in order in the same context: a stage's arguments
+/// are only evaluated once the previous stage has run, so its variable and
+/// basedir changes are visible to them.
 pub struct PipeCommand;
 
+impl PipeCommand {
+    /// Runs a single stage: the named command with its own arguments followed by the previous value
+    fn run_stage(command_name: &str, mut args: Vec<Value>, previous: Value, ctx: &mut Context) -> Result<Value, String> {
+        let command = ctx
+            .registry
+            .get(command_name)
+            .ok_or_else(|| unknown_command_error(&ctx.registry, command_name))?;
+
+        args.push(previous);
+        command.execute(args, ctx)
+    }
+}
+
 impl Command for PipeCommand {
     fn execute(&self, args: Vec<Value>, ctx: &mut Context) -> Result<Value, String> {
         if args.is_empty() {
@@ -12,18 +35,64 @@ impl Command for PipeCommand {
 
         let mut result = args[0].clone();
 
-        for i in 1..args.len() {
+        for arg in &args[1..] {
             // Each subsequent argument should be a command to execute with the previous result
-            match &args[i] {
+            match arg {
                 Value::List(cmd_list) => {
                     if cmd_list.is_empty() {
                         continue;
                     }
 
-                    // Convert back to lexpr format for evaluation
-                    let lexpr_value = args[i].to_lexpr();
-                    result = evaluate(&lexpr_value, ctx)?;
+                    let command_name = match &cmd_list[0] {
+                        Value::Str(name) => name.clone(),
+                        _ => return Err("Pipe stage must start with a command name".to_string()),
+                    };
+                    result = Self::run_stage(&command_name, cmd_list[1..].to_vec(), result, ctx)?;
+                }
+                _ => {
+                    return Err("Pipe arguments must be command lists".to_string());
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        if args.is_empty() {
+            return Ok(Value::Nil);
+        }
+
+        let mut result = evaluate(&args[0], ctx)?;
+
+        for stage in &args[1..] {
+            match stage {
+                lexpr::Value::Cons(cons) => {
+                    let command_name = match cons.car() {
+                        lexpr::Value::Symbol(s) => s.to_string(),
+                        _ => return Err("Pipe stage must start with a command name".to_string()),
+                    };
+
+                    // Evaluate the stage's own arguments
+                    let mut stage_args = Vec::new();
+                    let mut current = cons.cdr();
+                    loop {
+                        match current {
+                            lexpr::Value::Cons(cons) => {
+                                stage_args.push(evaluate(cons.car(), ctx)?);
+                                current = cons.cdr();
+                            }
+                            lexpr::Value::Nil | lexpr::Value::Null => break,
+                            _ => {
+                                stage_args.push(evaluate(current, ctx)?);
+                                break;
+                            }
+                        }
+                    }
+
+                    result = Self::run_stage(&command_name, stage_args, result, ctx)?;
                 }
+                lexpr::Value::Nil | lexpr::Value::Null => continue,
                 _ => {
                     return Err("Pipe arguments must be command lists".to_string());
                 }
@@ -33,19 +102,137 @@ impl Command for PipeCommand {
         Ok(result)
     }
 
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
     fn name(&self) -> &'static str {
         "pipe"
     }
 
     fn description(&self) -> &'static str {
-        "Execute a pipeline of commands, passing results between them"
+        "Execute a pipeline of commands, appending each result as the last argument of the next command"
     }
 
     fn syntax(&self) -> &'static str {
-        "(pipe command1 command2 ...)"
+        "(pipe initial-expr (command1 args...) (command2 args...) ...)"
     }
 
     fn examples(&self) -> &'static str {
-        "  (pipe (sum 1 2 3) (print \"Result:\"))"
+        "  (pipe (sum 1 2) (multiply 10))                 ; Runs (multiply 10 3), returns 30\n  (pipe (sum 1 2 3) (print \"Result:\"))          ; Prints \"Result: 6\""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{ConcatCommand, PrintCommand};
+    use crate::commands::core::math::{MultiplyCommand, SumCommand};
+    use crate::commands::core::basedir::register_basedir_commands;
+    use crate::commands::core::vars::register_var_commands;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_pipe_threads_previous_value() {
+        let mut registry = CommandRegistry::new();
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        registry.register(ConcatCommand);
+        registry.register(PrintCommand);
+        register_var_commands(&mut registry);
+        register_basedir_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let result = evaluate_string("(pipe (sum 1 2) (multiply 10))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(30));
+    }
+
+    #[test]
+    fn test_pipe_three_stages() {
+        let mut registry = CommandRegistry::new();
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        registry.register(ConcatCommand);
+        registry.register(PrintCommand);
+        register_var_commands(&mut registry);
+        register_basedir_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        // (sum 1 2) -> 3, (multiply 10 3) -> 30, (sum 5 30) -> 35
+        let result = evaluate_string("(pipe (sum 1 2) (multiply 10) (sum 5))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(35));
+
+        // The previous value is the last argument
+        let result = evaluate_string("(pipe \"c\" (concat \"a\" \"b\") (concat \"x\"))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Str("xabc".to_string()));
+    }
+
+    #[test]
+    fn test_pipe_stage_arguments_are_evaluated() {
+        let mut registry = CommandRegistry::new();
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        registry.register(ConcatCommand);
+        registry.register(PrintCommand);
+        register_var_commands(&mut registry);
+        register_basedir_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let result = evaluate_string("(pipe (sum 1 1) (multiply (sum 2 3)))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(10));
+    }
+
+    #[test]
+    fn test_pipe_errors() {
+        let mut registry = CommandRegistry::new();
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        registry.register(ConcatCommand);
+        registry.register(PrintCommand);
+        register_var_commands(&mut registry);
+        register_basedir_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        assert_eq!(evaluate_string("(pipe)", &mut ctx).unwrap(), Value::Nil);
+        assert!(evaluate_string("(pipe 1 2)", &mut ctx).is_err());
+        assert!(evaluate_string("(pipe 1 (unknown-command))", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_pipe_execute_with_values() {
+        let mut registry = CommandRegistry::new();
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        registry.register(ConcatCommand);
+        registry.register(PrintCommand);
+        register_var_commands(&mut registry);
+        register_basedir_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let stage = Value::List(vec![Value::Str("multiply".to_string()), Value::Int(10)]);
+        let result = ctx.registry.get("pipe").unwrap().execute(vec![Value::Int(4), stage], &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(40));
+    }
+
+    #[test]
+    fn test_pipe_stages_see_earlier_context_changes() {
+        let mut registry = CommandRegistry::new();
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        registry.register(ConcatCommand);
+        registry.register(PrintCommand);
+        register_var_commands(&mut registry);
+        register_basedir_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        // Stage one sets NAME from the previous value, stage two reads it
+        let result = evaluate_string("(pipe \"dpm\" (set-var \"NAME\") (concat (get-var \"NAME\") \"|\"))", &mut ctx).unwrap();
+        assert!(result.to_string().starts_with("dpm|"), "{}", result);
+        assert_eq!(ctx.get_variable("NAME"), Some(Value::Str("dpm".to_string())));
+
+        let dir = std::env::temp_dir().canonicalize().unwrap();
+        let script = format!("(pipe (basedir \"{}\") (concat (get-basedir) \"|\"))", dir.display());
+        let result = evaluate_string(&script, &mut ctx).unwrap();
+        assert!(result.to_string().starts_with(&format!("{}|", dir.display())), "{}", result);
     }
 }
@@ -1,15 +1,21 @@
 use crate::{Command, Value};
 use crate::context::Context;
+use std::io::Write;
+
+/// Joins the arguments with spaces, as printed by `print` and `print-raw`
+fn format_args(args: &[Value]) -> String {
+    args.iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
 
 /// Print command - prints its arguments
 pub struct PrintCommand;
 
 impl Command for PrintCommand {
     fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
-        let output = args.iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<_>>()
-            .join(" ");
+        let output = format_args(&args);
         println!("{}", output);
         Ok(Value::Str(output))
     }
@@ -30,3 +36,114 @@ impl Command for PrintCommand {
         "  (print \"Hello World\")\n  (print \"Sum is:\" (sum 1 2 3))"
     }
 }
+
+/// Print-raw command - prints its arguments without a trailing newline
+pub struct PrintRawCommand;
+
+impl Command for PrintRawCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        let output = format_args(&args);
+        print!("{}", output);
+        // Flush so partial lines (progress, inline status) show up immediately
+        std::io::stdout().flush().map_err(|e| format!("print-raw failed to flush stdout: {}", e))?;
+        Ok(Value::Str(output))
+    }
+
+    fn name(&self) -> &'static str {
+        "print-raw"
+    }
+
+    fn description(&self) -> &'static str {
+        "Print arguments to stdout without a trailing newline"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(print-raw arg1 arg2 ...)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (print-raw \"Building...\")   ; Cursor stays on the same line\n  (print-raw \"Step\" 1 \"of\" 3)"
+    }
+}
+
+/// Formats a value over several lines, indenting nested lists by two spaces
+/// per level; lists without nested lists stay on one line
+fn pretty_format(value: &Value, indent: usize) -> String {
+    match value {
+        Value::List(items) if items.iter().any(|item| matches!(item, Value::List(_))) => {
+            let padding = " ".repeat(indent + 2);
+            let mut output = String::from("(\n");
+            for item in items {
+                output.push_str(&padding);
+                output.push_str(&pretty_format(item, indent + 2));
+                output.push('\n');
+            }
+            output.push_str(&" ".repeat(indent));
+            output.push(')');
+            output
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Pp command - pretty-prints a value, one nested list per line
+pub struct PpCommand;
+
+impl Command for PpCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        if args.len() != 1 {
+            return Err("pp expects exactly one argument".to_string());
+        }
+        let output = pretty_format(&args[0], 0);
+        println!("{}", output);
+        Ok(Value::Str(output))
+    }
+
+    fn name(&self) -> &'static str {
+        "pp"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pretty-print a value to stdout, indenting nested lists"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(pp value)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (pp (version-report))     ; One entry per line\n  (pp (list (list 1 2) 3))  ; Prints (\\n  (1 2)\\n  3\\n)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_format_indents_nested_lists() {
+        let value = Value::List(vec![
+            Value::Str("root".to_string()),
+            Value::List(vec![Value::Str("a".to_string()), Value::Int(1)]),
+            Value::List(vec![
+                Value::Str("b".to_string()),
+                Value::List(vec![Value::Int(2), Value::Int(3)]),
+            ]),
+        ]);
+        assert_eq!(pretty_format(&value, 0), "(\n  root\n  (a 1)\n  (\n    b\n    (2 3)\n  )\n)");
+
+        // Flat values keep the compact form
+        let flat = Value::List(vec![Value::Int(1), Value::Int(2)]);
+        assert_eq!(pretty_format(&flat, 0), flat.to_string());
+        assert_eq!(pretty_format(&Value::Nil, 0), "nil");
+    }
+
+    #[test]
+    fn test_pp_returns_formatted_string() {
+        let mut ctx = Context::new(crate::CommandRegistry::new());
+        let value = Value::List(vec![Value::List(vec![Value::Int(1)])]);
+        let result = PpCommand.execute(vec![value], &mut ctx).unwrap();
+        assert_eq!(result, Value::Str("(\n  (1)\n)".to_string()));
+        assert!(PpCommand.execute(vec![], &mut ctx).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{Command, Value};
+use crate::context::Context;
+
+/// Quote command - returns its argument as data instead of evaluating it
+///
+/// `(quote (sum 1 2))` yields the list `("sum" 1 2)`: lists become
+/// `Value::List` and symbols become strings. The reader shorthand `'expr`
+/// expands to `(quote expr)`.
+pub struct QuoteCommand;
+
+impl Command for QuoteCommand {
+    fn execute(&self, _args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Err("quote needs an unevaluated argument and cannot be used as a pipe stage".to_string())
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, _ctx: &mut Context) -> Result<Value, String> {
+        match args.as_slice() {
+            [expression] => Value::from_lexpr(expression),
+            _ => Err("quote expects exactly one argument".to_string()),
+        }
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "quote"
+    }
+
+    fn description(&self) -> &'static str {
+        "Return an expression as data without evaluating it"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(quote expr) or 'expr"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (quote (sum 1 2))      ; Returns (\"sum\" 1 2), not 3\n  '(docker \"up\" \"-d\")    ; Same as (quote (docker \"up\" \"-d\"))"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::core::math::SumCommand;
+    use crate::commands::core::vars::register_var_commands;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_quote_returns_unevaluated_list() {
+        let mut registry = CommandRegistry::new();
+        registry.register(QuoteCommand);
+        registry.register(SumCommand);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let expected = Value::List(vec![Value::Str("sum".to_string()), Value::Int(1), Value::Int(2)]);
+
+        assert_eq!(evaluate_string("(quote (sum 1 2))", &mut ctx).unwrap(), expected);
+        assert_eq!(evaluate_string("'(sum 1 2)", &mut ctx).unwrap(), expected);
+
+        // Nested forms are not run either
+        let result = evaluate_string("(quote (set-var \"A\" (sum 1 2)))", &mut ctx).unwrap();
+        assert_eq!(result.to_string(), "(set-var A (sum 1 2))");
+        assert!(!ctx.has_variable("A"));
+
+        assert_eq!(evaluate_string("(quote docker)", &mut ctx).unwrap(), Value::Str("docker".to_string()));
+        assert_eq!(evaluate_string("(quote 42)", &mut ctx).unwrap(), Value::Int(42));
+    }
+
+    #[test]
+    fn test_quote_errors() {
+        let mut registry = CommandRegistry::new();
+        registry.register(QuoteCommand);
+        registry.register(SumCommand);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        assert!(evaluate_string("(quote)", &mut ctx).is_err());
+        assert!(evaluate_string("(quote a b)", &mut ctx).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::context::Context;
+use crate::{CommandRegistry, Value};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Scrambles a seed into a well-distributed, non-zero xorshift state (splitmix64)
+fn mix_seed(seed: u64) -> u64 {
+  let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+  z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  z ^= z >> 31;
+  // xorshift gets stuck on a zero state
+  if z == 0 { 0x2545_F491_4F6C_DD1D } else { z }
+}
+
+/// Advances the context's xorshift64 generator, seeding it from the system
+/// clock on first use
+fn next_random(ctx: &mut Context) -> u64 {
+  let mut state = match ctx.rng_state {
+    Some(state) => state,
+    None => {
+      let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+      mix_seed(nanos)
+    }
+  };
+
+  state ^= state << 13;
+  state ^= state >> 7;
+  state ^= state << 17;
+  ctx.rng_state = Some(state);
+  state
+}
+
+/// Register random number commands
+pub fn register_random_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help(
+    "random-seed",
+    "Seed the random generator so that later random-int calls are reproducible",
+    "(random-seed n)",
+    "  (random-seed 42)          ; Same sequence of random-int values on every run",
+    |args, ctx| {
+      if args.len() != 1 {
+        return Err("random-seed expects exactly one argument".to_string());
+      }
+
+      match &args[0] {
+        Value::Int(seed) => {
+          ctx.rng_state = Some(mix_seed(*seed as u64));
+          Ok(Value::Nil)
+        }
+        other => Err(format!("random-seed expects an integer seed, got {}", other)),
+      }
+    },
+  );
+
+  registry.register_closure_with_help(
+    "random-int",
+    "Get a random integer between min and max, both inclusive",
+    "(random-int min max)",
+    "  (random-int 1 6)           ; Roll a die\n  (random-int 20000 29999)   ; Pick a port",
+    |args, ctx| {
+      if args.len() != 2 {
+        return Err("random-int expects exactly two arguments (min and max)".to_string());
+      }
+
+      let (min, max) = match (&args[0], &args[1]) {
+        (Value::Int(min), Value::Int(max)) => (*min, *max),
+        _ => return Err("random-int expects integer bounds".to_string()),
+      };
+      if min > max {
+        return Err(format!("random-int min ({}) must not be greater than max ({})", min, max));
+      }
+
+      // The span of the full i64 range does not fit in a u64, hence u128
+      let span = (max as i128 - min as i128 + 1) as u128;
+      let offset = (next_random(ctx) as u128 % span) as i128;
+      Ok(Value::Int((min as i128 + offset) as i64))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::evaluate_string;
+
+  fn sequence(ctx: &mut Context, count: usize) -> Vec<Value> {
+    (0..count)
+      .map(|_| evaluate_string("(random-int 1 1000)", ctx).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn test_same_seed_same_sequence() {
+    let mut registry = CommandRegistry::new();
+    register_random_commands(&mut registry);
+    let mut first = Context::new(registry);
+    let mut registry = CommandRegistry::new();
+    register_random_commands(&mut registry);
+    let mut second = Context::new(registry);
+    evaluate_string("(random-seed 42)", &mut first).unwrap();
+    evaluate_string("(random-seed 42)", &mut second).unwrap();
+    let expected = sequence(&mut first, 20);
+    assert_eq!(sequence(&mut second, 20), expected);
+
+    // Re-seeding restarts the sequence; another seed gives another one
+    evaluate_string("(random-seed 42)", &mut first).unwrap();
+    assert_eq!(sequence(&mut first, 20), expected);
+    evaluate_string("(random-seed 43)", &mut first).unwrap();
+    assert_ne!(sequence(&mut first, 20), expected);
+  }
+
+  #[test]
+  fn test_random_int_bounds() {
+    let mut registry = CommandRegistry::new();
+    register_random_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    for value in sequence(&mut ctx, 200) {
+      match value {
+        Value::Int(i) => assert!((1..=1000).contains(&i)),
+        other => panic!("expected an integer, got {:?}", other),
+      }
+    }
+
+    assert_eq!(evaluate_string("(random-int 7 7)", &mut ctx).unwrap(), Value::Int(7));
+    let full = ctx.registry.get("random-int").unwrap();
+    assert!(full.execute(vec![Value::Int(i64::MIN), Value::Int(i64::MAX)], &mut ctx).is_ok());
+
+    assert!(evaluate_string("(random-int 5 1)", &mut ctx).unwrap_err().contains("must not be greater"));
+    assert!(evaluate_string("(random-int 1)", &mut ctx).is_err());
+    assert!(evaluate_string("(random-seed \"x\")", &mut ctx).is_err());
+  }
+}
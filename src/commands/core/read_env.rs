@@ -1,11 +1,24 @@
 use crate::commands::core::vars::register_var_commands;
 use crate::commands::core::files::register_file_commands;
-use crate::commands::app::write_env::register_write_env_command;
-use crate::commands::app::version_check::register_version_check_command;
+use crate::commands::core::combine_env::register_combine_env_command;
+use crate::commands::core::env_diff::register_env_diff_command;
+use crate::commands::core::source::register_source_command;
+use crate::commands::core::validate_env::register_validate_env_command;
+use crate::commands::app::write_env::{register_write_docker_env_command, register_write_env_command};
+use crate::commands::app::version_check::{
+  register_normalize_var_name_command, register_version_check_command, register_version_report_command,
+};
 use crate::commands::app::docker::register_docker_command;
+use crate::commands::app::docker_context::register_docker_context_command;
+use crate::commands::app::host::register_host_user_info_command;
+use crate::commands::app::rebuild::register_rebuild_commands;
+use crate::commands::app::clean::register_clean_command;
+use crate::file_ops::EnvParseOptions;
+use crate::context::LogLevel;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Context, Value, tags};
 use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
 
 /// Register app commands
@@ -16,38 +29,84 @@ pub fn register_app_commands(registry: &mut CommandRegistry) {
   // Register filesystem-related core commands
   register_file_commands(registry);
 
-  // Register the write-env command
+  // Register the source command
+  register_source_command(registry);
+
+  // Register the combine-env command
+  register_combine_env_command(registry);
+
+  // Register the env-diff command
+  register_env_diff_command(registry);
+
+  // Register the validate-env command
+  register_validate_env_command(registry);
+
+  // Register the write-env and write-docker-env commands
   register_write_env_command(registry);
+  register_write_docker_env_command(registry);
 
-  // Register the version-check command
+  // Register the version-check, version-report and normalize-var-name commands
   register_version_check_command(registry);
+  register_version_report_command(registry);
+  register_normalize_var_name_command(registry);
 
-  // Register the docker command
+  // Register the docker and docker-context-files commands
   register_docker_command(registry);
+  register_docker_context_command(registry);
+
+  // Register the host-user-info command
+  register_host_user_info_command(registry);
+
+  // Register the needs-rebuild and write-stamp commands
+  register_rebuild_commands(registry);
+
+  // Register the clean command
+  register_clean_command(registry);
 
   // Register the read-env command
   registry.register_closure_with_help_and_tag(
     "read-env",
     "Read environment variables from a file or a list of files and store them in the context",
-    "(read-env path-or-list)",
-    "  (read-env \"config.env\")                 ; Read from config.env relative to basedir\n  (read-env (list \"a.env\" \"b.env\"))   ; Read multiple files in order",
+    "(read-env path-or-list [mode-or-mode-list] [comment-char delimiter])",
+    "  (read-env \"config.env\")                 ; Read from config.env relative to basedir\n  (read-env (list \"a.env\" \"b.env\"))   ; Read multiple files in order\n  (read-env \"defaults.env\" \"no-overwrite\") ; Only set variables not already present\n  (read-env \".env\" (list \"overwrite\" \"strict\")) ; Fail on duplicate keys in a file\n  (read-env \"app.properties\" \"overwrite\" \";\" \":\") ; Use ';' comments and 'key: value' pairs",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "read-env", "executing read-env command");
+      debug_log(ctx, LogLevel::Debug, "read-env", "executing read-env command");
 
-      if args.len() != 1 {
-        return Err("read-env expects exactly one argument (path string or list of strings)".to_string());
+      if args.is_empty() || args.len() == 3 || args.len() > 4 {
+        return Err("read-env expects a path string or list of strings, an optional mode (overwrite, no-overwrite, strict or a list of them) and optional comment and delimiter characters".to_string());
       }
 
+      // Existing variables are overwritten unless "no-overwrite" is given;
+      // "strict" turns duplicate keys within a file into an error
+      let (overwrite, strict) = match args.get(1) {
+        None => (true, false),
+        Some(mode) => parse_mode(mode)?,
+      };
+
+      // Comment and delimiter characters default to '#' and '='
+      let options = if args.len() == 4 {
+        let options = EnvParseOptions {
+          comment: expect_char(&args[2], "comment")?,
+          delimiter: expect_char(&args[3], "delimiter")?,
+        };
+        if options.comment == options.delimiter {
+          return Err("read-env comment and delimiter characters must differ".to_string());
+        }
+        options
+      } else {
+        EnvParseOptions::default()
+      };
+
       // Helper to process a single file path string and return a per-file message
       let mut process_one = |path_arg: &str| -> Result<String, String> {
-        debug_log(ctx, "read-env", &format!("processing path argument: {}", path_arg));
+        debug_log(ctx, LogLevel::Debug, "read-env", &format!("processing path argument: {}", path_arg));
 
         // Resolve path relative to basedir
         let basedir = ctx.get_basedir();
         let file_path = basedir.join(path_arg);
 
-        debug_log(ctx, "read-env", &format!("resolved file path: {}", file_path.display()));
+        debug_log(ctx, LogLevel::Debug, "read-env", &format!("resolved file path: {}", file_path.display()));
 
         // Check if file exists
         if !file_path.exists() {
@@ -60,58 +119,83 @@ pub fn register_app_commands(registry: &mut CommandRegistry) {
           Err(e) => return Err(format!("Failed to read file {}: {}", file_path.display(), e)),
         };
 
-        debug_log(ctx, "read-env", "file read successfully, processing lines");
+        debug_log(ctx, LogLevel::Info, "read-env", "file read successfully, processing lines");
 
         let mut variables_loaded = 0;
+        let mut variables_skipped = 0;
         let mut lines_processed = 0;
+        let mut entries: Vec<(String, String)> = Vec::new();
+        // Key -> (line number, raw value) of its first definition in this file
+        let mut seen: HashMap<String, (usize, String)> = HashMap::new();
 
-        // Process each line
+        // Parse the whole file first, so strict mode rejects duplicate keys
+        // before any variable is changed
         for (line_num, line) in contents.lines().enumerate() {
           lines_processed += 1;
-          let trimmed = line.trim();
 
-          // Skip empty lines and comments
-          if trimmed.is_empty() || trimmed.starts_with('#') {
-            debug_log(ctx, "read-env", &format!("skipping line {}: empty or comment", line_num + 1));
+          // Parse key<delimiter>value format, skipping comments, empty lines
+          // and lines without a key
+          let Some((key, value)) = options.parse_line(line) else {
+            debug_log(ctx, LogLevel::Debug, "read-env", &format!("skipping line {}: not a key{}value pair", line_num + 1, options.delimiter));
             continue;
+          };
+
+          if let Some((first_line, first_value)) = seen.get(&key) {
+            let warning = format!(
+              "duplicate key '{}' in {}: line {} sets '{}', line {} sets '{}'",
+              key,
+              file_path.display(),
+              first_line,
+              ctx.display_value(&key, first_value),
+              line_num + 1,
+              ctx.display_value(&key, &value)
+            );
+            if strict {
+              return Err(warning);
+            }
+            eprintln!("Warning: {}", warning);
+          } else {
+            seen.insert(key.clone(), (line_num + 1, value.clone()));
           }
 
-          // Parse key=value format
-          if let Some(eq_pos) = trimmed.find('=') {
-            let key = trimmed[..eq_pos].trim().to_string();
-            let value = trimmed[eq_pos + 1..].trim().to_string();
+          entries.push((key, value));
+        }
 
-            if key.is_empty() {
-              debug_log(ctx, "read-env", &format!("skipping line {}: empty key", line_num + 1));
-              continue;
-            }
+        // Store the variables in file order
+        for (key, value) in entries {
+          debug_log(ctx, LogLevel::Debug, "read-env", &format!("found variable: {} = {}", key, ctx.display_value(&key, &value)));
 
-            debug_log(ctx, "read-env", &format!("found variable: {} = {}", key, value));
+          if !overwrite && ctx.variables.contains_key(&key) {
+            debug_log(ctx, LogLevel::Debug, "read-env", &format!("skipping variable {}: already set", key));
+            variables_skipped += 1;
+            continue;
+          }
 
-            // Interpolate variables in the value
-            let interpolated_value = match interpolate_variables(&value, ctx) {
-              Ok(val) => val,
-              Err(e) => return Err(format!("Error interpolating variable '{}': {}", key, e)),
-            };
+          // Interpolate variables in the value
+          let interpolated_value = match interpolate_variables(&value, ctx) {
+            Ok(val) => val,
+            Err(e) => return Err(format!("Error interpolating variable '{}': {}", key, e)),
+          };
 
-            debug_log(ctx, "read-env", &format!("interpolated value: {} = {}", key, interpolated_value));
+          debug_log(ctx, LogLevel::Debug, "read-env", &format!("interpolated value: {} = {}", key, ctx.display_value(&key, &interpolated_value)));
 
-            // Store in context
-            ctx.set_variable(key, Value::Str(interpolated_value));
-            variables_loaded += 1;
-          } else {
-            debug_log(ctx, "read-env", &format!("skipping line {}: no '=' found", line_num + 1));
-          }
+          // Store in context
+          ctx.set_variable(key, Value::Str(interpolated_value));
+          variables_loaded += 1;
         }
 
-        let result_msg = format!(
-          "Loaded {} variables from {} (processed {} lines)",
+        let mut result_msg = format!(
+          "Loaded {} variables from {} (processed {} lines",
           variables_loaded,
           file_path.display(),
           lines_processed
         );
+        if !overwrite {
+          result_msg.push_str(&format!(", skipped {} already set", variables_skipped));
+        }
+        result_msg.push(')');
 
-        debug_log(ctx, "read-env", &format!("completed: {}", result_msg));
+        debug_log(ctx, LogLevel::Info, "read-env", &format!("completed: {}", result_msg));
         Ok(result_msg)
       };
 
@@ -148,10 +232,50 @@ pub fn register_app_commands(registry: &mut CommandRegistry) {
   );
 }
 
+/// Parses the read-env mode argument, a single mode or a list of modes
+///
+/// # Returns
+/// * `Result<(bool, bool), String>` - `(overwrite, strict)`
+fn parse_mode(value: &Value) -> Result<(bool, bool), String> {
+  let modes = match value {
+    Value::List(items) => items.clone(),
+    other => vec![other.clone()],
+  };
+
+  let mut overwrite = true;
+  let mut strict = false;
+  for mode in &modes {
+    match mode {
+      Value::Str(m) if m == "overwrite" => overwrite = true,
+      Value::Str(m) if m == "no-overwrite" => overwrite = false,
+      Value::Str(m) if m == "strict" => strict = true,
+      other => {
+        return Err(format!(
+          "read-env mode must be \"overwrite\", \"no-overwrite\" or \"strict\", found: {}",
+          other
+        ));
+      }
+    }
+  }
+  Ok((overwrite, strict))
+}
+
+/// Extracts a single character from a read-env option argument
+fn expect_char(value: &Value, name: &str) -> Result<char, String> {
+  if let Value::Str(s) = value {
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+      return Ok(c);
+    }
+  }
+  Err(format!("read-env {} must be a single character, found: {}", name, value))
+}
+
 /// Interpolate variables in a string value
-/// Supports ${key} format with single-pass resolution
+/// Supports ${key} and ${key:-default} formats with single-pass resolution;
+/// the default is used literally when key is neither in context nor in the process env
 pub fn interpolate_variables(value: &str, ctx: &Context) -> Result<String, String> {
-  let var_regex = Regex::new(r"\$\{([^}]+)\}").unwrap();
+  let var_regex = Regex::new(r"\$\{([^}]+?)(?::-([^}]*))?\}").unwrap();
   let mut result = String::new();
   let mut last_end = 0;
 
@@ -167,6 +291,9 @@ pub fn interpolate_variables(value: &str, ctx: &Context) -> Result<String, Strin
       ctx_value.to_string()
     } else if let Ok(env_value) = std::env::var(var_name) {
       env_value
+    } else if let Some(default) = cap.get(2) {
+      // Variable not found, use the provided default
+      default.as_str().to_string()
     } else {
       // Variable not found, leave as is
       full_match.as_str().to_string()
@@ -243,4 +370,180 @@ mod tests {
     let result = interpolate_variables("${VAR1}_${VAR2}_${VAR3}", &ctx).unwrap();
     assert_eq!(result, "${VAR1}_${VAR2}_${VAR3}");
   }
+
+  #[test]
+  fn test_read_env_no_overwrite_keeps_existing() {
+    let mut registry = CommandRegistry::new();
+    register_app_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_read_env_no_overwrite");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("defaults.env"), "PRESET=from_file\nNEW_KEY=new\n").unwrap();
+    ctx.set_basedir(dir.clone());
+    ctx.set_variable("PRESET".to_string(), Value::Str("from_cli".to_string()));
+
+    let args = vec![
+      Value::Str("defaults.env".to_string()),
+      Value::Str("no-overwrite".to_string()),
+    ];
+    let result = ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert!(result.to_string().contains("Loaded 1 variables"));
+    assert!(result.to_string().contains("skipped 1 already set"));
+    assert_eq!(ctx.get_variable("PRESET"), Some(Value::Str("from_cli".to_string())));
+    assert_eq!(ctx.get_variable("NEW_KEY"), Some(Value::Str("new".to_string())));
+
+    // Default mode still overwrites
+    let args = vec![Value::Str("defaults.env".to_string())];
+    ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(ctx.get_variable("PRESET"), Some(Value::Str("from_file".to_string())));
+
+    let args = vec![Value::Str("defaults.env".to_string()), Value::Str("merge".to_string())];
+    assert!(ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_interpolate_variables_default_values() {
+    let registry = CommandRegistry::new();
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("PORT".to_string(), Value::Str("8080".to_string()));
+
+    // Present variable ignores the default
+    let result = interpolate_variables("port=${PORT:-80}", &ctx).unwrap();
+    assert_eq!(result, "port=8080");
+
+    // Absent variable with default
+    let result = interpolate_variables("host=${DPM_TEST_UNSET_HOST:-localhost}:${DPM_TEST_UNSET_PORT:-}", &ctx).unwrap();
+    assert_eq!(result, "host=localhost:");
+
+    // Defaults may contain literal text
+    let result = interpolate_variables("${DPM_TEST_UNSET:-a b/c-d}", &ctx).unwrap();
+    assert_eq!(result, "a b/c-d");
+
+    // Absent variable without default is left unchanged
+    let result = interpolate_variables("${DPM_TEST_UNSET}", &ctx).unwrap();
+    assert_eq!(result, "${DPM_TEST_UNSET}");
+
+    // Context attributes keep working
+    let result = interpolate_variables("${CTX:debug_print}", &ctx).unwrap();
+    assert_eq!(result, "false");
+  }
+
+  #[test]
+  fn test_read_env_custom_comment_and_delimiter() {
+    let mut registry = CommandRegistry::new();
+    register_app_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_read_env_custom_syntax");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let contents = "; database settings\nDB_HOST: localhost\nDB_URL : postgres://${DB_HOST}:5432\n# not a comment here\n";
+    fs::write(dir.join("app.properties"), contents).unwrap();
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![
+      Value::Str("app.properties".to_string()),
+      Value::Str("overwrite".to_string()),
+      Value::Str(";".to_string()),
+      Value::Str(":".to_string()),
+    ];
+    let result = ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert!(result.to_string().contains("Loaded 2 variables"));
+    assert_eq!(ctx.get_variable("DB_HOST"), Some(Value::Str("localhost".to_string())));
+    assert_eq!(
+      ctx.get_variable("DB_URL"),
+      Some(Value::Str("postgres://localhost:5432".to_string()))
+    );
+
+    // The file-level reader honours the same options
+    let path = dir.join("app.properties");
+    let options = EnvParseOptions { comment: ';', delimiter: ':' };
+    let vars = crate::file_ops::read_env_file_with_options(&path.to_string_lossy(), &options).unwrap();
+    assert_eq!(vars.len(), 2);
+    assert_eq!(vars.get("DB_HOST"), Some(&"localhost".to_string()));
+
+    // Options must be single, distinct characters
+    for (comment, delimiter) in [("//", ":"), (":", ":")] {
+      let args = vec![
+        Value::Str("app.properties".to_string()),
+        Value::Str("overwrite".to_string()),
+        Value::Str(comment.to_string()),
+        Value::Str(delimiter.to_string()),
+      ];
+      assert!(ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).is_err());
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_read_env_strips_export_prefix() {
+    let mut registry = CommandRegistry::new();
+    register_app_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_read_env_export");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let contents = "export FOO=bar\nBARE=plain\nexport\tTABBED=\"quoted value\"\nexported=kept\n";
+    fs::write(dir.join("shell.env"), contents).unwrap();
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![Value::Str("shell.env".to_string())];
+    let result = ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert!(result.to_string().contains("Loaded 4 variables"));
+    assert_eq!(ctx.get_variable("FOO"), Some(Value::Str("bar".to_string())));
+    assert_eq!(ctx.get_variable("BARE"), Some(Value::Str("plain".to_string())));
+    assert_eq!(ctx.get_variable("TABBED"), Some(Value::Str("quoted value".to_string())));
+    assert_eq!(ctx.get_variable("exported"), Some(Value::Str("kept".to_string())));
+    assert!(!ctx.has_variable("export FOO"));
+
+    let vars = crate::file_ops::read_env_file(&dir.join("shell.env").to_string_lossy()).unwrap();
+    assert_eq!(vars.get("FOO"), Some(&"bar".to_string()));
+    assert_eq!(vars.get("BARE"), Some(&"plain".to_string()));
+    assert_eq!(vars.get("TABBED"), Some(&"quoted value".to_string()));
+    assert_eq!(vars.len(), 4);
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_read_env_duplicate_keys() {
+    let mut registry = CommandRegistry::new();
+    register_app_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = PathBuf::from("target").join("test_read_env_duplicates");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("dup.env"), "PORT=80\nHOST=localhost\nPORT=8080\n").unwrap();
+    ctx.set_basedir(dir.clone());
+
+    // Default mode keeps the last value
+    let args = vec![Value::Str("dup.env".to_string())];
+    ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(ctx.get_variable("PORT"), Some(Value::Str("8080".to_string())));
+
+    // Strict mode names the key and both values
+    ctx.remove_variable("PORT");
+    ctx.remove_variable("HOST");
+    let args = vec![Value::Str("dup.env".to_string()), Value::Str("strict".to_string())];
+    let err = ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).unwrap_err();
+    assert!(err.contains("duplicate key 'PORT'"), "{}", err);
+    assert!(err.contains("line 1 sets '80', line 3 sets '8080'"), "{}", err);
+    // Nothing from the rejected file is stored
+    assert!(!ctx.has_variable("PORT"));
+    assert!(!ctx.has_variable("HOST"));
+
+    // Modes can be combined in a list
+    let modes = Value::List(vec![Value::Str("no-overwrite".to_string()), Value::Str("strict".to_string())]);
+    let args = vec![Value::Str("dup.env".to_string()), modes];
+    assert!(ctx.registry.get("read-env").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
 }
@@ -0,0 +1,143 @@
+use crate::{Command, Value, evaluate};
+use crate::context::{Context, LogLevel};
+use crate::utils::debug_log;
+use std::thread;
+use std::time::Duration;
+
+/// Retry command - re-evaluates an expression until it succeeds
+///
+/// `(retry attempts delay-ms expr)` evaluates `expr` up to `attempts` times,
+/// sleeping `delay-ms` milliseconds between failed tries. The first
+/// successful value is returned; when every attempt fails, the last error is.
+pub struct RetryCommand;
+
+impl Command for RetryCommand {
+    fn execute(&self, _args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Err("retry needs an unevaluated expression and cannot be used as a pipe stage".to_string())
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        let [attempts, delay, expression] = args.as_slice() else {
+            return Err("retry expects exactly three arguments: attempts, delay-ms and expression".to_string());
+        };
+
+        let attempts = match evaluate(attempts, ctx)? {
+            Value::Int(n) if n > 0 => n,
+            _ => return Err("retry attempts must be a positive integer".to_string()),
+        };
+        let delay = match evaluate(delay, ctx)? {
+            Value::Int(ms) if ms >= 0 => Duration::from_millis(ms as u64),
+            _ => return Err("retry delay-ms must be a non-negative integer".to_string()),
+        };
+
+        let mut attempt = 1;
+        loop {
+            match evaluate(expression, ctx) {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt >= attempts => return Err(e),
+                Err(e) => {
+                    debug_log(
+                        ctx,
+                        LogLevel::Info,
+                        "retry",
+                        &format!("attempt {}/{} failed: {}", attempt, attempts, e),
+                    );
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "retry"
+    }
+
+    fn description(&self) -> &'static str {
+        "Evaluate an expression, retrying with a delay while it fails"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(retry attempts delay-ms expr)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (retry 3 2000 (docker \"pull\" \"nginx\"))   ; Up to 3 tries, 2 seconds apart"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::TryCommand;
+    use crate::commands::core::list_utils::register_list_commands;
+    use crate::commands::core::math::SumCommand;
+    use crate::commands::core::try_catch::LAST_ERROR_INFO_VAR;
+    use crate::commands::core::vars::register_var_commands;
+    use crate::{CommandRegistry, evaluate_string};
+
+    /// Registers `flaky`, which fails until it has been called `n` times
+    fn register_flaky(registry: &mut CommandRegistry) {
+        registry.register_closure("flaky", "Fails until called N times", |args, ctx| {
+            let calls = match ctx.get_variable("CALLS") {
+                Some(Value::Int(n)) => n + 1,
+                _ => 1,
+            };
+            ctx.set_variable("CALLS".to_string(), Value::Int(calls));
+            match args.as_slice() {
+                [Value::Int(n)] if calls >= *n => Ok(Value::Str("ok".to_string())),
+                _ => Err(format!("flaky failure #{}", calls)),
+            }
+        });
+    }
+
+    #[test]
+    fn test_retry_until_success() {
+        let mut registry = CommandRegistry::new();
+        registry.register(RetryCommand);
+        registry.register(TryCommand);
+        register_var_commands(&mut registry);
+        register_flaky(&mut registry);
+        let mut ctx = Context::new(registry);
+        let result = evaluate_string("(retry 5 1 (flaky 3))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Str("ok".to_string()));
+        assert_eq!(ctx.get_variable("CALLS"), Some(Value::Int(3)));
+    }
+
+    #[test]
+    fn test_retry_returns_last_error() {
+        let mut registry = CommandRegistry::new();
+        registry.register(RetryCommand);
+        registry.register(TryCommand);
+        register_var_commands(&mut registry);
+        register_flaky(&mut registry);
+        let mut ctx = Context::new(registry);
+        let err = evaluate_string("(retry 2 0 (flaky 3))", &mut ctx).unwrap_err();
+        assert!(err.contains("flaky failure #2"), "{}", err);
+        assert_eq!(ctx.get_variable("CALLS"), Some(Value::Int(2)));
+
+        assert!(evaluate_string("(retry 0 0 (flaky 1))", &mut ctx).is_err());
+        assert!(evaluate_string("(retry 1 -5 (flaky 1))", &mut ctx).is_err());
+        assert!(evaluate_string("(retry 1 0)", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_retry_recovered_error_is_not_reported_by_try() {
+        let mut registry = CommandRegistry::new();
+        registry.register(RetryCommand);
+        registry.register(TryCommand);
+        registry.register(SumCommand);
+        register_list_commands(&mut registry);
+        register_var_commands(&mut registry);
+        register_flaky(&mut registry);
+        let mut ctx = Context::new(registry);
+        // flaky fails once and is retried; the error caught by try comes from sum
+        evaluate_string("(try (list (retry 3 0 (flaky 2)) (sum \"x\")) (catch 0))", &mut ctx).unwrap();
+        let info = ctx.get_variable(LAST_ERROR_INFO_VAR).unwrap();
+        assert!(info.to_string().ends_with("(command sum))"), "{}", info);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::context::{Context, LogLevel};
+use crate::lisp_interpreter::{code_chars, evaluate, parse_string_normalized};
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Value, tags};
+use std::fs;
+
+/// Register the source command
+pub fn register_source_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "source",
+    "Evaluate a script file in the current context, relative to the base directory",
+    "(source path)",
+    "  (source \"common.lisp\")        ; Run common.lisp, keeping its variables\n  (source \"env/dev.lisp\")       ; Paths are resolved against the base directory",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "source", "executing source command");
+
+      if args.len() != 1 {
+        return Err("source expects exactly one argument (file path)".to_string());
+      }
+
+      let file_path = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("source file path must be a string".to_string()),
+      };
+
+      source_file(ctx, &file_path)
+    },
+  );
+}
+
+/// Reads a script file and evaluates each of its expressions in `ctx`
+///
+/// # Arguments
+/// * `ctx` - Context in which the expressions are evaluated
+/// * `file_path` - Script path, resolved against the base directory
+///
+/// # Returns
+/// * `Result<Value, String>` - Value of the last expression, or an error
+///   naming the file and the line of the failing expression
+fn source_file(ctx: &mut Context, file_path: &str) -> Result<Value, String> {
+  let resolved = ctx.get_basedir().join(file_path);
+  let canonical = fs::canonicalize(&resolved)
+    .map_err(|e| format!("{}: cannot open file: {}", resolved.display(), e))?;
+  let display = canonical.display().to_string();
+
+  if ctx.sourcing.contains(&canonical) {
+    return Err(format!("{}: recursive source detected", display));
+  }
+
+  debug_log(ctx, LogLevel::Debug, "source", &format!("reading {}", display));
+
+  let content = fs::read_to_string(&canonical)
+    .map_err(|e| format!("{}: cannot read file: {}", display, e))?;
+  let expressions = parse_string_normalized(&content)
+    .map_err(|e| format!("{}: {}", display, e))?;
+
+  // Line numbers are only reliable when every expression is a list
+  let lines = top_level_form_lines(&content);
+  let line_of = |index: usize| {
+    if lines.len() == expressions.len() {
+      lines.get(index).copied()
+    } else {
+      None
+    }
+  };
+
+  ctx.sourcing.insert(canonical.clone());
+  let mut result = Ok(Value::Nil);
+  for (index, expression) in expressions.iter().enumerate() {
+    result = evaluate(expression, ctx).map_err(|e| match line_of(index) {
+      Some(line) => format!("{}:{}: {}", display, line, e),
+      None => format!("{}: {}", display, e),
+    });
+    if result.is_err() {
+      break;
+    }
+  }
+  ctx.sourcing.remove(&canonical);
+
+  if result.is_ok() {
+    debug_log(
+      ctx,
+      LogLevel::Info,
+      "source",
+      &format!("evaluated {} expressions from {}", expressions.len(), display),
+    );
+  }
+  result
+}
+
+/// Returns the 1-based line on which each top-level list in `content` starts
+/// Parentheses inside strings, character literals and `;` comments are ignored
+fn top_level_form_lines(content: &str) -> Vec<usize> {
+  let mut lines = Vec::new();
+  let mut depth = 0usize;
+  let mut line = 1;
+  let mut counted = 0;
+
+  for (index, ch) in code_chars(content) {
+    match ch {
+      '(' => {
+        if depth == 0 {
+          line += content[counted..index].matches('\n').count();
+          counted = index;
+          lines.push(line);
+        }
+        depth += 1;
+      }
+      ')' => depth = depth.saturating_sub(1),
+      _ => {}
+    }
+  }
+
+  lines
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::commands::core::vars::register_var_commands;
+  use crate::commands::core::math::SumCommand;
+  use crate::evaluate_string;
+
+  #[test]
+  fn test_source_sets_variables_in_context() {
+    let base = std::env::temp_dir().join("dpm_test_source_vars");
+    let _ = fs::remove_dir_all(&base);
+    fs::create_dir_all(&base).unwrap();
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_var_commands(&mut registry);
+    register_source_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(base.clone());
+    fs::write(
+      base.join("vars.lisp"),
+      "; shared settings\n(set-var \"GREETING\" \"hello\")\n(sum 1 2)\n",
+    )
+    .unwrap();
+
+    let result = evaluate_string("(source \"vars.lisp\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Int(3));
+
+    let value = evaluate_string("(get-var \"GREETING\")", &mut ctx).unwrap();
+    assert_eq!(value, Value::Str("hello".to_string()));
+    assert!(ctx.sourcing.is_empty());
+
+    let _ = fs::remove_dir_all(&base);
+  }
+
+  #[test]
+  fn test_source_reports_file_and_line() {
+    let base = std::env::temp_dir().join("dpm_test_source_line");
+    let _ = fs::remove_dir_all(&base);
+    fs::create_dir_all(&base).unwrap();
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_var_commands(&mut registry);
+    register_source_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(base.clone());
+    fs::write(
+      base.join("broken.lisp"),
+      "(set-var \"A\" \"1\")\n\n(sum\n  1\n  2)\n(no-such-command)\n",
+    )
+    .unwrap();
+
+    let err = evaluate_string("(source \"broken.lisp\")", &mut ctx).unwrap_err();
+    assert!(err.contains("broken.lisp:6: Unknown command: no-such-command"), "{}", err);
+    // Expressions before the failure were still evaluated
+    assert!(ctx.has_variable("A"));
+
+    let err = evaluate_string("(source \"missing.lisp\")", &mut ctx).unwrap_err();
+    assert!(err.contains("missing.lisp: cannot open file"));
+
+    let _ = fs::remove_dir_all(&base);
+  }
+
+  #[test]
+  fn test_source_rejects_recursion() {
+    let base = std::env::temp_dir().join("dpm_test_source_recursion");
+    let _ = fs::remove_dir_all(&base);
+    fs::create_dir_all(&base).unwrap();
+    let mut registry = CommandRegistry::new();
+    registry.register(SumCommand);
+    register_var_commands(&mut registry);
+    register_source_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_basedir(base.clone());
+    fs::write(base.join("a.lisp"), "(source \"b.lisp\")\n").unwrap();
+    fs::write(base.join("b.lisp"), "(source \"a.lisp\")\n").unwrap();
+
+    let err = evaluate_string("(source \"a.lisp\")", &mut ctx).unwrap_err();
+    assert!(err.contains("recursive source detected"), "{}", err);
+    assert!(ctx.sourcing.is_empty());
+
+    let _ = fs::remove_dir_all(&base);
+  }
+
+  #[test]
+  fn test_top_level_form_lines() {
+    let content = "(a \"(\" ; )\n  (b))\n; (c)\n\n(d)";
+    assert_eq!(top_level_form_lines(content), vec![1, 5]);
+
+    let content = "(a \"\\\")\" #\\) \"x\\\\\")\n(b)";
+    assert_eq!(top_level_form_lines(content), vec![1, 2]);
+  }
+}
@@ -0,0 +1,398 @@
+use crate::{CommandRegistry, Value};
+
+/// Extracts a string argument, naming the command in the error
+fn expect_str<'a>(command_name: &str, value: &'a Value) -> Result<&'a str, String> {
+  match value {
+    Value::Str(s) => Ok(s),
+    other => Err(format!("{} expects a string, got {}", command_name, other)),
+  }
+}
+
+/// Extracts a non-negative character index argument
+fn expect_index(command_name: &str, value: &Value) -> Result<usize, String> {
+  match value {
+    Value::Int(i) if *i >= 0 => Ok(*i as usize),
+    other => Err(format!("{} expects a non-negative integer index, got {}", command_name, other)),
+  }
+}
+
+/// Registers a single-argument command mapping a string to a new string
+fn register_string_transform(
+  registry: &mut CommandRegistry,
+  name: &'static str,
+  description: &'static str,
+  syntax: &'static str,
+  examples: &'static str,
+  transform: fn(&str) -> String,
+) {
+  registry.register_closure_with_help(
+    name,
+    description,
+    syntax,
+    examples,
+    move |args, _ctx| {
+      if args.len() != 1 {
+        return Err(format!("{} expects exactly one argument", name));
+      }
+      Ok(Value::Str(transform(expect_str(name, &args[0])?)))
+    },
+  );
+}
+
+/// Registers a two-argument string predicate returning a boolean
+fn register_string_predicate(
+  registry: &mut CommandRegistry,
+  name: &'static str,
+  description: &'static str,
+  syntax: &'static str,
+  examples: &'static str,
+  predicate: fn(&str, &str) -> bool,
+) {
+  registry.register_closure_with_help(
+    name,
+    description,
+    syntax,
+    examples,
+    move |args, _ctx| {
+      if args.len() != 2 {
+        return Err(format!("{} expects exactly two arguments", name));
+      }
+      Ok(Value::Bool(predicate(
+        expect_str(name, &args[0])?,
+        expect_str(name, &args[1])?,
+      )))
+    },
+  );
+}
+
+/// Shared implementation of string-pad-left and string-pad-right
+///
+/// Pads `(string width [fill])` to `width` characters with the fill
+/// character (a space by default); longer strings are returned unchanged.
+fn pad_string(command_name: &str, args: &[Value], pad_left: bool) -> Result<Value, String> {
+  if args.len() < 2 || args.len() > 3 {
+    return Err(format!(
+      "{} expects a string, a width and an optional fill character",
+      command_name
+    ));
+  }
+
+  let text = expect_str(command_name, &args[0])?;
+  let width = expect_index(command_name, &args[1])?;
+  let fill = match args.get(2) {
+    Some(value) => {
+      let fill = expect_str(command_name, value)?;
+      let mut chars = fill.chars();
+      match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => {
+          return Err(format!(
+            "{} fill must be a single character, got \"{}\"",
+            command_name, fill
+          ));
+        }
+      }
+    }
+    None => ' ',
+  };
+
+  let length = text.chars().count();
+  if length >= width {
+    return Ok(Value::Str(text.to_string()));
+  }
+
+  let padding: String = std::iter::repeat_n(fill, width - length).collect();
+  if pad_left {
+    Ok(Value::Str(format!("{}{}", padding, text)))
+  } else {
+    Ok(Value::Str(format!("{}{}", text, padding)))
+  }
+}
+
+/// Register string manipulation commands
+pub fn register_string_commands(registry: &mut CommandRegistry) {
+  register_string_transform(
+    registry,
+    "string-trim",
+    "Remove leading and trailing whitespace",
+    "(string-trim string)",
+    "  (string-trim \"  value \\n\")  ; Returns \"value\"",
+    |s| s.trim().to_string(),
+  );
+
+  register_string_transform(
+    registry,
+    "string-trim-start",
+    "Remove leading whitespace",
+    "(string-trim-start string)",
+    "  (string-trim-start \"  value \")  ; Returns \"value \"",
+    |s| s.trim_start().to_string(),
+  );
+
+  register_string_transform(
+    registry,
+    "string-trim-end",
+    "Remove trailing whitespace",
+    "(string-trim-end string)",
+    "  (string-trim-end \"  value \")  ; Returns \"  value\"",
+    |s| s.trim_end().to_string(),
+  );
+
+  register_string_transform(
+    registry,
+    "string-upper",
+    "Convert a string to uppercase",
+    "(string-upper string)",
+    "  (string-upper \"my-service\")  ; Returns \"MY-SERVICE\"",
+    |s| s.to_uppercase(),
+  );
+
+  register_string_transform(
+    registry,
+    "string-lower",
+    "Convert a string to lowercase",
+    "(string-lower string)",
+    "  (string-lower \"PRODUCTION\")  ; Returns \"production\"",
+    |s| s.to_lowercase(),
+  );
+
+  register_string_predicate(
+    registry,
+    "string-starts-with",
+    "Check whether a string starts with a prefix",
+    "(string-starts-with string prefix)",
+    "  (string-starts-with \"docker-compose.yml\" \"docker\")  ; Returns true",
+    |s, prefix| s.starts_with(prefix),
+  );
+
+  register_string_predicate(
+    registry,
+    "string-ends-with",
+    "Check whether a string ends with a suffix",
+    "(string-ends-with string suffix)",
+    "  (string-ends-with \"app.env\" \".env\")  ; Returns true",
+    |s, suffix| s.ends_with(suffix),
+  );
+
+  register_string_predicate(
+    registry,
+    "string-contains",
+    "Check whether a string contains a substring",
+    "(string-contains string needle)",
+    "  (string-contains (get-var \"PROFILE\") \"prod\")  ; Route on the profile name",
+    |s, needle| s.contains(needle),
+  );
+
+  registry.register_closure_with_help(
+    "string-length",
+    "Get the number of characters (not bytes) in a string",
+    "(string-length string)",
+    "  (string-length \"dpm\")    ; Returns 3\n  (string-length \"città\")  ; Returns 5",
+    |args, _ctx| {
+      if args.len() != 1 {
+        return Err("string-length expects exactly one argument".to_string());
+      }
+      Ok(Value::Int(expect_str("string-length", &args[0])?.chars().count() as i64))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "string-pad-left",
+    "Pad a string on the left to the given width with a fill character (default space)",
+    "(string-pad-left string width [fill])",
+    "  (string-pad-left \"7\" 3 \"0\")   ; Returns \"007\"\n  (string-pad-left \"abc\" 5)     ; Returns \"  abc\"",
+    |args, _ctx| pad_string("string-pad-left", &args, true),
+  );
+
+  registry.register_closure_with_help(
+    "string-pad-right",
+    "Pad a string on the right to the given width with a fill character (default space)",
+    "(string-pad-right string width [fill])",
+    "  (string-pad-right \"name\" 8 \".\")  ; Returns \"name....\"\n  (string-pad-right \"abc\" 5)      ; Returns \"abc  \"",
+    |args, _ctx| pad_string("string-pad-right", &args, false),
+  );
+
+  registry.register_closure_with_help(
+    "substring",
+    "Get the characters of a string from start (inclusive) to end (exclusive)",
+    "(substring string start end)",
+    "  (substring \"docker-compose\" 0 6)  ; Returns \"docker\"\n  (substring \"città\" 3 5)           ; Indices count characters, returns \"tà\"",
+    |args, _ctx| {
+      if args.len() != 3 {
+        return Err("substring expects exactly three arguments (string, start and end)".to_string());
+      }
+
+      let text = expect_str("substring", &args[0])?;
+      let start = expect_index("substring", &args[1])?;
+      let end = expect_index("substring", &args[2])?;
+
+      let length = text.chars().count();
+      if start > end || end > length {
+        return Err(format!(
+          "substring range {}..{} is out of bounds: valid indices are 0..={} with start <= end",
+          start, end, length
+        ));
+      }
+
+      Ok(Value::Str(text.chars().skip(start).take(end - start).collect()))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "char-at",
+    "Get the character at the given index as a one-character string",
+    "(char-at string index)",
+    "  (char-at \"dpm\" 0)    ; Returns \"d\"\n  (char-at \"città\" 4)  ; Returns \"à\"",
+    |args, _ctx| {
+      if args.len() != 2 {
+        return Err("char-at expects exactly two arguments (string and index)".to_string());
+      }
+
+      let text = expect_str("char-at", &args[0])?;
+      let index = expect_index("char-at", &args[1])?;
+
+      match text.chars().nth(index) {
+        Some(c) => Ok(Value::Str(c.to_string())),
+        None => {
+          let length = text.chars().count();
+          if length == 0 {
+            Err(format!("char-at index {} is out of bounds: the string is empty", index))
+          } else {
+            Err(format!(
+              "char-at index {} is out of bounds: valid indices are 0..={}",
+              index,
+              length - 1
+            ))
+          }
+        }
+      }
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+  use crate::evaluate_string;
+
+  fn eval(ctx: &mut Context, input: &str) -> Result<Value, String> {
+    evaluate_string(input, ctx)
+  }
+
+  fn s(text: &str) -> Value {
+    Value::Str(text.to_string())
+  }
+
+  #[test]
+  fn test_substring_and_char_at_ascii() {
+    let mut registry = CommandRegistry::new();
+    register_string_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(substring \"docker-compose\" 0 6)").unwrap(), s("docker"));
+    assert_eq!(eval(&mut ctx, "(substring \"docker-compose\" 7 14)").unwrap(), s("compose"));
+    assert_eq!(eval(&mut ctx, "(substring \"abc\" 1 1)").unwrap(), s(""));
+    assert_eq!(eval(&mut ctx, "(char-at \"abc\" 2)").unwrap(), s("c"));
+
+    let err = eval(&mut ctx, "(substring \"abc\" 1 4)").unwrap_err();
+    assert!(err.contains("valid indices are 0..=3"), "{}", err);
+    assert!(eval(&mut ctx, "(substring \"abc\" 2 1)").is_err());
+    assert!(eval(&mut ctx, "(substring \"abc\" -1 1)").is_err());
+
+    let err = eval(&mut ctx, "(char-at \"abc\" 3)").unwrap_err();
+    assert!(err.contains("valid indices are 0..=2"), "{}", err);
+    assert!(eval(&mut ctx, "(char-at \"\" 0)").unwrap_err().contains("empty"));
+  }
+
+  #[test]
+  fn test_substring_and_char_at_multibyte() {
+    let mut registry = CommandRegistry::new();
+    register_string_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    // "è" and "🐳" are multi-byte: byte slicing at these indices would panic
+    assert_eq!(eval(&mut ctx, "(substring \"perché 🐳 ok\" 5 8)").unwrap(), s("é 🐳"));
+    assert_eq!(eval(&mut ctx, "(char-at \"perché 🐳 ok\" 7)").unwrap(), s("🐳"));
+    assert_eq!(eval(&mut ctx, "(char-at \"perché\" 5)").unwrap(), s("é"));
+
+    let err = eval(&mut ctx, "(substring \"🐳🐳\" 0 3)").unwrap_err();
+    assert!(err.contains("valid indices are 0..=2"), "{}", err);
+  }
+
+  #[test]
+  fn test_trim_variants() {
+    let mut registry = CommandRegistry::new();
+    register_string_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(string-trim \"  a b \t\")").unwrap(), s("a b"));
+    assert_eq!(eval(&mut ctx, "(string-trim-start \"  a b  \")").unwrap(), s("a b  "));
+    assert_eq!(eval(&mut ctx, "(string-trim-end \"  a b  \")").unwrap(), s("  a b"));
+
+    // Whitespace-only input trims to the empty string
+    assert_eq!(eval(&mut ctx, "(string-trim \"   \")").unwrap(), s(""));
+    assert_eq!(eval(&mut ctx, "(string-trim-start \"   \")").unwrap(), s(""));
+    assert_eq!(eval(&mut ctx, "(string-trim-end \"   \")").unwrap(), s(""));
+
+    // Already-trimmed input is unchanged
+    assert_eq!(eval(&mut ctx, "(string-trim \"abc\")").unwrap(), s("abc"));
+    assert_eq!(eval(&mut ctx, "(string-trim-end \"abc\")").unwrap(), s("abc"));
+
+    assert!(eval(&mut ctx, "(string-trim 1)").is_err());
+    assert!(eval(&mut ctx, "(string-trim \"a\" \"b\")").is_err());
+  }
+
+  #[test]
+  fn test_case_conversions() {
+    let mut registry = CommandRegistry::new();
+    register_string_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(string-upper \"my-service_1\")").unwrap(), s("MY-SERVICE_1"));
+    assert_eq!(eval(&mut ctx, "(string-lower \"PRODUCTION\")").unwrap(), s("production"));
+    assert_eq!(eval(&mut ctx, "(string-upper \"città\")").unwrap(), s("CITTÀ"));
+    assert_eq!(eval(&mut ctx, "(string-lower \"\")").unwrap(), s(""));
+  }
+
+  #[test]
+  fn test_string_predicates() {
+    let mut registry = CommandRegistry::new();
+    register_string_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(string-starts-with \"docker-compose\" \"docker\")").unwrap(), Value::Bool(true));
+    assert_eq!(eval(&mut ctx, "(string-starts-with \"docker-compose\" \"compose\")").unwrap(), Value::Bool(false));
+    assert_eq!(eval(&mut ctx, "(string-ends-with \"app.env\" \".env\")").unwrap(), Value::Bool(true));
+    assert_eq!(eval(&mut ctx, "(string-ends-with \"app.env\" \"app\")").unwrap(), Value::Bool(false));
+    assert_eq!(eval(&mut ctx, "(string-contains \"staging-prod-eu\" \"prod\")").unwrap(), Value::Bool(true));
+    assert_eq!(eval(&mut ctx, "(string-contains \"staging\" \"prod\")").unwrap(), Value::Bool(false));
+
+    // The empty needle matches everything, even the empty string
+    assert_eq!(eval(&mut ctx, "(string-starts-with \"abc\" \"\")").unwrap(), Value::Bool(true));
+    assert_eq!(eval(&mut ctx, "(string-ends-with \"abc\" \"\")").unwrap(), Value::Bool(true));
+    assert_eq!(eval(&mut ctx, "(string-contains \"\" \"\")").unwrap(), Value::Bool(true));
+
+    assert!(eval(&mut ctx, "(string-contains \"abc\" 1)").is_err());
+    assert!(eval(&mut ctx, "(string-contains \"abc\")").is_err());
+  }
+
+  #[test]
+  fn test_string_length_and_pad() {
+    let mut registry = CommandRegistry::new();
+    register_string_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(eval(&mut ctx, "(string-length \"abc\")").unwrap(), Value::Int(3));
+    assert_eq!(eval(&mut ctx, "(string-length \"città 🐳\")").unwrap(), Value::Int(7));
+    assert_eq!(eval(&mut ctx, "(string-length \"\")").unwrap(), Value::Int(0));
+
+    assert_eq!(eval(&mut ctx, "(string-pad-left \"7\" 3 \"0\")").unwrap(), s("007"));
+    assert_eq!(eval(&mut ctx, "(string-pad-right \"ab\" 4)").unwrap(), s("ab  "));
+    // Width counts characters, and the fill may itself be multibyte
+    assert_eq!(eval(&mut ctx, "(string-pad-left \"tà\" 4 \"·\")").unwrap(), s("··tà"));
+    assert_eq!(eval(&mut ctx, "(string-pad-right \"🐳\" 2 \"-\")").unwrap(), s("🐳-"));
+
+    // Strings already at or over the width are returned unchanged
+    assert_eq!(eval(&mut ctx, "(string-pad-left \"abcdef\" 3 \"0\")").unwrap(), s("abcdef"));
+    assert_eq!(eval(&mut ctx, "(string-pad-right \"città\" 5)").unwrap(), s("città"));
+
+    assert!(eval(&mut ctx, "(string-pad-left \"a\" 3 \"ab\")").unwrap_err().contains("single character"));
+    assert!(eval(&mut ctx, "(string-pad-left \"a\" 3 \"\")").is_err());
+    assert!(eval(&mut ctx, "(string-pad-right \"a\" -1)").is_err());
+  }
+}
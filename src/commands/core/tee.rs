@@ -0,0 +1,84 @@
+use crate::{Command, Value};
+use crate::context::Context;
+
+/// Tee command - prints a labelled value and returns the value unchanged
+/// Meant to be inserted between pipe stages, which pass the value as the last argument
+pub struct TeeCommand;
+
+impl TeeCommand {
+    /// Formats the line printed for a value, with an optional label
+    fn format_line(label: Option<&Value>, value: &Value) -> String {
+        match label {
+            Some(label) => format!("{}: {}", label, value),
+            None => value.to_string(),
+        }
+    }
+}
+
+impl Command for TeeCommand {
+    fn execute(&self, args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        let (label, value) = match args.as_slice() {
+            [value] => (None, value),
+            [label, value] => (Some(label), value),
+            _ => return Err("tee expects a value, optionally preceded by a label".to_string()),
+        };
+
+        println!("{}", Self::format_line(label, value));
+        Ok(value.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "tee"
+    }
+
+    fn description(&self) -> &'static str {
+        "Print a value (with an optional label) and return it unchanged"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(tee [label] value)"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (pipe (sum 1 2) (tee \"after sum\") (multiply 10))  ; Prints \"after sum: 3\", returns 30\n  (tee (list 1 2))                                    ; Prints \"(1 2)\""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::PipeCommand;
+    use crate::commands::core::math::{MultiplyCommand, SumCommand};
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_tee_returns_input_unchanged() {
+        let mut registry = CommandRegistry::new();
+        registry.register(TeeCommand);
+        registry.register(PipeCommand);
+        registry.register(SumCommand);
+        registry.register(MultiplyCommand);
+        let mut ctx = Context::new(registry);
+
+        let value = Value::List(vec![Value::Int(1), Value::Str("a".to_string())]);
+        let result = ctx
+            .registry
+            .get("tee")
+            .unwrap()
+            .execute(vec![Value::Str("label".to_string()), value.clone()], &mut ctx)
+            .unwrap();
+        assert_eq!(result, value);
+
+        let result = evaluate_string("(pipe (sum 1 2) (tee \"after sum\") (multiply 10))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(30));
+
+        assert!(evaluate_string("(tee)", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_tee_line_format() {
+        let label = Value::Str("after sum".to_string());
+        assert_eq!(TeeCommand::format_line(Some(&label), &Value::Int(3)), "after sum: 3");
+        assert_eq!(TeeCommand::format_line(None, &Value::Int(3)), "3");
+    }
+}
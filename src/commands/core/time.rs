@@ -0,0 +1,127 @@
+use crate::{CommandRegistry, Value};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Time elapsed since the Unix epoch according to the system clock
+fn since_epoch() -> Result<Duration, String> {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_err(|e| format!("System clock is before the Unix epoch: {}", e))
+}
+
+/// Formats seconds since the Unix epoch as an ISO-8601 UTC timestamp
+/// (`YYYY-MM-DDTHH:MM:SSZ`)
+fn format_iso8601(secs: u64) -> String {
+  let days = (secs / 86_400) as i64;
+  let secs_of_day = secs % 86_400;
+
+  // Civil date from days since 1970-01-01 (proleptic Gregorian calendar)
+  let z = days + 719_468;
+  let era = z.div_euclid(146_097);
+  let day_of_era = z.rem_euclid(146_097);
+  let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let mp = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+  format!(
+    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+    year,
+    month,
+    day,
+    secs_of_day / 3_600,
+    (secs_of_day % 3_600) / 60,
+    secs_of_day % 60
+  )
+}
+
+/// Register time commands
+pub fn register_time_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help(
+    "now",
+    "Current Unix timestamp in seconds",
+    "(now)",
+    "  (now)                                  ; e.g. 1760486400\n  (concat \"backup-\" (to-str (now)) \".env\")  ; Unique file name",
+    |args, _ctx| {
+      if !args.is_empty() {
+        return Err("now expects no arguments".to_string());
+      }
+      Ok(Value::Int(since_epoch()?.as_secs() as i64))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "now-millis",
+    "Current Unix timestamp in milliseconds",
+    "(now-millis)",
+    "  (now-millis)        ; e.g. 1760486400123",
+    |args, _ctx| {
+      if !args.is_empty() {
+        return Err("now-millis expects no arguments".to_string());
+      }
+      Ok(Value::Int(since_epoch()?.as_millis() as i64))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "now-iso",
+    "Current UTC time as an ISO-8601 string",
+    "(now-iso)",
+    "  (now-iso)           ; e.g. \"2025-10-15T00:00:00Z\"",
+    |args, _ctx| {
+      if !args.is_empty() {
+        return Err("now-iso expects no arguments".to_string());
+      }
+      Ok(Value::Str(format_iso8601(since_epoch()?.as_secs())))
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+  use crate::evaluate_string;
+
+  #[test]
+  fn test_now_matches_test_clock() {
+    let mut registry = CommandRegistry::new();
+    register_time_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let before = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let secs = evaluate_string("(now)", &mut ctx).unwrap();
+    let millis = evaluate_string("(now-millis)", &mut ctx).unwrap();
+    let after = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+    match secs {
+      Value::Int(s) => assert!(s as u64 >= before.as_secs() && s as u64 <= after.as_secs()),
+      other => panic!("expected an integer, got {:?}", other),
+    }
+    match millis {
+      Value::Int(ms) => assert!(ms as u128 >= before.as_millis() && ms as u128 <= after.as_millis()),
+      other => panic!("expected an integer, got {:?}", other),
+    }
+
+    assert!(evaluate_string("(now 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_now_iso() {
+    let mut registry = CommandRegistry::new();
+    register_time_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    match evaluate_string("(now-iso)", &mut ctx).unwrap() {
+      Value::Str(s) => {
+        assert_eq!(s.len(), 20);
+        assert!(s.ends_with('Z') && s.as_bytes()[10] == b'T');
+      }
+      other => panic!("expected a string, got {:?}", other),
+    }
+
+    assert_eq!(format_iso8601(0), "1970-01-01T00:00:00Z");
+    assert_eq!(format_iso8601(951_782_400), "2000-02-29T00:00:00Z");
+    assert_eq!(format_iso8601(1_700_000_000), "2023-11-14T22:13:20Z");
+  }
+}
@@ -0,0 +1,182 @@
+use crate::{Command, Value, evaluate};
+use crate::context::Context;
+
+/// Variable that receives the error message when a try body fails
+pub const LAST_ERROR_VAR: &str = "last-error";
+
+/// Variable that receives the structured error when a try body fails:
+/// `(("message" text) ("command" name))`, without `command` when unknown
+pub const LAST_ERROR_INFO_VAR: &str = "last-error-info";
+
+/// Try command - evaluates a body and recovers from its errors with a catch handler
+///
+/// `(try body (catch handler))` returns the body's value when it succeeds.
+/// When the body fails, the error message is stored in `last-error`, the
+/// message and the name of the failing command in `last-error-info`, and the
+/// handler is evaluated instead; its value becomes the result. The handler
+/// is never evaluated when the body succeeds.
+pub struct TryCommand;
+
+impl TryCommand {
+    /// Returns the handler expressions if `form` is a `(catch handler...)` clause
+    fn catch_handlers(form: &lexpr::Value) -> Option<Vec<lexpr::Value>> {
+        let cons = form.as_cons()?;
+        if cons.car().as_symbol() != Some("catch") {
+            return None;
+        }
+
+        let mut handlers = Vec::new();
+        let mut current = cons.cdr();
+        while let lexpr::Value::Cons(cons) = current {
+            handlers.push(cons.car().clone());
+            current = cons.cdr();
+        }
+        Some(handlers)
+    }
+}
+
+impl Command for TryCommand {
+    fn execute(&self, _args: Vec<Value>, _ctx: &mut Context) -> Result<Value, String> {
+        Err("try needs unevaluated arguments and cannot be used as a pipe stage".to_string())
+    }
+
+    fn execute_form(&self, args: Vec<lexpr::Value>, ctx: &mut Context) -> Result<Value, String> {
+        let (body, handlers) = match args.split_last() {
+            Some((last, body)) => match Self::catch_handlers(last) {
+                Some(handlers) => (body, handlers),
+                None => (&args[..], Vec::new()),
+            },
+            None => return Err("try expects a body and an optional (catch handler) clause".to_string()),
+        };
+
+        ctx.error_command = None;
+        let mut result = Value::Nil;
+        for expression in body {
+            match evaluate(expression, ctx) {
+                Ok(value) => result = value,
+                Err(e) => {
+                    let mut info = vec![Value::List(vec![Value::Str("message".to_string()), Value::Str(e.clone())])];
+                    if let Some(command) = ctx.error_command.take() {
+                        info.push(Value::List(vec![Value::Str("command".to_string()), Value::Str(command)]));
+                    }
+                    ctx.set_variable(LAST_ERROR_INFO_VAR.to_string(), Value::List(info));
+                    ctx.set_variable(LAST_ERROR_VAR.to_string(), Value::Str(e));
+
+                    let mut handled = Value::Nil;
+                    for handler in &handlers {
+                        handled = evaluate(handler, ctx)?;
+                    }
+                    return Ok(handled);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn is_special_form(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "try"
+    }
+
+    fn description(&self) -> &'static str {
+        "Evaluate a body; on error store the message in last-error and evaluate the catch handler instead"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "(try body... (catch handler...))"
+    }
+
+    fn examples(&self) -> &'static str {
+        "  (try (docker \"build\") (catch (print \"Build failed:\" (get-var \"last-error\"))))\n  (try (sum 1 \"x\") (catch (get-var \"last-error-info\")))   ; ((message ...) (command sum))\n  (try (read-env \"missing.env\") (catch \"skipped\"))   ; Returns \"skipped\" on failure"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::core::math::SumCommand;
+    use crate::commands::core::vars::register_var_commands;
+    use crate::{CommandRegistry, evaluate_string};
+
+    #[test]
+    fn test_try_success_skips_handler() {
+        let mut registry = CommandRegistry::new();
+        registry.register(TryCommand);
+        registry.register(SumCommand);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let result = evaluate_string("(try (sum 1 2) (catch (set-var \"HANDLED\" \"yes\")))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(3));
+        assert!(!ctx.has_variable("HANDLED"));
+        assert!(!ctx.has_variable(LAST_ERROR_VAR));
+
+        // Without a catch clause the body's value is returned as well
+        assert_eq!(evaluate_string("(try (sum 2 2))", &mut ctx).unwrap(), Value::Int(4));
+    }
+
+    #[test]
+    fn test_try_catches_error() {
+        let mut registry = CommandRegistry::new();
+        registry.register(TryCommand);
+        registry.register(SumCommand);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        let result = evaluate_string("(try (sum 1 \"x\") (catch \"recovered\"))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Str("recovered".to_string()));
+
+        let last_error = ctx.get_variable(LAST_ERROR_VAR).unwrap().to_string();
+        assert!(!last_error.is_empty());
+
+        // The handler can read the error message
+        let result = evaluate_string("(try (no-such-command) (catch (get-var \"last-error\")))", &mut ctx).unwrap();
+        assert_eq!(result, Value::Str("Unknown command: no-such-command".to_string()));
+
+        // Evaluation continues after a caught error
+        let result = evaluate_string("(try (no-such-command) (catch 0)) (sum 5 5)", &mut ctx).unwrap();
+        assert_eq!(result, Value::Int(10));
+    }
+
+    #[test]
+    fn test_try_errors() {
+        let mut registry = CommandRegistry::new();
+        registry.register(TryCommand);
+        registry.register(SumCommand);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        assert!(evaluate_string("(try)", &mut ctx).is_err());
+        // Errors raised by the handler itself propagate
+        assert!(evaluate_string("(try (no-such-command) (catch (no-such-handler)))", &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_try_records_failing_command() {
+        let mut registry = CommandRegistry::new();
+        registry.register(TryCommand);
+        registry.register(SumCommand);
+        register_var_commands(&mut registry);
+        let mut ctx = Context::new(registry);
+        // The innermost failing call is reported, not the enclosing ones
+        evaluate_string("(try (sum 1 (sum 2 \"x\")) (catch 0))", &mut ctx).unwrap();
+        let info = ctx.get_variable(LAST_ERROR_INFO_VAR).unwrap();
+        let message = ctx.get_variable(LAST_ERROR_VAR).unwrap();
+        assert_eq!(
+            info,
+            Value::List(vec![
+                Value::List(vec![Value::Str("message".to_string()), message]),
+                Value::List(vec![Value::Str("command".to_string()), Value::Str("sum".to_string())]),
+            ])
+        );
+
+        let info = evaluate_string("(try (no-such-command) (catch (get-var \"last-error-info\")))", &mut ctx).unwrap();
+        assert!(info.to_string().ends_with("(command no-such-command))"), "{}", info);
+
+        // A stale name from an uncaught error does not leak into the next try
+        assert!(evaluate_string("(set-var 1 2)", &mut ctx).is_err());
+        let info = evaluate_string("(try (sum \"x\") (catch (get-var \"last-error-info\")))", &mut ctx).unwrap();
+        assert!(info.to_string().ends_with("(command sum))"), "{}", info);
+    }
+}
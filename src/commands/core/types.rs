@@ -0,0 +1,298 @@
+use crate::{CommandRegistry, Value};
+
+/// Registers a single-argument predicate on the value variant
+fn register_type_predicate(
+  registry: &mut CommandRegistry,
+  name: &'static str,
+  description: &'static str,
+  syntax: &'static str,
+  examples: &'static str,
+  predicate: fn(&Value) -> bool,
+) {
+  registry.register_closure_with_help(
+    name,
+    description,
+    syntax,
+    examples,
+    move |args, _ctx| {
+      if args.len() != 1 {
+        return Err(format!("{} expects exactly one argument", name));
+      }
+      Ok(Value::Bool(predicate(&args[0])))
+    },
+  );
+}
+
+/// Register value type-predicate commands
+pub fn register_type_commands(registry: &mut CommandRegistry) {
+  register_type_predicate(
+    registry,
+    "is-int",
+    "Check whether a value is an integer",
+    "(is-int value)",
+    "  (is-int 42)    ; Returns true\n  (is-int \"42\")  ; Returns false",
+    |v| matches!(v, Value::Int(_)),
+  );
+
+  register_type_predicate(
+    registry,
+    "is-str",
+    "Check whether a value is a string",
+    "(is-str value)",
+    "  (is-str \"abc\")         ; Returns true\n  (is-str (get-var \"n\")) ; Check a variable before using it",
+    |v| matches!(v, Value::Str(_)),
+  );
+
+  register_type_predicate(
+    registry,
+    "is-bool",
+    "Check whether a value is a boolean",
+    "(is-bool value)",
+    "  (is-bool #t)  ; Returns true\n  (is-bool 1)   ; Returns false",
+    |v| matches!(v, Value::Bool(_)),
+  );
+
+  register_type_predicate(
+    registry,
+    "is-list",
+    "Check whether a value is a list",
+    "(is-list value)",
+    "  (is-list (list 1 2))  ; Returns true\n  (is-list \"a\")         ; Returns false",
+    |v| matches!(v, Value::List(_)),
+  );
+
+  register_type_predicate(
+    registry,
+    "is-nil",
+    "Check whether a value is nil",
+    "(is-nil value)",
+    "  (is-nil (list-first (list)))  ; Returns true\n  (is-nil 0)                    ; Returns false",
+    |v| matches!(v, Value::Nil),
+  );
+}
+
+/// Register explicit type-coercion commands
+pub fn register_coercion_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help(
+    "to-int",
+    "Convert a value to an integer",
+    "(to-int value)",
+    "  (to-int \"42\")             ; Returns 42\n  (to-int (get-var \"PORT\"))  ; Use a stored string as a number\n  (to-int #t)               ; Returns 1",
+    |args, _ctx| {
+      if args.len() != 1 {
+        return Err("to-int expects exactly one argument".to_string());
+      }
+
+      match &args[0] {
+        Value::Int(i) => Ok(Value::Int(*i)),
+        Value::Str(s) => s
+          .trim()
+          .parse::<i64>()
+          .map(Value::Int)
+          .map_err(|_| format!("to-int cannot convert string '{}' to an integer", s)),
+        Value::Bool(b) => Ok(Value::Int(if *b { 1 } else { 0 })),
+        Value::List(_) => Err(format!("to-int cannot convert a list to an integer: {}", args[0])),
+        Value::Nil => Err("to-int cannot convert nil to an integer".to_string()),
+      }
+    },
+  );
+
+  registry.register_closure_with_help(
+    "parse-int",
+    "Parse a string as an integer in the given base (2 to 36, default 10)",
+    "(parse-int string [radix])",
+    "  (parse-int \"ff\" 16)      ; Returns 255\n  (parse-int \"-101\" 2)    ; Returns -5\n  (parse-int \"0755\" 8)    ; Returns 493",
+    |args, _ctx| {
+      if args.is_empty() || args.len() > 2 {
+        return Err("parse-int expects a string and an optional radix".to_string());
+      }
+
+      let text = match &args[0] {
+        Value::Str(s) => s.trim(),
+        other => return Err(format!("parse-int expects a string, got {}", other)),
+      };
+      let radix = match args.get(1) {
+        Some(Value::Int(r)) if (2..=36).contains(r) => *r as u32,
+        Some(other) => return Err(format!("parse-int radix must be an integer between 2 and 36, got {}", other)),
+        None => 10,
+      };
+
+      i64::from_str_radix(text, radix)
+        .map(Value::Int)
+        .map_err(|e| format!("parse-int cannot parse '{}' in base {}: {}", text, radix, e))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "to-str",
+    "Convert a value to its string representation",
+    "(to-str value)",
+    "  (to-str 42)          ; Returns \"42\"\n  (to-str (list 1 2))  ; Returns \"(1 2)\"",
+    |args, _ctx| {
+      if args.len() != 1 {
+        return Err("to-str expects exactly one argument".to_string());
+      }
+      Ok(Value::Str(args[0].to_string()))
+    },
+  );
+
+  registry.register_closure_with_help(
+    "to-bool",
+    "Convert a value to a boolean; the strings \"true\" and \"false\" are parsed, anything else uses truthiness",
+    "(to-bool value)",
+    "  (to-bool \"false\")  ; Returns false\n  (to-bool 0)        ; Returns false\n  (to-bool \"yes\")    ; Returns true",
+    |args, _ctx| {
+      if args.len() != 1 {
+        return Err("to-bool expects exactly one argument".to_string());
+      }
+
+      match &args[0] {
+        Value::Str(s) => match s.to_lowercase().as_str() {
+          "true" => Ok(Value::Bool(true)),
+          "false" => Ok(Value::Bool(false)),
+          _ => Ok(Value::Bool(args[0].is_truthy())),
+        },
+        value => Ok(Value::Bool(value.is_truthy())),
+      }
+    },
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+
+  fn check(ctx: &mut Context, name: &str, value: Value) -> Value {
+    ctx.registry.get(name).unwrap().execute(vec![value], ctx).unwrap()
+  }
+
+  #[test]
+  fn test_is_int() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "is-int", Value::Int(1)), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "is-int", Value::Str("1".to_string())), Value::Bool(false));
+  }
+
+  #[test]
+  fn test_is_str() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "is-str", Value::Str("a".to_string())), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "is-str", Value::Nil), Value::Bool(false));
+  }
+
+  #[test]
+  fn test_is_bool() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "is-bool", Value::Bool(false)), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "is-bool", Value::Int(0)), Value::Bool(false));
+  }
+
+  #[test]
+  fn test_is_list() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "is-list", Value::List(vec![])), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "is-list", Value::Str("()".to_string())), Value::Bool(false));
+  }
+
+  #[test]
+  fn test_is_nil() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "is-nil", Value::Nil), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "is-nil", Value::Int(0)), Value::Bool(false));
+
+    let result = ctx.registry.get("is-nil").unwrap().execute(vec![], &mut ctx);
+    assert_eq!(result.unwrap_err(), "is-nil expects exactly one argument");
+  }
+
+  #[test]
+  fn test_to_int() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "to-int", Value::Str("42".to_string())), Value::Int(42));
+    assert_eq!(check(&mut ctx, "to-int", Value::Str(" -7 ".to_string())), Value::Int(-7));
+    assert_eq!(check(&mut ctx, "to-int", Value::Bool(true)), Value::Int(1));
+    assert_eq!(check(&mut ctx, "to-int", Value::Int(5)), Value::Int(5));
+
+    let cmd = ctx.registry.get("to-int").unwrap();
+    assert_eq!(
+      cmd.execute(vec![Value::Str("12abc".to_string())], &mut ctx).unwrap_err(),
+      "to-int cannot convert string '12abc' to an integer"
+    );
+    assert!(cmd.execute(vec![Value::Str(String::new())], &mut ctx).is_err());
+    assert!(cmd.execute(vec![Value::Str("99999999999999999999".to_string())], &mut ctx).is_err());
+    assert!(cmd.execute(vec![Value::Nil], &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_parse_int() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let cmd = ctx.registry.get("parse-int").unwrap();
+    let parse = |ctx: &mut Context, text: &str, radix: Option<i64>| {
+      let mut args = vec![Value::Str(text.to_string())];
+      args.extend(radix.map(Value::Int));
+      cmd.execute(args, ctx)
+    };
+
+    assert_eq!(parse(&mut ctx, "ff", Some(16)).unwrap(), Value::Int(255));
+    assert_eq!(parse(&mut ctx, "-7FFF", Some(16)).unwrap(), Value::Int(-32767));
+    assert_eq!(parse(&mut ctx, "1011", Some(2)).unwrap(), Value::Int(11));
+    assert_eq!(parse(&mut ctx, "-101", Some(2)).unwrap(), Value::Int(-5));
+    assert_eq!(parse(&mut ctx, "0755", Some(8)).unwrap(), Value::Int(493));
+    assert_eq!(parse(&mut ctx, " 42 ", None).unwrap(), Value::Int(42));
+    assert_eq!(parse(&mut ctx, "z", Some(36)).unwrap(), Value::Int(35));
+
+    let err = parse(&mut ctx, "102", Some(2)).unwrap_err();
+    assert!(err.starts_with("parse-int cannot parse '102' in base 2"), "{}", err);
+    assert!(parse(&mut ctx, "ff", None).is_err());
+    assert!(parse(&mut ctx, "1", Some(1)).is_err());
+    assert!(parse(&mut ctx, "1", Some(37)).is_err());
+  }
+
+  #[test]
+  fn test_to_str() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "to-str", Value::Int(42)), Value::Str("42".to_string()));
+    assert_eq!(check(&mut ctx, "to-str", Value::Bool(false)), Value::Str("false".to_string()));
+    assert_eq!(
+      check(&mut ctx, "to-str", Value::List(vec![Value::Int(1), Value::Int(2)])),
+      Value::Str("(1 2)".to_string())
+    );
+  }
+
+  #[test]
+  fn test_to_bool() {
+    let mut registry = CommandRegistry::new();
+    register_type_commands(&mut registry);
+    register_coercion_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(check(&mut ctx, "to-bool", Value::Str("false".to_string())), Value::Bool(false));
+    assert_eq!(check(&mut ctx, "to-bool", Value::Str("TRUE".to_string())), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "to-bool", Value::Str("no".to_string())), Value::Bool(true));
+    assert_eq!(check(&mut ctx, "to-bool", Value::Int(0)), Value::Bool(false));
+    assert_eq!(check(&mut ctx, "to-bool", Value::Nil), Value::Bool(false));
+  }
+}
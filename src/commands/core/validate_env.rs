@@ -0,0 +1,159 @@
+use crate::context::LogLevel;
+use crate::file_ops::strip_export_prefix;
+use crate::utils::debug_log;
+use crate::{CommandRegistry, Context, Value, tags};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+
+/// Register the validate-env command
+pub fn register_validate_env_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "validate-env",
+    "Check an env file for duplicate keys, malformed lines and unresolved ${} references",
+    "(validate-env path)",
+    "  (validate-env \".env\")            ; Returns () when the file is valid\n  (validate-env \"generated.env\")   ; Returns (\"line 3: missing '='\" ...) otherwise",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "validate-env", "executing validate-env command");
+
+      if args.len() != 1 {
+        return Err("validate-env expects exactly one argument (file path)".to_string());
+      }
+
+      let path = match &args[0] {
+        Value::Str(s) => s,
+        _ => return Err("validate-env file path must be a string".to_string()),
+      };
+
+      let file_path = ctx.get_basedir().join(path);
+      let contents = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read file {}: {}", file_path.display(), e))?;
+
+      let problems = validate_env_contents(&contents, ctx);
+      debug_log(
+        ctx,
+        LogLevel::Info,
+        "validate-env",
+        &format!("{}: {} problems found", file_path.display(), problems.len()),
+      );
+
+      Ok(Value::List(problems.into_iter().map(Value::Str).collect()))
+    },
+  );
+}
+
+/// Lints the contents of an env file without modifying the context
+///
+/// References are considered resolved when they name a context variable, a
+/// process environment variable, a key defined earlier in the file, or when
+/// they carry a `:-default`.
+///
+/// # Returns
+/// * `Vec<String>` - One message per problem, prefixed with its 1-based line number
+fn validate_env_contents(contents: &str, ctx: &Context) -> Vec<String> {
+  let reference_regex = Regex::new(r"\$\{([^}]+?)(?::-([^}]*))?\}").unwrap();
+  let mut problems = Vec::new();
+  let mut defined: HashMap<String, usize> = HashMap::new();
+
+  for (index, line) in contents.lines().enumerate() {
+    let line_num = index + 1;
+    let trimmed = line.trim();
+
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+      continue;
+    }
+
+    let Some((key, value)) = strip_export_prefix(trimmed).split_once('=') else {
+      problems.push(format!("line {}: missing '='", line_num));
+      continue;
+    };
+
+    let key = key.trim();
+    if key.is_empty() {
+      problems.push(format!("line {}: empty key", line_num));
+      continue;
+    }
+
+    for cap in reference_regex.captures_iter(value) {
+      let name = &cap[1];
+      let resolved = cap.get(2).is_some()
+        || defined.contains_key(name)
+        || ctx.has_variable(name)
+        || std::env::var(name).is_ok();
+      if !resolved {
+        problems.push(format!("line {}: unresolved reference ${{{}}}", line_num, name));
+      }
+    }
+
+    if let Some(first) = defined.get(key) {
+      problems.push(format!(
+        "line {}: duplicate key '{}' (first defined on line {})",
+        line_num, key, first
+      ));
+    } else {
+      defined.insert(key.to_string(), line_num);
+    }
+  }
+
+  problems
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::path::PathBuf;
+
+  #[test]
+  fn test_validate_env_valid_file() {
+    let mut registry = CommandRegistry::new();
+    register_validate_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("PROJECT".to_string(), Value::Str("dpm".to_string()));
+
+    let contents = "# comment\n\nexport HOST=localhost\nURL=http://${HOST}:${PORT:-80}/${PROJECT}\n";
+    assert!(validate_env_contents(contents, &ctx).is_empty());
+  }
+
+  #[test]
+  fn test_validate_env_reports_problems() {
+    let mut registry = CommandRegistry::new();
+    register_validate_env_command(&mut registry);
+    let ctx = Context::new(registry);
+    let contents = "A=1\nnot an assignment\n=orphan\nA=2\nB=${DPM_TEST_UNDEFINED_REF}\n";
+
+    assert_eq!(
+      validate_env_contents(contents, &ctx),
+      vec![
+        "line 2: missing '='".to_string(),
+        "line 3: empty key".to_string(),
+        "line 4: duplicate key 'A' (first defined on line 1)".to_string(),
+        "line 5: unresolved reference ${DPM_TEST_UNDEFINED_REF}".to_string(),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_validate_env_command_reads_from_basedir() {
+    let mut registry = CommandRegistry::new();
+    register_validate_env_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    let dir = PathBuf::from("target").join("test_validate_env");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("dup.env"), "KEY=a\nKEY=b\n").unwrap();
+    ctx.set_basedir(dir.clone());
+
+    let args = vec![Value::Str("dup.env".to_string())];
+    let result = ctx.registry.get("validate-env").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(
+      result,
+      Value::List(vec![Value::Str("line 2: duplicate key 'KEY' (first defined on line 1)".to_string())])
+    );
+
+    let args = vec![Value::Str("missing.env".to_string())];
+    assert!(ctx.registry.get("validate-env").unwrap().execute(args, &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
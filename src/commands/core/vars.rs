@@ -1,4 +1,6 @@
 use crate::commands::core::read_env::interpolate_variables;
+use crate::context::LogLevel;
+use crate::env_ops::missing_required_vars;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Value, tags};
 
@@ -6,15 +8,15 @@ use crate::{CommandRegistry, Value, tags};
 pub fn register_get_var_command(registry: &mut CommandRegistry) {
   registry.register_closure_with_help_and_tag(
     "get-var",
-    "Get a variable from the context with the given key",
-    "(get-var key)",
-    "  (get-var \"name\")        ; Get variable 'name'\n  (get-var \"count\")       ; Get variable 'count'\n  (get-var \"path\")        ; Get variable 'path'",
+    "Get a variable from the context with the given key, or a default when it is missing",
+    "(get-var key [default])",
+    "  (get-var \"name\")              ; Get variable 'name', error if missing\n  (get-var \"count\")             ; Get variable 'count'\n  (get-var \"ENV\" \"dev\")         ; Get variable 'ENV', \"dev\" if missing",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "get-var", "executing get-var command");
+      debug_log(ctx, LogLevel::Debug, "get-var", "executing get-var command");
 
-      if args.len() != 1 {
-        return Err("get-var expects exactly one argument (key)".to_string());
+      if args.is_empty() || args.len() > 2 {
+        return Err("get-var expects one or two arguments (key, [default])".to_string());
       }
 
       let key = match &args[0] {
@@ -22,17 +24,21 @@ pub fn register_get_var_command(registry: &mut CommandRegistry) {
         _ => return Err("get-var key must be a string".to_string()),
       };
 
-      debug_log(ctx, "get-var", &format!("getting variable: {}", key));
+      debug_log(ctx, LogLevel::Debug, "get-var", &format!("getting variable: {}", key));
 
       // Get the variable from the context
       match ctx.get_variable(&key) {
         Some(value) => {
-          debug_log(ctx, "get-var", &format!("found variable: {} = {}", key, value));
+          debug_log(ctx, LogLevel::Debug, "get-var", &format!("found variable: {} = {}", key, ctx.display_value(&key, &value)));
           Ok(value.clone())
         },
+        None if args.len() == 2 => {
+          debug_log(ctx, LogLevel::Debug, "get-var", &format!("variable {} not found, using default", key));
+          Ok(args[1].clone())
+        },
         None => {
           let error_msg = format!("Variable '{}' not found", key);
-          debug_log(ctx, "get-var", &error_msg);
+          debug_log(ctx, LogLevel::Error, "get-var", &error_msg);
           Err(error_msg)
         }
       }
@@ -44,85 +50,271 @@ pub fn register_get_var_command(registry: &mut CommandRegistry) {
 pub fn register_set_var_command(registry: &mut CommandRegistry) {
   registry.register_closure_with_help_and_tag(
     "set-var",
-    "Set a variable in the context with the given key and value",
+    "Set a variable in the context with the given key and value; string values are interpolated",
     "(set-var key value)",
-    "  (set-var \"name\" \"John\")        ; Set variable 'name' to 'John'\n  (set-var \"count\" \"42\")         ; Set variable 'count' to '42'\n  (set-var \"path\" \"/home/user\")   ; Set variable 'path' to '/home/user'",
+    "  (set-var \"name\" \"John\")        ; Set variable 'name' to 'John'\n  (set-var \"total\" (sum 1 2))    ; Store the integer 3\n  (set-var \"count\" \"42\")         ; Set variable 'count' to '42'\n  (set-var \"path\" \"/home/user\")   ; Set variable 'path' to '/home/user'",
     &tags::COMMANDS,
     |args, ctx| {
-      debug_log(ctx, "set-var", "executing set-var command");
-      debug_log(ctx, "set-var", &format!("received {} arguments", args.len()));
+      debug_log(ctx, LogLevel::Debug, "set-var", "executing set-var command");
+      debug_log(ctx, LogLevel::Debug, "set-var", &format!("received {} arguments", args.len()));
 
       // Validate argument count
       if args.len() != 2 {
         let error_msg = "set-var expects exactly two arguments (key, value)".to_string();
-        debug_log(ctx, "set-var", &format!("argument validation failed: {}", error_msg));
+        debug_log(ctx, LogLevel::Error, "set-var", &format!("argument validation failed: {}", error_msg));
         return Err(error_msg);
       }
-      debug_log(ctx, "set-var", "argument count validation passed");
+      debug_log(ctx, LogLevel::Debug, "set-var", "argument count validation passed");
 
       // Validate and extract key
-      debug_log(ctx, "set-var", "validating key argument");
+      debug_log(ctx, LogLevel::Debug, "set-var", "validating key argument");
       let key = match &args[0] {
         Value::Str(s) => {
-          debug_log(ctx, "set-var", &format!("key validation passed: '{}'", s));
+          debug_log(ctx, LogLevel::Debug, "set-var", &format!("key validation passed: '{}'", s));
           s.clone()
         },
         other => {
           let error_msg = "set-var key must be a string".to_string();
-          debug_log(ctx, "set-var", &format!("key validation failed: expected string, got {:?}", other));
+          debug_log(ctx, LogLevel::Error, "set-var", &format!("key validation failed: expected string, got {:?}", other));
           return Err(error_msg);
         },
       };
 
-      // Validate and extract value
-      debug_log(ctx, "set-var", "validating value argument");
+      // Only string values are interpolated, others are stored as they are
       let value = match &args[1] {
         Value::Str(s) => {
-          debug_log(ctx, "set-var", &format!("value validation passed: '{}'", s));
-          s.clone()
-        },
-        other => {
-          let error_msg = "set-var value must be a string".to_string();
-          debug_log(ctx, "set-var", &format!("value validation failed: expected string, got {:?}", other));
-          return Err(error_msg);
+          debug_log(ctx, LogLevel::Debug, "set-var", &format!("setting variable: {} = {}", key, ctx.display_value(&key, s)));
+
+          // Interpolate variables in the value
+          debug_log(ctx, LogLevel::Debug, "set-var", "starting variable interpolation");
+          match interpolate_variables(s, ctx) {
+            Ok(val) => {
+              debug_log(ctx, LogLevel::Debug, "set-var", "variable interpolation successful");
+              Value::Str(val)
+            },
+            Err(e) => {
+              let error_msg = format!("Error interpolating variable '{}': {}", key, e);
+              debug_log(ctx, LogLevel::Error, "set-var", &format!("variable interpolation failed: {}", e));
+              return Err(error_msg);
+            },
+          }
         },
+        other => other.clone(),
       };
 
-      debug_log(ctx, "set-var", &format!("setting variable: {} = {}", key, value));
+      debug_log(ctx, LogLevel::Debug, "set-var", &format!("value to store: {} = {}", key, ctx.display_value(&key, &value)));
 
-      // Interpolate variables in the value
-      debug_log(ctx, "set-var", "starting variable interpolation");
-      let interpolated_value = match interpolate_variables(&value, ctx) {
-        Ok(val) => {
-          debug_log(ctx, "set-var", "variable interpolation successful");
-          val
-        },
-        Err(e) => {
-          let error_msg = format!("Error interpolating variable '{}': {}", key, e);
-          debug_log(ctx, "set-var", &format!("variable interpolation failed: {}", e));
-          return Err(error_msg);
-        },
+      // Store the variable in the context
+      debug_log(ctx, LogLevel::Debug, "set-var", "storing variable in context");
+      let result_msg = format!("Variable '{}' set to '{}'", key, ctx.display_value(&key, &value));
+      ctx.set_variable(key, value);
+      debug_log(ctx, LogLevel::Info, "set-var", "variable successfully stored in context");
+
+      debug_log(ctx, LogLevel::Info, "set-var", &format!("completed: {}", result_msg));
+
+      Ok(Value::Str(result_msg))
+    },
+  );
+}
+
+/// Register unset-var command
+pub fn register_unset_var_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "unset-var",
+    "Remove a variable from the context, returning true if it was set",
+    "(unset-var key)",
+    "  (unset-var \"name\")      ; Remove variable 'name'\n  (unset-var \"missing\")   ; Returns false, no error",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "unset-var", "executing unset-var command");
+
+      if args.len() != 1 {
+        return Err("unset-var expects exactly one argument (key)".to_string());
+      }
+
+      let key = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("unset-var key must be a string".to_string()),
       };
 
-      debug_log(ctx, "set-var", &format!("interpolated value: {} = {}", key, interpolated_value));
+      let removed = ctx.remove_variable(&key).is_some();
+      debug_log(ctx, LogLevel::Debug, "unset-var", &format!("variable '{}' removed: {}", key, removed));
 
-      // Store the variable in the context
-      debug_log(ctx, "set-var", "storing variable in context");
-      ctx.set_variable(key.clone(), Value::Str(interpolated_value.clone()));
-      debug_log(ctx, "set-var", "variable successfully stored in context");
+      Ok(Value::Bool(removed))
+    },
+  );
+}
 
-      let result_msg = format!("Variable '{}' set to '{}'", key, interpolated_value);
-      debug_log(ctx, "set-var", &format!("completed: {}", result_msg));
+/// Register has-var command
+pub fn register_has_var_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "has-var",
+    "Check whether a variable is set in the context",
+    "(has-var key)",
+    "  (has-var \"name\")          ; Returns true if 'name' is set\n  (has-var \"CTX:basedir\")   ; Context attributes are supported too",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "has-var", "executing has-var command");
 
-      Ok(Value::Str(result_msg))
+      if args.len() != 1 {
+        return Err("has-var expects exactly one argument (key)".to_string());
+      }
+
+      let key = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("has-var key must be a string".to_string()),
+      };
+
+      let present = ctx.has_variable(&key);
+      debug_log(ctx, LogLevel::Debug, "has-var", &format!("variable '{}' present: {}", key, present));
+
+      Ok(Value::Bool(present))
     },
   );
 }
 
-/// Register both variable commands
+/// Register list-vars command
+pub fn register_list_vars_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "list-vars",
+    "List all context variables as (key value) pairs sorted by key",
+    "(list-vars)",
+    "  (list-vars)              ; Returns ((\"a\" \"1\") (\"b\" \"2\") ...)\n  (print (list-vars))      ; Inspect variables before write-env",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "list-vars", "executing list-vars command");
+
+      if !args.is_empty() {
+        return Err("list-vars expects no arguments".to_string());
+      }
+
+      let mut keys: Vec<&String> = ctx.variables.keys().collect();
+      keys.sort();
+
+      let pairs: Vec<Value> = keys
+        .into_iter()
+        .map(|key| {
+          Value::List(vec![Value::Str(key.clone()), ctx.variables[key].clone()])
+        })
+        .collect();
+
+      debug_log(ctx, LogLevel::Debug, "list-vars", &format!("listing {} variables", pairs.len()));
+      Ok(Value::List(pairs))
+    },
+  );
+}
+
+/// Register require-vars command
+pub fn register_require_vars_command(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "require-vars",
+    "Fail unless all given variables are set and non-empty; with \"with-env\" first, process environment variables also count",
+    "(require-vars [\"with-env\"] key1 key2 ...)",
+    "  (require-vars \"PROJECT_NAME\" \"HOST_UID\")        ; Error listing every missing variable\n  (require-vars \"with-env\" \"HOME\" \"PROJECT_NAME\")  ; Accept variables from the process environment",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "require-vars", "executing require-vars command");
+
+      let mut names = Vec::new();
+      for arg in &args {
+        match arg {
+          Value::Str(s) => names.push(s.clone()),
+          _ => return Err("require-vars keys must be strings".to_string()),
+        }
+      }
+
+      let use_process_env = names.first().map(|n| n == "with-env").unwrap_or(false);
+      if use_process_env {
+        names.remove(0);
+      }
+
+      if names.is_empty() {
+        return Err("require-vars expects at least one variable name".to_string());
+      }
+
+      let missing = missing_required_vars(names.iter().map(String::as_str), |name| {
+        let in_context = match ctx.variables.get(name) {
+          Some(Value::Str(s)) => !s.is_empty(),
+          Some(Value::Nil) | None => false,
+          Some(_) => true,
+        };
+        let in_env = use_process_env
+          && std::env::var(name).map(|v| !v.is_empty()).unwrap_or(false);
+        in_context || in_env
+      });
+
+      if missing.is_empty() {
+        debug_log(ctx, LogLevel::Debug, "require-vars", "all required variables are set");
+        Ok(Value::Nil)
+      } else {
+        let error_msg = format!("Missing required variables: {}", missing.join(", "));
+        debug_log(ctx, LogLevel::Error, "require-vars", &error_msg);
+        Err(error_msg)
+      }
+    },
+  );
+}
+
+/// Register all variable commands
 pub fn register_var_commands(registry: &mut CommandRegistry) {
   register_get_var_command(registry);
   register_set_var_command(registry);
+  register_unset_var_command(registry);
+  register_has_var_command(registry);
+  register_list_vars_command(registry);
+  register_require_vars_command(registry);
+  register_env_snapshot_commands(registry);
+}
+
+/// Register env-snapshot and env-restore commands
+pub fn register_env_snapshot_commands(registry: &mut CommandRegistry) {
+  registry.register_closure_with_help_and_tag(
+    "env-snapshot",
+    "Save a copy of all context variables and return its snapshot id",
+    "(env-snapshot)",
+    "  (set-var \"id\" (env-snapshot))   ; Save the variables before a risky section",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "env-snapshot", "executing env-snapshot command");
+
+      if !args.is_empty() {
+        return Err("env-snapshot expects no arguments".to_string());
+      }
+
+      ctx.snapshots.push(ctx.variables.clone());
+      let id = ctx.snapshots.len() - 1;
+      debug_log(ctx, LogLevel::Info, "env-snapshot", &format!("saved {} variables as snapshot {}", ctx.variables.len(), id));
+
+      Ok(Value::Int(id as i64))
+    },
+  );
+
+  registry.register_closure_with_help_and_tag(
+    "env-restore",
+    "Replace all context variables with a snapshot taken by env-snapshot",
+    "(env-restore id)",
+    "  (env-restore (get-var \"id\"))   ; Undo every variable change since the snapshot",
+    &tags::COMMANDS,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "env-restore", "executing env-restore command");
+
+      let id = match args.as_slice() {
+        [Value::Int(id)] => *id,
+        [_] => return Err("env-restore snapshot id must be an integer".to_string()),
+        _ => return Err("env-restore expects exactly one argument (snapshot id)".to_string()),
+      };
+
+      // Snapshots stay available: the same id can be restored again
+      let snapshot = usize::try_from(id)
+        .ok()
+        .and_then(|index| ctx.snapshots.get(index))
+        .ok_or_else(|| format!("Unknown snapshot id: {}", id))?;
+      ctx.variables = snapshot.clone();
+      debug_log(ctx, LogLevel::Info, "env-restore", &format!("restored snapshot {} ({} variables)", id, ctx.variables.len()));
+
+      Ok(Value::Bool(true))
+    },
+  );
 }
 
 #[cfg(test)]
@@ -180,15 +372,40 @@ mod tests {
     // Test with wrong number of arguments
     let args = vec![
       Value::Str("key1".to_string()),
-      Value::Str("key2".to_string()),
+      Value::Str("default".to_string()),
+      Value::Str("extra".to_string()),
     ];
     let result = ctx.registry.get("get-var").unwrap().execute(args, &mut ctx);
 
     assert!(result.is_err());
     assert_eq!(
       result.unwrap_err(),
-      "get-var expects exactly one argument (key)"
+      "get-var expects one or two arguments (key, [default])"
     );
+    assert!(ctx.registry.get("get-var").unwrap().execute(vec![], &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_get_var_default() {
+    let mut registry = CommandRegistry::new();
+    register_get_var_command(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("ENV".to_string(), Value::Str("prod".to_string()));
+
+    // Present key: the default is ignored
+    let args = vec![Value::Str("ENV".to_string()), Value::Str("dev".to_string())];
+    let result = ctx.registry.get("get-var").unwrap().execute(args, &mut ctx);
+    assert_eq!(result, Ok(Value::Str("prod".to_string())));
+
+    // Missing key with default, of any type
+    let args = vec![Value::Str("PORT".to_string()), Value::Int(8080)];
+    let result = ctx.registry.get("get-var").unwrap().execute(args, &mut ctx);
+    assert_eq!(result, Ok(Value::Int(8080)));
+
+    // Missing key without default still errors
+    let args = vec![Value::Str("PORT".to_string())];
+    let result = ctx.registry.get("get-var").unwrap().execute(args, &mut ctx);
+    assert_eq!(result, Err("Variable 'PORT' not found".to_string()));
   }
 
   #[test]
@@ -307,13 +524,28 @@ mod tests {
 
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), "set-var key must be a string");
+  }
 
-    // Test with non-string value
-    let args = vec![Value::Str("key".to_string()), Value::Int(456)];
-    let result = ctx.registry.get("set-var").unwrap().execute(args, &mut ctx);
+  #[test]
+  fn test_set_var_keeps_value_types() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
 
-    assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "set-var value must be a string");
+    let list = Value::List(vec![Value::Str("${A}".to_string()), Value::Int(2)]);
+    for (key, value) in [("n", Value::Int(3)), ("flag", Value::Bool(true)), ("items", list.clone())] {
+      let args = vec![Value::Str(key.to_string()), value];
+      ctx.registry.get("set-var").unwrap().execute(args, &mut ctx).unwrap();
+    }
+
+    let get = |ctx: &mut Context, key: &str| {
+      let args = vec![Value::Str(key.to_string())];
+      ctx.registry.get("get-var").unwrap().execute(args, ctx).unwrap()
+    };
+    assert_eq!(get(&mut ctx, "n"), Value::Int(3));
+    assert_eq!(get(&mut ctx, "flag"), Value::Bool(true));
+    // Strings inside lists are stored verbatim, not interpolated
+    assert_eq!(get(&mut ctx, "items"), list);
   }
 
   // Test for combined registration
@@ -349,4 +581,197 @@ mod tests {
 
     assert_eq!(result, Value::Str("test_value".to_string()));
   }
+
+  // Tests for unset-var and has-var commands
+  #[test]
+  fn test_context_remove_and_has_variable() {
+    let mut ctx = Context::new(CommandRegistry::new());
+
+    assert!(!ctx.has_variable("key"));
+    ctx.set_variable("key".to_string(), Value::Int(1));
+    assert!(ctx.has_variable("key"));
+    assert!(ctx.has_variable("CTX:basedir"));
+    assert!(!ctx.has_variable("CTX:unknown"));
+
+    assert_eq!(ctx.remove_variable("key"), Some(Value::Int(1)));
+    assert!(!ctx.has_variable("key"));
+    assert_eq!(ctx.remove_variable("key"), None);
+  }
+
+  #[test]
+  fn test_unset_var_command() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    ctx.set_variable(
+      "test_key".to_string(),
+      Value::Str("test_value".to_string()),
+    );
+
+    let args = vec![Value::Str("test_key".to_string())];
+    let result = ctx
+      .registry
+      .get("unset-var")
+      .unwrap()
+      .execute(args.clone(), &mut ctx)
+      .unwrap();
+    assert_eq!(result, Value::Bool(true));
+    assert_eq!(ctx.get_variable("test_key"), None);
+
+    // Removing again is not an error
+    let result = ctx
+      .registry
+      .get("unset-var")
+      .unwrap()
+      .execute(args, &mut ctx)
+      .unwrap();
+    assert_eq!(result, Value::Bool(false));
+
+    let result = ctx
+      .registry
+      .get("unset-var")
+      .unwrap()
+      .execute(vec![Value::Int(1)], &mut ctx);
+    assert_eq!(result.unwrap_err(), "unset-var key must be a string");
+  }
+
+  #[test]
+  fn test_has_var_command() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![Value::Str("test_key".to_string())];
+    let result = ctx
+      .registry
+      .get("has-var")
+      .unwrap()
+      .execute(args.clone(), &mut ctx)
+      .unwrap();
+    assert_eq!(result, Value::Bool(false));
+
+    ctx.set_variable("test_key".to_string(), Value::Int(7));
+    let result = ctx
+      .registry
+      .get("has-var")
+      .unwrap()
+      .execute(args, &mut ctx)
+      .unwrap();
+    assert_eq!(result, Value::Bool(true));
+
+    let result = ctx.registry.get("has-var").unwrap().execute(vec![], &mut ctx);
+    assert_eq!(
+      result.unwrap_err(),
+      "has-var expects exactly one argument (key)"
+    );
+  }
+
+  #[test]
+  fn test_list_vars_command() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = ctx
+      .registry
+      .get("list-vars")
+      .unwrap()
+      .execute(vec![], &mut ctx)
+      .unwrap();
+    assert_eq!(result, Value::List(vec![]));
+
+    ctx.set_variable("b_key".to_string(), Value::Int(2));
+    ctx.set_variable("a_key".to_string(), Value::Str("one".to_string()));
+
+    let result = ctx
+      .registry
+      .get("list-vars")
+      .unwrap()
+      .execute(vec![], &mut ctx)
+      .unwrap();
+    assert_eq!(
+      result,
+      Value::List(vec![
+        Value::List(vec![
+          Value::Str("a_key".to_string()),
+          Value::Str("one".to_string())
+        ]),
+        Value::List(vec![Value::Str("b_key".to_string()), Value::Int(2)]),
+      ])
+    );
+
+    // Listing does not mutate the context
+    assert_eq!(ctx.variables.len(), 2);
+  }
+
+  #[test]
+  fn test_require_vars_all_present() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("PROJECT_NAME".to_string(), Value::Str("demo".to_string()));
+    ctx.set_variable("HOST_UID".to_string(), Value::Int(1000));
+
+    let args = vec![
+      Value::Str("PROJECT_NAME".to_string()),
+      Value::Str("HOST_UID".to_string()),
+    ];
+    let result = ctx.registry.get("require-vars").unwrap().execute(args, &mut ctx);
+    assert_eq!(result, Ok(Value::Nil));
+
+    // Process environment counts only with "with-env"
+    let args = vec![Value::Str("PATH".to_string())];
+    assert!(ctx.registry.get("require-vars").unwrap().execute(args, &mut ctx).is_err());
+    let args = vec![Value::Str("with-env".to_string()), Value::Str("PATH".to_string())];
+    let result = ctx.registry.get("require-vars").unwrap().execute(args, &mut ctx);
+    assert_eq!(result, Ok(Value::Nil));
+  }
+
+  #[test]
+  fn test_require_vars_reports_all_missing() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("PROJECT_NAME".to_string(), Value::Str("demo".to_string()));
+    ctx.set_variable("EMPTY".to_string(), Value::Str(String::new()));
+
+    let args = vec![
+      Value::Str("MISSING_A".to_string()),
+      Value::Str("PROJECT_NAME".to_string()),
+      Value::Str("EMPTY".to_string()),
+      Value::Str("MISSING_B".to_string()),
+    ];
+    let result = ctx.registry.get("require-vars").unwrap().execute(args, &mut ctx);
+    assert_eq!(
+      result.unwrap_err(),
+      "Missing required variables: MISSING_A, EMPTY, MISSING_B"
+    );
+
+    let result = ctx.registry.get("require-vars").unwrap().execute(vec![], &mut ctx);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_env_snapshot_and_restore() {
+    let mut registry = CommandRegistry::new();
+    register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    ctx.set_variable("KEEP".to_string(), Value::Str("before".to_string()));
+
+    let id = crate::evaluate_string("(env-snapshot)", &mut ctx).unwrap();
+    assert_eq!(id, Value::Int(0));
+
+    crate::evaluate_string("(set-var \"KEEP\" \"after\") (set-var \"NEW\" \"x\")", &mut ctx).unwrap();
+    assert!(ctx.has_variable("NEW"));
+
+    let result = crate::evaluate_string("(env-restore 0)", &mut ctx).unwrap();
+    assert_eq!(result, Value::Bool(true));
+    assert_eq!(ctx.get_variable("KEEP"), Some(Value::Str("before".to_string())));
+    assert!(!ctx.has_variable("NEW"));
+
+    assert_eq!(crate::evaluate_string("(env-restore 1)", &mut ctx), Err("Unknown snapshot id: 1".to_string()));
+    assert!(crate::evaluate_string("(env-restore -1)", &mut ctx).is_err());
+    assert!(crate::evaluate_string("(env-restore)", &mut ctx).is_err());
+  }
 }
@@ -2,14 +2,26 @@ pub mod rust;
 pub mod app;
 pub mod core;
 
-pub use core::PrintCommand;
-pub use core::SumCommand;
+pub use core::{PpCommand, PrintCommand, PrintRawCommand};
+pub use core::TeeCommand;
+pub use core::{RetryCommand, TryCommand};
+pub use core::{ApplyCommand, EvalCommand, QuoteCommand};
 pub use core::PipeCommand;
 pub use core::register_list_commands;
-pub use core::register_help_commands;
-pub use core::MultiplyCommand;
+pub use core::register_time_commands;
+pub use core::register_random_commands;
+pub use core::register_string_commands;
+pub use core::register_hash_commands;
+pub use core::{register_coercion_commands, register_type_commands};
+pub use core::{format_help_json, format_help_long, format_help_short, register_help_commands};
+pub use core::register_alias_commands;
+pub use core::register_limit_commands;
+pub use core::register_config_commands;
+pub use core::register_math_commands;
 pub use core::ConcatCommand;
 pub use core::register_basedir_commands;
 pub use core::register_app_commands;
 pub use core::DebugCommand;
+pub use core::{AndCommand, CaseCommand, CondCommand, NotCommand, OrCommand};
+pub use core::{AssertCommand, AssertEqCommand};
 pub use rust::register_all_rust_commands;
@@ -1,3 +1,4 @@
+use crate::context::LogLevel;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Value, tags};
 use std::env;
@@ -12,17 +13,18 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
     "  (rust-env-current-dir)  ; Returns current working directory path",
     &tags::RUST,
     |args, ctx| {
-      debug_log(ctx, "rust-env", "executing rust-env-current-dir command");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-current-dir command");
 
       if !args.is_empty() {
         return Err("rust-env-current-dir expects no arguments".to_string());
       }
 
-      debug_log(ctx, "rust-env", "getting current working directory");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "getting current working directory");
       match env::current_dir() {
         Ok(path) => {
           debug_log(
             ctx,
+            LogLevel::Debug,
             "rust-env",
             &format!("current directory retrieved: {}", path.display()),
           );
@@ -33,6 +35,46 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
     },
   );
 
+  // rust-env-set-current-dir command
+  registry.register_closure_with_help_and_tag(
+    "rust-env-set-current-dir",
+    "Change the current working directory of the dpm process (independent of basedir)",
+    "(rust-env-set-current-dir path)",
+    "  (rust-env-set-current-dir \"services/api\")  ; Later rust-process-* calls and relative paths start here\n  ; basedir is not changed: use (basedir ...) for commands that resolve paths against it",
+    &tags::RUST,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-set-current-dir command");
+
+      if args.len() != 1 {
+        return Err("rust-env-set-current-dir expects exactly one argument (directory path)".to_string());
+      }
+
+      let dir_path = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("rust-env-set-current-dir directory path must be a string".to_string()),
+      };
+
+      let path = std::path::Path::new(&dir_path);
+      if !path.exists() {
+        return Err(format!("Directory '{}' does not exist", dir_path));
+      }
+      if !path.is_dir() {
+        return Err(format!("'{}' is not a directory", dir_path));
+      }
+
+      debug_log(ctx, LogLevel::Debug, "rust-env", &format!("changing current directory to: {}", dir_path));
+      match env::set_current_dir(path) {
+        Ok(()) => {
+          let current = env::current_dir()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or(dir_path);
+          Ok(Value::Str(current))
+        }
+        Err(e) => Err(format!("Failed to change current directory to '{}': {}", dir_path, e)),
+      }
+    },
+  );
+
   // rust-env-current-exe command
   registry.register_closure_with_help_and_tag(
     "rust-env-current-exe",
@@ -41,17 +83,18 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
     "  (rust-env-current-exe)  ; Returns path to current executable",
     &tags::RUST,
     |args, ctx| {
-      debug_log(ctx, "rust-env", "executing rust-env-current-exe command");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-current-exe command");
 
       if !args.is_empty() {
         return Err("rust-env-current-exe expects no arguments".to_string());
       }
 
-      debug_log(ctx, "rust-env", "getting current executable path");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "getting current executable path");
       match env::current_exe() {
         Ok(path) => {
           debug_log(
             ctx,
+            LogLevel::Debug,
             "rust-env",
             &format!("executable path retrieved: {}", path.display()),
           );
@@ -70,17 +113,18 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
     "  (rust-env-home-dir)  ; Returns user's home directory path",
     &tags::RUST,
     |args, ctx| {
-      debug_log(ctx, "rust-env", "executing rust-env-home-dir command");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-home-dir command");
 
       if !args.is_empty() {
         return Err("rust-env-home-dir expects no arguments".to_string());
       }
 
-      debug_log(ctx, "rust-env", "getting user's home directory");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "getting user's home directory");
       match dirs::home_dir() {
         Some(path) => {
           debug_log(
             ctx,
+            LogLevel::Debug,
             "rust-env",
             &format!("home directory retrieved: {}", path.display()),
           );
@@ -99,7 +143,7 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
         "  (rust-env-var \"PATH\")  ; Get PATH environment variable\n  (rust-env-var \"HOME\")  ; Get HOME environment variable",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-env", "executing rust-env-var command");
+            debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-var command");
 
             if args.len() != 1 {
                 return Err("rust-env-var expects exactly one argument (variable name)".to_string());
@@ -110,14 +154,14 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-env-var variable name must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-env", &format!("getting environment variable: {}", var_name));
+            debug_log(ctx, LogLevel::Debug, "rust-env", &format!("getting environment variable: {}", var_name));
             match env::var(&var_name) {
                 Ok(value) => {
-                    debug_log(ctx, "rust-env", &format!("environment variable '{}' retrieved successfully", var_name));
+                    debug_log(ctx, LogLevel::Info, "rust-env", &format!("environment variable '{}' retrieved successfully", var_name));
                     Ok(Value::Str(value))
                 },
                 Err(env::VarError::NotPresent) => {
-                    debug_log(ctx, "rust-env", &format!("environment variable '{}' not found", var_name));
+                    debug_log(ctx, LogLevel::Debug, "rust-env", &format!("environment variable '{}' not found", var_name));
                     Ok(Value::Nil)
                 },
                 Err(env::VarError::NotUnicode(_)) => Err(format!("Environment variable '{}' contains invalid Unicode", var_name)),
@@ -133,13 +177,13 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
     "  (rust-env-vars)  ; Returns list of all environment variables",
     &tags::RUST,
     |args, ctx| {
-      debug_log(ctx, "rust-env", "executing rust-env-vars command");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-vars command");
 
       if !args.is_empty() {
         return Err("rust-env-vars expects no arguments".to_string());
       }
 
-      debug_log(ctx, "rust-env", "collecting all environment variables");
+      debug_log(ctx, LogLevel::Debug, "rust-env", "collecting all environment variables");
       let mut vars = Vec::new();
       let mut count = 0;
       for (key, value) in env::vars() {
@@ -150,10 +194,125 @@ pub fn register_env_commands(registry: &mut CommandRegistry) {
 
       debug_log(
         ctx,
+        LogLevel::Debug,
         "rust-env",
         &format!("collected {} environment variables", count),
       );
       Ok(Value::List(vars))
     },
   );
+
+  // rust-env-set-var command
+  registry.register_closure_with_help_and_tag(
+    "rust-env-set-var",
+    "Set an environment variable for the whole dpm process and the processes it spawns",
+    "(rust-env-set-var name value)",
+    "  (rust-env-set-var \"COMPOSE_PROFILES\" \"dev\")  ; Seen by later docker and rust-process-* calls\n  ; The change is process-wide: it outlives the current script and is not undone by try/catch",
+    &tags::RUST,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-set-var command");
+
+      if args.len() != 2 {
+        return Err("rust-env-set-var expects exactly two arguments (variable name and value)".to_string());
+      }
+
+      let var_name = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("rust-env-set-var variable name must be a string".to_string()),
+      };
+      validate_var_name("rust-env-set-var", &var_name)?;
+
+      let value = match &args[1] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("rust-env-set-var value must be a string".to_string()),
+      };
+      if value.contains('\0') {
+        return Err("rust-env-set-var value must not contain NUL characters".to_string());
+      }
+
+      debug_log(ctx, LogLevel::Debug, "rust-env", &format!("setting environment variable: {}", var_name));
+      env::set_var(&var_name, &value);
+      Ok(Value::Str(format!("Environment variable '{}' set", var_name)))
+    },
+  );
+
+  // rust-env-remove-var command
+  registry.register_closure_with_help_and_tag(
+    "rust-env-remove-var",
+    "Remove an environment variable from the whole dpm process and the processes it spawns",
+    "(rust-env-remove-var name)",
+    "  (rust-env-remove-var \"DOCKER_HOST\")  ; Later docker calls use the default socket",
+    &tags::RUST,
+    |args, ctx| {
+      debug_log(ctx, LogLevel::Debug, "rust-env", "executing rust-env-remove-var command");
+
+      if args.len() != 1 {
+        return Err("rust-env-remove-var expects exactly one argument (variable name)".to_string());
+      }
+
+      let var_name = match &args[0] {
+        Value::Str(s) => s.clone(),
+        _ => return Err("rust-env-remove-var variable name must be a string".to_string()),
+      };
+      validate_var_name("rust-env-remove-var", &var_name)?;
+
+      debug_log(ctx, LogLevel::Debug, "rust-env", &format!("removing environment variable: {}", var_name));
+      env::remove_var(&var_name);
+      Ok(Value::Str(format!("Environment variable '{}' removed", var_name)))
+    },
+  );
+}
+
+/// Rejects names that `env::set_var`/`env::remove_var` would panic on
+fn validate_var_name(command_name: &str, var_name: &str) -> Result<(), String> {
+  if var_name.is_empty() || var_name.contains('=') || var_name.contains('\0') {
+    return Err(format!(
+      "{} variable name must be non-empty and must not contain '=' or NUL characters",
+      command_name
+    ));
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+  use crate::evaluate_string;
+
+  #[test]
+  fn test_set_var_read_back_and_remove() {
+    let mut registry = CommandRegistry::new();
+    register_env_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let result = evaluate_string("(rust-env-set-var \"DPM_TEST_ENV_SET\" \"value 1\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("Environment variable 'DPM_TEST_ENV_SET' set".to_string()));
+    let value = evaluate_string("(rust-env-var \"DPM_TEST_ENV_SET\")", &mut ctx).unwrap();
+    assert_eq!(value, Value::Str("value 1".to_string()));
+
+    evaluate_string("(rust-env-remove-var \"DPM_TEST_ENV_SET\")", &mut ctx).unwrap();
+    let value = evaluate_string("(rust-env-var \"DPM_TEST_ENV_SET\")", &mut ctx).unwrap();
+    assert_eq!(value, Value::Nil);
+
+    assert!(evaluate_string("(rust-env-set-var \"A=B\" \"x\")", &mut ctx).is_err());
+    assert!(evaluate_string("(rust-env-set-var \"\" \"x\")", &mut ctx).is_err());
+    assert!(evaluate_string("(rust-env-remove-var 1)", &mut ctx).is_err());
+  }
+
+  #[test]
+  fn test_set_current_dir_rejects_invalid_paths() {
+    let mut registry = CommandRegistry::new();
+    register_env_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    let before = env::current_dir().unwrap();
+
+    let err = evaluate_string("(rust-env-set-current-dir \"/no/such/dpm/dir\")", &mut ctx).unwrap_err();
+    assert!(err.contains("does not exist"));
+
+    let err = evaluate_string("(rust-env-set-current-dir \"Cargo.toml\")", &mut ctx).unwrap_err();
+    assert!(err.contains("is not a directory"));
+
+    assert_eq!(env::current_dir().unwrap(), before);
+  }
 }
@@ -1,6 +1,12 @@
+use crate::context::LogLevel;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Value, tags};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use std::fs;
+use std::io;
+use std::path::Path;
+use walkdir::WalkDir;
 
 /// Register filesystem commands
 pub fn register_fs_commands(registry: &mut CommandRegistry) {
@@ -12,7 +18,7 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
         "  (rust-fs-read-to-string \"config.txt\")  ; Read file contents as string\n  (rust-fs-read-to-string \"/etc/hosts\")  ; Read system file",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-fs", "executing rust-fs-read-to-string command");
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-read-to-string command");
 
             if args.len() != 1 {
                 return Err("rust-fs-read-to-string expects exactly one argument (file path)".to_string());
@@ -23,10 +29,10 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-fs-read-to-string file path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-fs", &format!("reading file contents from: {}", file_path));
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("reading file contents from: {}", file_path));
             match fs::read_to_string(&file_path) {
                 Ok(contents) => {
-                    debug_log(ctx, "rust-fs", &format!("successfully read {} bytes from file", contents.len()));
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully read {} bytes from file", contents.len()));
                     Ok(Value::Str(contents))
                 },
                 Err(e) => Err(format!("Failed to read file '{}': {}", file_path, e)),
@@ -42,7 +48,7 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
         "  (rust-fs-write \"output.txt\" \"Hello, World!\")  ; Write string to file\n  (rust-fs-write \"config.json\" \"{\\\"key\\\": \\\"value\\\"}\")  ; Write JSON content",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-fs", "executing rust-fs-write command");
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-write command");
 
             if args.len() != 2 {
                 return Err("rust-fs-write expects exactly two arguments (file path and content)".to_string());
@@ -58,10 +64,10 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-fs-write content must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-fs", &format!("writing {} bytes to file: {}", content.len(), file_path));
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("writing {} bytes to file: {}", content.len(), file_path));
             match fs::write(&file_path, &content) {
                 Ok(()) => {
-                    debug_log(ctx, "rust-fs", &format!("successfully wrote to file: {}", file_path));
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully wrote to file: {}", file_path));
                     Ok(Value::Str(format!("Successfully wrote {} bytes to '{}'", content.len(), file_path)))
                 },
                 Err(e) => Err(format!("Failed to write to file '{}': {}", file_path, e)),
@@ -77,7 +83,7 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
         "  (rust-fs-create-dir \"new_folder\")  ; Create directory\n  (rust-fs-create-dir \"/tmp/test_dir\")  ; Create directory with absolute path",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-fs", "executing rust-fs-create-dir command");
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-create-dir command");
 
             if args.len() != 1 {
                 return Err("rust-fs-create-dir expects exactly one argument (directory path)".to_string());
@@ -88,10 +94,40 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-fs-create-dir directory path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-fs", &format!("creating directory: {}", dir_path));
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("creating directory: {}", dir_path));
             match fs::create_dir(&dir_path) {
                 Ok(()) => {
-                    debug_log(ctx, "rust-fs", &format!("successfully created directory: {}", dir_path));
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully created directory: {}", dir_path));
+                    Ok(Value::Str(format!("Successfully created directory '{}'", dir_path)))
+                },
+                Err(e) => Err(format!("Failed to create directory '{}': {}", dir_path, e)),
+            }
+        },
+    );
+
+  // rust-fs-create-dir-all command
+  registry.register_closure_with_help_and_tag(
+        "rust-fs-create-dir-all",
+        "Create a directory and all of its missing parent directories",
+        "(rust-fs-create-dir-all path)",
+        "  (rust-fs-create-dir-all \"build/cache/images\")  ; Create nested directories\n  (rust-fs-create-dir-all \"existing_folder\")  ; Succeeds if the directory already exists",
+        &tags::RUST,
+        |args, ctx| {
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-create-dir-all command");
+
+            if args.len() != 1 {
+                return Err("rust-fs-create-dir-all expects exactly one argument (directory path)".to_string());
+            }
+
+            let dir_path = match &args[0] {
+                Value::Str(s) => s.clone(),
+                _ => return Err("rust-fs-create-dir-all directory path must be a string".to_string()),
+            };
+
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("creating directory tree: {}", dir_path));
+            match fs::create_dir_all(&dir_path) {
+                Ok(()) => {
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully created directory tree: {}", dir_path));
                     Ok(Value::Str(format!("Successfully created directory '{}'", dir_path)))
                 },
                 Err(e) => Err(format!("Failed to create directory '{}': {}", dir_path, e)),
@@ -107,7 +143,7 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
         "  (rust-fs-remove-file \"temp.txt\")  ; Remove file\n  (rust-fs-remove-file \"/tmp/old_file.log\")  ; Remove file with absolute path",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-fs", "executing rust-fs-remove-file command");
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-remove-file command");
 
             if args.len() != 1 {
                 return Err("rust-fs-remove-file expects exactly one argument (file path)".to_string());
@@ -118,10 +154,10 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-fs-remove-file file path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-fs", &format!("removing file: {}", file_path));
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("removing file: {}", file_path));
             match fs::remove_file(&file_path) {
                 Ok(()) => {
-                    debug_log(ctx, "rust-fs", &format!("successfully removed file: {}", file_path));
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully removed file: {}", file_path));
                     Ok(Value::Str(format!("Successfully removed file '{}'", file_path)))
                 },
                 Err(e) => Err(format!("Failed to remove file '{}': {}", file_path, e)),
@@ -137,7 +173,7 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
         "  (rust-fs-copy \"source.txt\" \"backup.txt\")  ; Copy file\n  (rust-fs-copy \"/etc/config\" \"/tmp/config.bak\")  ; Copy with absolute paths",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-fs", "executing rust-fs-copy command");
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-copy command");
 
             if args.len() != 2 {
                 return Err("rust-fs-copy expects exactly two arguments (source and destination paths)".to_string());
@@ -153,14 +189,275 @@ pub fn register_fs_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-fs-copy destination path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-fs", &format!("copying file from '{}' to '{}'", source_path, dest_path));
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("copying file from '{}' to '{}'", source_path, dest_path));
             match fs::copy(&source_path, &dest_path) {
                 Ok(bytes_copied) => {
-                    debug_log(ctx, "rust-fs", &format!("successfully copied {} bytes", bytes_copied));
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully copied {} bytes", bytes_copied));
                     Ok(Value::Str(format!("Successfully copied {} bytes from '{}' to '{}'", bytes_copied, source_path, dest_path)))
                 },
                 Err(e) => Err(format!("Failed to copy from '{}' to '{}': {}", source_path, dest_path, e)),
             }
         },
     );
+
+  // rust-fs-move command
+  registry.register_closure_with_help_and_tag(
+        "rust-fs-move",
+        "Move a file or directory, falling back to copy and remove across filesystems",
+        "(rust-fs-move source destination)",
+        "  (rust-fs-move \"old_name.txt\" \"new_name.txt\")  ; Rename a file\n  (rust-fs-move \"dist\" \"/mnt/volume/dist\")  ; Move a directory to another mount",
+        &tags::RUST,
+        |args, ctx| {
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-move command");
+
+            if args.len() != 2 {
+                return Err("rust-fs-move expects exactly two arguments (source and destination paths)".to_string());
+            }
+
+            let source_path = match &args[0] {
+                Value::Str(s) => s.clone(),
+                _ => return Err("rust-fs-move source path must be a string".to_string()),
+            };
+
+            let dest_path = match &args[1] {
+                Value::Str(s) => s.clone(),
+                _ => return Err("rust-fs-move destination path must be a string".to_string()),
+            };
+
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("moving '{}' to '{}'", source_path, dest_path));
+            match fs::rename(&source_path, &dest_path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+                    debug_log(ctx, LogLevel::Debug, "rust-fs", "rename crosses filesystems, copying instead");
+                    copy_then_remove(Path::new(&source_path), Path::new(&dest_path))
+                        .map_err(|e| format!("Failed to move '{}' to '{}': {}", source_path, dest_path, e))?;
+                }
+                Err(e) => return Err(format!("Failed to move '{}' to '{}': {}", source_path, dest_path, e)),
+            }
+
+            debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully moved '{}' to '{}'", source_path, dest_path));
+            Ok(Value::Str(format!("Successfully moved '{}' to '{}'", source_path, dest_path)))
+        },
+    );
+
+  // rust-fs-read-base64 command
+  registry.register_closure_with_help_and_tag(
+        "rust-fs-read-base64",
+        "Read the raw bytes of a file and return them base64-encoded",
+        "(rust-fs-read-base64 path)",
+        "  (rust-fs-read-base64 \"certs/ca.der\")  ; Read binary file as base64\n  (set-var \"KEY\" (rust-fs-read-base64 \"id.key\"))  ; Store binary content in a variable",
+        &tags::RUST,
+        |args, ctx| {
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-read-base64 command");
+
+            if args.len() != 1 {
+                return Err("rust-fs-read-base64 expects exactly one argument (file path)".to_string());
+            }
+
+            let file_path = match &args[0] {
+                Value::Str(s) => s.clone(),
+                _ => return Err("rust-fs-read-base64 file path must be a string".to_string()),
+            };
+
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("reading file bytes from: {}", file_path));
+            match fs::read(&file_path) {
+                Ok(bytes) => {
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully read {} bytes from file", bytes.len()));
+                    Ok(Value::Str(BASE64.encode(bytes)))
+                },
+                Err(e) => Err(format!("Failed to read file '{}': {}", file_path, e)),
+            }
+        },
+    );
+
+  // rust-fs-write-base64 command
+  registry.register_closure_with_help_and_tag(
+        "rust-fs-write-base64",
+        "Decode base64 content and write the resulting bytes to a file",
+        "(rust-fs-write-base64 path content)",
+        "  (rust-fs-write-base64 \"out.bin\" \"AAEC/w==\")  ; Write bytes 00 01 02 ff\n  (rust-fs-write-base64 \"id.key\" (get-var \"KEY\"))  ; Restore a stored binary file",
+        &tags::RUST,
+        |args, ctx| {
+            debug_log(ctx, LogLevel::Debug, "rust-fs", "executing rust-fs-write-base64 command");
+
+            if args.len() != 2 {
+                return Err("rust-fs-write-base64 expects exactly two arguments (file path and base64 content)".to_string());
+            }
+
+            let file_path = match &args[0] {
+                Value::Str(s) => s.clone(),
+                _ => return Err("rust-fs-write-base64 file path must be a string".to_string()),
+            };
+
+            let encoded = match &args[1] {
+                Value::Str(s) => s.clone(),
+                _ => return Err("rust-fs-write-base64 content must be a string".to_string()),
+            };
+
+            let bytes = BASE64
+                .decode(encoded.trim())
+                .map_err(|e| format!("rust-fs-write-base64 content is not valid base64: {}", e))?;
+
+            debug_log(ctx, LogLevel::Debug, "rust-fs", &format!("writing {} bytes to file: {}", bytes.len(), file_path));
+            match fs::write(&file_path, &bytes) {
+                Ok(()) => {
+                    debug_log(ctx, LogLevel::Info, "rust-fs", &format!("successfully wrote to file: {}", file_path));
+                    Ok(Value::Str(format!("Successfully wrote {} bytes to '{}'", bytes.len(), file_path)))
+                },
+                Err(e) => Err(format!("Failed to write to file '{}': {}", file_path, e)),
+            }
+        },
+    );
+}
+
+/// Moves `source` to `dest` by copying it and then removing the original
+///
+/// Used when `fs::rename` cannot move across filesystems. Directories are
+/// copied recursively; the source is only removed once the copy is complete.
+fn copy_then_remove(source: &Path, dest: &Path) -> io::Result<()> {
+  if !source.is_dir() {
+    fs::copy(source, dest)?;
+    return fs::remove_file(source);
+  }
+
+  for entry in WalkDir::new(source) {
+    let entry = entry?;
+    let relative = entry.path().strip_prefix(source).map_err(io::Error::other)?;
+    let target = dest.join(relative);
+    if entry.file_type().is_dir() {
+      fs::create_dir_all(&target)?;
+    } else {
+      fs::copy(entry.path(), &target)?;
+    }
+  }
+  fs::remove_dir_all(source)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+
+  #[test]
+  fn test_base64_round_trip_non_utf8() {
+    let mut registry = CommandRegistry::new();
+    register_fs_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let dir = std::env::temp_dir().join("dpm_test_fs_base64");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let source = dir.join("source.bin");
+    let copy = dir.join("copy.bin");
+    let bytes: Vec<u8> = vec![0x00, 0xff, 0xfe, 0x80, b'\n', 0xc3];
+    assert!(String::from_utf8(bytes.clone()).is_err());
+    fs::write(&source, &bytes).unwrap();
+
+    let read = ctx.registry.get("rust-fs-read-base64").unwrap();
+    let encoded = read
+      .execute(vec![Value::Str(source.to_string_lossy().to_string())], &mut ctx)
+      .unwrap();
+    assert_eq!(encoded, Value::Str("AP/+gArD".to_string()));
+
+    let write = ctx.registry.get("rust-fs-write-base64").unwrap();
+    write
+      .execute(vec![Value::Str(copy.to_string_lossy().to_string()), encoded], &mut ctx)
+      .unwrap();
+    assert_eq!(fs::read(&copy).unwrap(), bytes);
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_write_base64_rejects_invalid_input() {
+    let mut registry = CommandRegistry::new();
+    register_fs_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let target = std::env::temp_dir().join("dpm_test_fs_base64_invalid.bin");
+    let _ = fs::remove_file(&target);
+
+    let write = ctx.registry.get("rust-fs-write-base64").unwrap();
+    let err = write
+      .execute(
+        vec![Value::Str(target.to_string_lossy().to_string()), Value::Str("not base64!".to_string())],
+        &mut ctx,
+      )
+      .unwrap_err();
+    assert!(err.contains("not valid base64"));
+    assert!(!target.exists());
+  }
+
+  #[test]
+  fn test_create_dir_all_nested() {
+    let mut registry = CommandRegistry::new();
+    register_fs_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let root = std::env::temp_dir().join("dpm_test_fs_create_dir_all");
+    let _ = fs::remove_dir_all(&root);
+    let nested = root.join("a").join("b").join("c");
+    let args = vec![Value::Str(nested.to_string_lossy().to_string())];
+
+    // create-dir cannot create missing parents
+    let create_dir = ctx.registry.get("rust-fs-create-dir").unwrap();
+    assert!(create_dir.execute(args.clone(), &mut ctx).is_err());
+
+    let create_dir_all = ctx.registry.get("rust-fs-create-dir-all").unwrap();
+    create_dir_all.execute(args.clone(), &mut ctx).unwrap();
+    assert!(nested.is_dir());
+
+    // An existing directory is not an error
+    create_dir_all.execute(args, &mut ctx).unwrap();
+
+    let _ = fs::remove_dir_all(&root);
+  }
+
+  #[test]
+  fn test_move_file_and_directory() {
+    let mut registry = CommandRegistry::new();
+    register_fs_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let root = std::env::temp_dir().join("dpm_test_fs_move");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("dir/sub")).unwrap();
+    fs::write(root.join("file.txt"), "payload").unwrap();
+    fs::write(root.join("dir/sub/inner.txt"), "inner").unwrap();
+    let path = |name: &str| Value::Str(root.join(name).to_string_lossy().to_string());
+
+    let move_cmd = ctx.registry.get("rust-fs-move").unwrap();
+    move_cmd.execute(vec![path("file.txt"), path("moved.txt")], &mut ctx).unwrap();
+    assert!(!root.join("file.txt").exists());
+    assert_eq!(fs::read_to_string(root.join("moved.txt")).unwrap(), "payload");
+
+    move_cmd.execute(vec![path("dir"), path("moved_dir")], &mut ctx).unwrap();
+    assert!(!root.join("dir").exists());
+    assert_eq!(fs::read_to_string(root.join("moved_dir/sub/inner.txt")).unwrap(), "inner");
+
+    assert!(move_cmd.execute(vec![path("missing"), path("other")], &mut ctx).is_err());
+
+    let _ = fs::remove_dir_all(&root);
+  }
+
+  #[test]
+  fn test_copy_then_remove_fallback() {
+    // The cross-device fallback itself, exercised on a single filesystem
+    let root = std::env::temp_dir().join("dpm_test_fs_copy_then_remove");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src/nested")).unwrap();
+    fs::write(root.join("src/top.txt"), "top").unwrap();
+    fs::write(root.join("src/nested/deep.txt"), "deep").unwrap();
+
+    copy_then_remove(&root.join("src"), &root.join("dst")).unwrap();
+    assert!(!root.join("src").exists());
+    assert_eq!(fs::read_to_string(root.join("dst/top.txt")).unwrap(), "top");
+    assert_eq!(fs::read_to_string(root.join("dst/nested/deep.txt")).unwrap(), "deep");
+
+    copy_then_remove(&root.join("dst/top.txt"), &root.join("top.txt")).unwrap();
+    assert!(!root.join("dst/top.txt").exists());
+    assert_eq!(fs::read_to_string(root.join("top.txt")).unwrap(), "top");
+
+    let _ = fs::remove_dir_all(&root);
+  }
 }
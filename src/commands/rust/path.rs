@@ -1,3 +1,4 @@
+use crate::context::LogLevel;
 use crate::utils::debug_log;
 use crate::{CommandRegistry, Value, tags};
 use std::path::Path;
@@ -12,7 +13,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-join \"/home\" \"user\" \"documents\")  ; Returns /home/user/documents\n  (rust-path-join \"..\" \"project\" \"src\")  ; Returns ../project/src",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-join command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-join command");
 
             if args.is_empty() {
                 return Err("rust-path-join expects at least one argument".to_string());
@@ -26,13 +27,13 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 }
             }
 
-            debug_log(ctx, "rust-path", &format!("joining {} path components", path_components.len()));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("joining {} path components", path_components.len()));
             let mut result_path = Path::new(&path_components[0]).to_path_buf();
             for component in &path_components[1..] {
                 result_path = result_path.join(component);
             }
 
-            debug_log(ctx, "rust-path", &format!("path joined successfully: {}", result_path.display()));
+            debug_log(ctx, LogLevel::Info, "rust-path", &format!("path joined successfully: {}", result_path.display()));
             Ok(Value::Str(result_path.to_string_lossy().to_string()))
         },
     );
@@ -45,7 +46,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-parent \"/home/user/file.txt\")  ; Returns /home/user\n  (rust-path-parent \"../project/src\")  ; Returns ../project",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-parent command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-parent command");
 
             if args.len() != 1 {
                 return Err("rust-path-parent expects exactly one argument (path)".to_string());
@@ -56,15 +57,15 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-path-parent path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-path", &format!("getting parent directory of: {}", path_str));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("getting parent directory of: {}", path_str));
             let path = Path::new(&path_str);
             match path.parent() {
                 Some(parent) => {
-                    debug_log(ctx, "rust-path", &format!("parent directory found: {}", parent.display()));
+                    debug_log(ctx, LogLevel::Debug, "rust-path", &format!("parent directory found: {}", parent.display()));
                     Ok(Value::Str(parent.to_string_lossy().to_string()))
                 },
                 None => {
-                    debug_log(ctx, "rust-path", "no parent directory found");
+                    debug_log(ctx, LogLevel::Debug, "rust-path", "no parent directory found");
                     Ok(Value::Nil)
                 },
             }
@@ -79,7 +80,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-filename \"/home/user/file.txt\")  ; Returns file.txt\n  (rust-path-filename \"../project/src/main.rs\")  ; Returns main.rs",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-filename command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-filename command");
 
             if args.len() != 1 {
                 return Err("rust-path-filename expects exactly one argument (path)".to_string());
@@ -90,15 +91,15 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-path-filename path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-path", &format!("extracting filename from: {}", path_str));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("extracting filename from: {}", path_str));
             let path = Path::new(&path_str);
             match path.file_name() {
                 Some(filename) => {
-                    debug_log(ctx, "rust-path", &format!("filename extracted: {}", filename.to_string_lossy()));
+                    debug_log(ctx, LogLevel::Debug, "rust-path", &format!("filename extracted: {}", filename.to_string_lossy()));
                     Ok(Value::Str(filename.to_string_lossy().to_string()))
                 },
                 None => {
-                    debug_log(ctx, "rust-path", "no filename found");
+                    debug_log(ctx, LogLevel::Debug, "rust-path", "no filename found");
                     Ok(Value::Nil)
                 },
             }
@@ -113,7 +114,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-extension \"file.txt\")  ; Returns txt\n  (rust-path-extension \"archive.tar.gz\")  ; Returns gz",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-extension command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-extension command");
 
             if args.len() != 1 {
                 return Err("rust-path-extension expects exactly one argument (path)".to_string());
@@ -124,15 +125,15 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-path-extension path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-path", &format!("extracting extension from: {}", path_str));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("extracting extension from: {}", path_str));
             let path = Path::new(&path_str);
             match path.extension() {
                 Some(ext) => {
-                    debug_log(ctx, "rust-path", &format!("extension extracted: {}", ext.to_string_lossy()));
+                    debug_log(ctx, LogLevel::Debug, "rust-path", &format!("extension extracted: {}", ext.to_string_lossy()));
                     Ok(Value::Str(ext.to_string_lossy().to_string()))
                 },
                 None => {
-                    debug_log(ctx, "rust-path", "no extension found");
+                    debug_log(ctx, LogLevel::Debug, "rust-path", "no extension found");
                     Ok(Value::Nil)
                 },
             }
@@ -147,7 +148,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-exists \"/home/user\")  ; Returns true if path exists\n  (rust-path-exists \"nonexistent.txt\")  ; Returns false",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-exists command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-exists command");
 
             if args.len() != 1 {
                 return Err("rust-path-exists expects exactly one argument (path)".to_string());
@@ -158,10 +159,10 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-path-exists path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-path", &format!("checking if path exists: {}", path_str));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("checking if path exists: {}", path_str));
             let path = Path::new(&path_str);
             let exists = path.exists();
-            debug_log(ctx, "rust-path", &format!("path exists: {}", exists));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("path exists: {}", exists));
             Ok(Value::Bool(exists))
         },
     );
@@ -174,7 +175,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-is-dir \"/home/user\")  ; Returns true if path is a directory\n  (rust-path-is-dir \"file.txt\")  ; Returns false",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-is-dir command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-is-dir command");
 
             if args.len() != 1 {
                 return Err("rust-path-is-dir expects exactly one argument (path)".to_string());
@@ -185,10 +186,10 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-path-is-dir path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-path", &format!("checking if path is directory: {}", path_str));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("checking if path is directory: {}", path_str));
             let path = Path::new(&path_str);
             let is_dir = path.is_dir();
-            debug_log(ctx, "rust-path", &format!("path is directory: {}", is_dir));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("path is directory: {}", is_dir));
             Ok(Value::Bool(is_dir))
         },
     );
@@ -201,7 +202,7 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
         "  (rust-path-is-file \"file.txt\")  ; Returns true if path is a file\n  (rust-path-is-file \"/home/user\")  ; Returns false",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-path", "executing rust-path-is-file command");
+            debug_log(ctx, LogLevel::Debug, "rust-path", "executing rust-path-is-file command");
 
             if args.len() != 1 {
                 return Err("rust-path-is-file expects exactly one argument (path)".to_string());
@@ -212,10 +213,10 @@ pub fn register_path_commands(registry: &mut CommandRegistry) {
                 _ => return Err("rust-path-is-file path must be a string".to_string()),
             };
 
-            debug_log(ctx, "rust-path", &format!("checking if path is file: {}", path_str));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("checking if path is file: {}", path_str));
             let path = Path::new(&path_str);
             let is_file = path.is_file();
-            debug_log(ctx, "rust-path", &format!("path is file: {}", is_file));
+            debug_log(ctx, LogLevel::Debug, "rust-path", &format!("path is file: {}", is_file));
             Ok(Value::Bool(is_file))
         },
     );
@@ -1,49 +1,164 @@
-use crate::utils::debug_log;
+use crate::context::LogLevel;
+use crate::utils::{debug_log, output_with_input_and_timeout, output_with_timeout, wait_with_timeout};
 use crate::{CommandRegistry, Value, tags};
 use std::process::Command;
+use std::time::Duration;
+
+/// Splits an optional leading timeout (integer seconds) from the remaining
+/// process arguments, which must all be strings.
+///
+/// # Returns
+/// * `Result<(Option<Duration>, Vec<String>), String>` - The timeout, if
+///   given, and the program followed by its arguments
+fn parse_process_args(
+  command_name: &str,
+  args: &[Value],
+) -> Result<(Option<Duration>, Vec<String>), String> {
+  let (timeout, rest) = match args.first() {
+    Some(Value::Int(secs)) if *secs > 0 => {
+      (Some(Duration::from_secs(*secs as u64)), &args[1..])
+    }
+    Some(Value::Int(_)) => {
+      return Err(format!("{} timeout must be a positive number of seconds", command_name));
+    }
+    _ => (None, args),
+  };
+
+  if rest.is_empty() {
+    return Err(format!("{} expects at least one argument (program name)", command_name));
+  }
+
+  let mut command_args = Vec::new();
+  for arg in rest {
+    match arg {
+      Value::Str(s) => command_args.push(s.clone()),
+      _ => return Err(format!("{} all arguments must be strings", command_name)),
+    }
+  }
+
+  Ok((timeout, command_args))
+}
+
+/// Parses a list of `(name value)` pairs into environment variables for a
+/// single child process.
+///
+/// # Returns
+/// * `Result<Vec<(String, String)>, String>` - The name/value pairs, or an
+///   error if the list is malformed or a value is not a string
+fn parse_env_pairs(
+  command_name: &str,
+  env_list: &Value,
+) -> Result<Vec<(String, String)>, String> {
+  let pairs = match env_list {
+    Value::List(pairs) => pairs,
+    Value::Nil => return Ok(Vec::new()),
+    _ => return Err(format!("{} expects a list of (name value) pairs as first argument", command_name)),
+  };
+
+  let mut env_vars = Vec::new();
+  for pair in pairs {
+    match pair {
+      Value::List(items) if items.len() == 2 => match (&items[0], &items[1]) {
+        (Value::Str(name), Value::Str(value)) => {
+          if name.is_empty() || name.contains('=') || name.contains('\0') || value.contains('\0') {
+            return Err(format!("{} invalid environment variable '{}'", command_name, name));
+          }
+          env_vars.push((name.clone(), value.clone()))
+        }
+        (Value::Str(name), _) => {
+          return Err(format!("{} value of environment variable '{}' must be a string", command_name, name));
+        }
+        _ => return Err(format!("{} environment variable names must be strings", command_name)),
+      },
+      _ => return Err(format!("{} each environment entry must be a (name value) pair", command_name)),
+    }
+  }
+
+  Ok(env_vars)
+}
 
 /// Register process commands
 pub fn register_process_commands(registry: &mut CommandRegistry) {
   // rust-process-command command
   registry.register_closure_with_help_and_tag(
         "rust-process-command",
-        "Execute a system command and return the exit status",
-        "(rust-process-command program arg1 arg2 ...)",
-        "  (rust-process-command \"ls\" \"-la\")  ; List files with details\n  (rust-process-command \"echo\" \"Hello World\")  ; Echo a message",
+        "Execute a system command and return the exit status, optionally killing it after a timeout",
+        "(rust-process-command [timeout-seconds] program arg1 arg2 ...)",
+        "  (rust-process-command \"ls\" \"-la\")  ; List files with details\n  (rust-process-command \"echo\" \"Hello World\")  ; Echo a message\n  (rust-process-command 30 \"make\" \"test\")  ; Kill make if it runs longer than 30s",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-process", "executing rust-process-command command");
+            debug_log(ctx, LogLevel::Debug, "rust-process", "executing rust-process-command command");
 
-            if args.is_empty() {
-                return Err("rust-process-command expects at least one argument (program name)".to_string());
-            }
+            let (timeout, command_args) = parse_process_args("rust-process-command", &args)?;
 
-            let mut command_args = Vec::new();
-            for arg in &args {
-                match arg {
-                    Value::Str(s) => command_args.push(s.clone()),
-                    _ => return Err("rust-process-command all arguments must be strings".to_string()),
+            let program = &command_args[0];
+            let args = &command_args[1..];
+
+            debug_log(ctx, LogLevel::Debug, "rust-process", &format!("executing system command: {} with {} arguments (timeout: {:?})", program, args.len(), timeout));
+            let mut cmd = Command::new(program);
+            cmd.args(args);
+
+            let mut child = match cmd.spawn() {
+                Ok(child) => child,
+                Err(e) => return Err(format!("Failed to execute command '{}': {}", program, e)),
+            };
+
+            match wait_with_timeout(&mut child, timeout) {
+                Ok(status) => {
+                    let success = status.success();
+                    let code = status.code().unwrap_or(-1);
+                    debug_log(ctx, LogLevel::Info, "rust-process", &format!("command completed with success: {}, exit code: {}", success, code));
+                    Ok(Value::List(vec![
+                        Value::Bool(success),
+                        Value::Int(code as i64),
+                    ]))
                 }
+                Err(e) => Err(format!("Command '{}' failed: {}", program, e)),
+            }
+        },
+    );
+
+  // rust-process-command-env command
+  registry.register_closure_with_help_and_tag(
+        "rust-process-command-env",
+        "Execute a system command with extra environment variables set only for that child process",
+        "(rust-process-command-env ((name value) ...) [timeout-seconds] program arg1 arg2 ...)",
+        "  (rust-process-command-env (list (list \"RUST_LOG\" \"debug\")) \"cargo\" \"run\")  ; RUST_LOG is set for cargo only\n  (rust-process-command-env (list (list \"A\" \"1\") (list \"B\" \"2\")) 30 \"make\")  ; With a timeout",
+        &tags::RUST,
+        |args, ctx| {
+            debug_log(ctx, LogLevel::Debug, "rust-process", "executing rust-process-command-env command");
+
+            if args.is_empty() {
+                return Err("rust-process-command-env expects an environment list and a program name".to_string());
             }
+            let env_vars = parse_env_pairs("rust-process-command-env", &args[0])?;
+            let (timeout, command_args) = parse_process_args("rust-process-command-env", &args[1..])?;
 
             let program = &command_args[0];
             let args = &command_args[1..];
 
-            debug_log(ctx, "rust-process", &format!("executing system command: {} with {} arguments", program, args.len()));
+            debug_log(ctx, LogLevel::Debug, "rust-process", &format!("executing system command: {} with {} arguments and {} extra environment variables (timeout: {:?})", program, args.len(), env_vars.len(), timeout));
             let mut cmd = Command::new(program);
             cmd.args(args);
+            // Only the child's environment is changed, never the parent's
+            cmd.envs(env_vars);
+
+            let mut child = match cmd.spawn() {
+                Ok(child) => child,
+                Err(e) => return Err(format!("Failed to execute command '{}': {}", program, e)),
+            };
 
-            match cmd.status() {
+            match wait_with_timeout(&mut child, timeout) {
                 Ok(status) => {
                     let success = status.success();
                     let code = status.code().unwrap_or(-1);
-                    debug_log(ctx, "rust-process", &format!("command completed with success: {}, exit code: {}", success, code));
+                    debug_log(ctx, LogLevel::Info, "rust-process", &format!("command completed with success: {}, exit code: {}", success, code));
                     Ok(Value::List(vec![
                         Value::Bool(success),
                         Value::Int(code as i64),
                     ]))
                 }
-                Err(e) => Err(format!("Failed to execute command '{}': {}", program, e)),
+                Err(e) => Err(format!("Command '{}' failed: {}", program, e)),
             }
         },
     );
@@ -51,40 +166,75 @@ pub fn register_process_commands(registry: &mut CommandRegistry) {
   // rust-process-output command
   registry.register_closure_with_help_and_tag(
         "rust-process-output",
-        "Execute a system command and return the output (stdout, stderr, status)",
-        "(rust-process-output program arg1 arg2 ...)",
-        "  (rust-process-output \"echo\" \"Hello\")  ; Get echo output\n  (rust-process-output \"ls\" \"-la\" \"/tmp\")  ; Get directory listing",
+        "Execute a system command and return the output (stdout, stderr, status), optionally killing it after a timeout",
+        "(rust-process-output [timeout-seconds] program arg1 arg2 ...)",
+        "  (rust-process-output \"echo\" \"Hello\")  ; Get echo output\n  (rust-process-output \"ls\" \"-la\" \"/tmp\")  ; Get directory listing\n  (rust-process-output 10 \"git\" \"fetch\")  ; Give up after 10 seconds",
         &tags::RUST,
         |args, ctx| {
-            debug_log(ctx, "rust-process", "executing rust-process-output command");
+            debug_log(ctx, LogLevel::Debug, "rust-process", "executing rust-process-output command");
 
-            if args.is_empty() {
-                return Err("rust-process-output expects at least one argument (program name)".to_string());
-            }
+            let (timeout, command_args) = parse_process_args("rust-process-output", &args)?;
+
+            let program = &command_args[0];
+            let args = &command_args[1..];
+
+            debug_log(ctx, LogLevel::Debug, "rust-process", &format!("executing system command with output capture: {} with {} arguments (timeout: {:?})", program, args.len(), timeout));
+            let mut cmd = Command::new(program);
+            cmd.args(args);
+
+            match output_with_timeout(&mut cmd, timeout) {
+                Ok(output) => {
+                    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                    let success = output.status.success();
+                    let code = output.status.code().unwrap_or(-1);
+
+                    debug_log(ctx, LogLevel::Info, "rust-process", &format!("command completed with success: {}, exit code: {}, stdout: {} bytes, stderr: {} bytes", 
+                        success, code, stdout.len(), stderr.len()));
 
-            let mut command_args = Vec::new();
-            for arg in &args {
-                match arg {
-                    Value::Str(s) => command_args.push(s.clone()),
-                    _ => return Err("rust-process-output all arguments must be strings".to_string()),
+                    Ok(Value::List(vec![
+                        Value::Str(stdout),
+                        Value::Str(stderr),
+                        Value::Bool(success),
+                        Value::Int(code as i64),
+                    ]))
                 }
+                Err(e) => Err(format!("Failed to execute command '{}': {}", program, e)),
+            }
+        },
+    );
+
+  // rust-process-input command
+  registry.register_closure_with_help_and_tag(
+        "rust-process-input",
+        "Execute a system command feeding a string to its stdin and return the output (stdout, stderr, status)",
+        "(rust-process-input [timeout-seconds] program input arg1 arg2 ...)",
+        "  (rust-process-input \"cat\" \"Hello\")  ; Returns (\"Hello\" \"\" true 0)\n  (rust-process-input \"docker\" (rust-fs-read-to-string \"compose.yml\") \"compose\" \"-f\" \"-\" \"config\")  ; Pipe generated content into a CLI",
+        &tags::RUST,
+        |args, ctx| {
+            debug_log(ctx, LogLevel::Debug, "rust-process", "executing rust-process-input command");
+
+            let (timeout, command_args) = parse_process_args("rust-process-input", &args)?;
+            if command_args.len() < 2 {
+                return Err("rust-process-input expects a program name and an input string".to_string());
             }
 
             let program = &command_args[0];
-            let args = &command_args[1..];
+            let input = &command_args[1];
+            let args = &command_args[2..];
 
-            debug_log(ctx, "rust-process", &format!("executing system command with output capture: {} with {} arguments", program, args.len()));
+            debug_log(ctx, LogLevel::Debug, "rust-process", &format!("executing system command with {} bytes of input: {} with {} arguments (timeout: {:?})", input.len(), program, args.len(), timeout));
             let mut cmd = Command::new(program);
             cmd.args(args);
 
-            match cmd.output() {
+            match output_with_input_and_timeout(&mut cmd, Some(input.as_bytes()), timeout) {
                 Ok(output) => {
                     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
                     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
                     let success = output.status.success();
                     let code = output.status.code().unwrap_or(-1);
 
-                    debug_log(ctx, "rust-process", &format!("command completed with success: {}, exit code: {}, stdout: {} bytes, stderr: {} bytes", 
+                    debug_log(ctx, LogLevel::Info, "rust-process", &format!("command completed with success: {}, exit code: {}, stdout: {} bytes, stderr: {} bytes",
                         success, code, stdout.len(), stderr.len()));
 
                     Ok(Value::List(vec![
@@ -99,3 +249,131 @@ pub fn register_process_commands(registry: &mut CommandRegistry) {
         },
     );
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+  use std::time::Instant;
+
+  #[test]
+  fn test_parse_process_args_timeout() {
+    let args = vec![Value::Int(5), Value::Str("sleep".to_string()), Value::Str("1".to_string())];
+    let (timeout, command_args) = parse_process_args("test", &args).unwrap();
+    assert_eq!(timeout, Some(Duration::from_secs(5)));
+    assert_eq!(command_args, vec!["sleep", "1"]);
+
+    let args = vec![Value::Str("echo".to_string())];
+    let (timeout, _) = parse_process_args("test", &args).unwrap();
+    assert_eq!(timeout, None);
+
+    assert!(parse_process_args("test", &[Value::Int(0), Value::Str("echo".to_string())]).is_err());
+    assert!(parse_process_args("test", &[Value::Int(5)]).is_err());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_rust_process_command_timeout_kills_child() {
+    let mut registry = CommandRegistry::new();
+    register_process_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let started = Instant::now();
+    let args = vec![Value::Int(1), Value::Str("sleep".to_string()), Value::Str("30".to_string())];
+    let result = ctx.registry.get("rust-process-command").unwrap().execute(args, &mut ctx);
+
+    assert!(result.unwrap_err().contains("timed out"));
+    assert!(started.elapsed() < Duration::from_secs(10));
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_rust_process_output_within_timeout() {
+    let mut registry = CommandRegistry::new();
+    register_process_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![Value::Int(10), Value::Str("echo".to_string()), Value::Str("hello".to_string())];
+    let result = ctx.registry.get("rust-process-output").unwrap().execute(args, &mut ctx).unwrap();
+
+    assert_eq!(
+      result,
+      Value::List(vec![
+        Value::Str("hello\n".to_string()),
+        Value::Str(String::new()),
+        Value::Bool(true),
+        Value::Int(0),
+      ])
+    );
+
+    let args = vec![Value::Int(1), Value::Str("sleep".to_string()), Value::Str("30".to_string())];
+    let result = ctx.registry.get("rust-process-output").unwrap().execute(args, &mut ctx);
+    assert!(result.unwrap_err().contains("timed out"));
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_rust_process_input_pipes_through_cat() {
+    let mut registry = CommandRegistry::new();
+    register_process_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let args = vec![Value::Str("cat".to_string()), Value::Str("line 1\nline 2\n".to_string())];
+    let result = ctx.registry.get("rust-process-input").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(
+      result,
+      Value::List(vec![
+        Value::Str("line 1\nline 2\n".to_string()),
+        Value::Str(String::new()),
+        Value::Bool(true),
+        Value::Int(0),
+      ])
+    );
+
+    // A child that exits without reading a large input does not deadlock
+    let input = "x".repeat(1024 * 1024);
+    let args = vec![Value::Int(10), Value::Str("true".to_string()), Value::Str(input)];
+    let result = ctx.registry.get("rust-process-input").unwrap().execute(args, &mut ctx).unwrap();
+    assert_eq!(value_at(&result, 2), Value::Bool(true));
+
+    let args = vec![Value::Str("cat".to_string())];
+    assert!(ctx.registry.get("rust-process-input").unwrap().execute(args, &mut ctx).is_err());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_rust_process_command_env_scopes_variable_to_child() {
+    let mut registry = CommandRegistry::new();
+    register_process_commands(&mut registry);
+    crate::commands::rust::env::register_env_commands(&mut registry);
+    crate::commands::register_list_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let script = "(rust-process-command-env (list (list \"DPM_TEST_CHILD_ONLY\" \"scoped\")) \"sh\" \"-c\" \"test \\\"$DPM_TEST_CHILD_ONLY\\\" = scoped\")";
+    let result = crate::evaluate_string(script, &mut ctx).unwrap();
+    assert_eq!(result, Value::List(vec![Value::Bool(true), Value::Int(0)]));
+
+    let parent = crate::evaluate_string("(rust-env-var \"DPM_TEST_CHILD_ONLY\")", &mut ctx).unwrap();
+    assert_eq!(parent, Value::Nil);
+  }
+
+  #[test]
+  fn test_parse_env_pairs() {
+    let pairs = Value::List(vec![Value::List(vec![Value::Str("A".to_string()), Value::Str("1".to_string())])]);
+    assert_eq!(parse_env_pairs("test", &pairs).unwrap(), vec![("A".to_string(), "1".to_string())]);
+    assert!(parse_env_pairs("test", &Value::Nil).unwrap().is_empty());
+
+    let bad_value = Value::List(vec![Value::List(vec![Value::Str("A".to_string()), Value::Int(1)])]);
+    assert!(parse_env_pairs("test", &bad_value).unwrap_err().contains("must be a string"));
+    assert!(parse_env_pairs("test", &Value::Str("A=1".to_string())).is_err());
+    let bad_pair = Value::List(vec![Value::List(vec![Value::Str("A".to_string())])]);
+    assert!(parse_env_pairs("test", &bad_pair).is_err());
+  }
+
+  fn value_at(value: &Value, index: usize) -> Value {
+    match value {
+      Value::List(items) => items[index].clone(),
+      other => panic!("expected a list, got {:?}", other),
+    }
+  }
+}
@@ -3,8 +3,9 @@
 //! This module contains the execution context that holds the command registry
 //! and shared state for command execution.
 
-use crate::lisp_interpreter::{CommandRegistry, Value};
-use std::collections::HashMap;
+use crate::core::Config;
+use crate::lisp_interpreter::{CommandRegistry, ParseCache, Value};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 /// Version information for a single element to be versioned
@@ -18,6 +19,53 @@ pub struct VersionInfo {
   pub checksum: String,
 }
 
+/// Log level threshold used by `debug_log`
+/// Levels are ordered from quietest to most verbose: a message is printed
+/// when its level is at or below the configured threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+  Off,
+  Error,
+  Info,
+  Debug,
+}
+
+impl LogLevel {
+  /// Parse a level name (case-insensitive): off, error, info or debug
+  pub fn parse(name: &str) -> Option<Self> {
+    match name.to_lowercase().as_str() {
+      "off" => Some(LogLevel::Off),
+      "error" => Some(LogLevel::Error),
+      "info" => Some(LogLevel::Info),
+      "debug" => Some(LogLevel::Debug),
+      _ => None,
+    }
+  }
+
+  /// Lowercase name of the level, as accepted by `parse`
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      LogLevel::Off => "off",
+      LogLevel::Error => "error",
+      LogLevel::Info => "info",
+      LogLevel::Debug => "debug",
+    }
+  }
+
+  /// Whether a message at `message_level` passes this threshold
+  pub fn allows(&self, message_level: LogLevel) -> bool {
+    message_level != LogLevel::Off && message_level <= *self
+  }
+}
+
+/// Default nesting limit for `evaluate`, deep enough for any real script
+/// Each level costs about 2 KiB of stack in debug builds, well within the
+/// default 8 MiB main thread stack
+pub const DEFAULT_MAX_EVAL_DEPTH: usize = 1000;
+
+/// Placeholder printed instead of the value of a secret variable
+pub const MASKED_VALUE: &str = "****";
+
 /// Execution context for commands
 /// Contains the command registry and any shared state
 pub struct Context {
@@ -27,9 +75,32 @@ pub struct Context {
   pub variables: HashMap<String, Value>,
   /// Version information storage
   pub versions: HashMap<String, VersionInfo>,
-  /// Debug printing flag - fixed context variable
-  pub debug_print: bool,
+  /// Log level threshold - fixed context variable
+  pub log_level: LogLevel,
   pub basedir: PathBuf,
+  /// Canonical paths of the script files currently being sourced
+  /// Used by the `source` command to reject recursive includes
+  pub sourcing: HashSet<PathBuf>,
+  /// State of the xorshift random generator used by `random-int`
+  /// `None` until the generator is first used or seeded with `random-seed`
+  pub rng_state: Option<u64>,
+  /// Legacy runtime configuration (DOCKER_DEV_PATH, VERSIONS_FOLDER)
+  /// Read and updated by `config-get` and `config-set`
+  pub config: Config,
+  /// Number of nested expressions currently being evaluated
+  pub eval_depth: usize,
+  /// Nesting limit enforced by `evaluate`, set with `max-eval-depth`
+  pub max_eval_depth: usize,
+  /// Scripts already parsed by `evaluate_string`
+  pub parse_cache: ParseCache,
+  /// Name of the innermost command whose error is propagating
+  /// Cleared whenever a call succeeds and by `try` before it runs its body
+  pub error_command: Option<String>,
+  /// Variable maps saved by `env-snapshot`, indexed by snapshot id
+  pub snapshots: Vec<HashMap<String, Value>>,
+  /// Variables whose values are masked in verbose and debug output
+  /// Registered with `docker-env-secret`
+  pub secret_vars: HashSet<String>,
 }
 
 impl Context {
@@ -39,8 +110,17 @@ impl Context {
       registry,
       variables: HashMap::new(),
       versions: HashMap::new(),
-      debug_print: false,
+      log_level: LogLevel::Off,
       basedir: PathBuf::from("."),
+      sourcing: HashSet::new(),
+      rng_state: None,
+      config: Config::new(),
+      eval_depth: 0,
+      max_eval_depth: DEFAULT_MAX_EVAL_DEPTH,
+      parse_cache: ParseCache::new(),
+      error_command: None,
+      snapshots: Vec::new(),
+      secret_vars: HashSet::new(),
     }
   }
 
@@ -60,6 +140,23 @@ impl Context {
     self.variables.get(name).cloned()
   }
 
+  /// Remove a variable from the context
+  ///
+  /// # Returns
+  /// * `Option<Value>` - The removed value, or `None` if it was not set
+  pub fn remove_variable(&mut self, name: &str) -> Option<Value> {
+    self.variables.remove(name)
+  }
+
+  /// Check whether a variable (or `CTX:` attribute) is present
+  pub fn has_variable(&self, name: &str) -> bool {
+    if let Some(ctx_attr) = name.strip_prefix("CTX:") {
+      return self.get_context_attribute(ctx_attr).is_some();
+    }
+
+    self.variables.contains_key(name)
+  }
+
   /// Get context attributes by name
   ///
   /// # Arguments
@@ -70,13 +167,26 @@ impl Context {
   fn get_context_attribute(&self, attr_name: &str) -> Option<Value> {
     match attr_name {
       "basedir" => Some(Value::Str(self.basedir.to_string_lossy().to_string())),
-      "debug_print" => Some(Value::Bool(self.debug_print)),
+      "debug_print" => Some(Value::Bool(self.get_debug_print())),
+      "log_level" => Some(Value::Str(self.log_level.as_str().to_string())),
+      "max_eval_depth" => Some(Value::Int(self.max_eval_depth as i64)),
 
       // Aggiungi altri attributi del context qui
       _ => None,
     }
   }
 
+  /// Value of the variable `key` as it may be printed
+  /// Secret variables are replaced by `****`; every verbose or debug print
+  /// of a variable value goes through here
+  pub fn display_value(&self, key: &str, value: impl std::fmt::Display) -> String {
+    if self.secret_vars.contains(key) {
+      MASKED_VALUE.to_string()
+    } else {
+      value.to_string()
+    }
+  }
+
   /// Set version information in the context
   pub fn set_version(&mut self, key: String, version_info: VersionInfo) {
     self.versions.insert(key, version_info);
@@ -93,13 +203,24 @@ impl Context {
   }
 
   /// Set the debug print flag
+  /// Enabling it sets the log level to `Debug`, disabling it to `Off`
   pub fn set_debug_print(&mut self, enabled: bool) {
-    self.debug_print = enabled;
+    self.log_level = if enabled { LogLevel::Debug } else { LogLevel::Off };
   }
 
-  /// Get the debug print flag
+  /// Get the debug print flag (true when the log level is `Debug`)
   pub fn get_debug_print(&self) -> bool {
-    self.debug_print
+    self.log_level == LogLevel::Debug
+  }
+
+  /// Set the log level threshold
+  pub fn set_log_level(&mut self, level: LogLevel) {
+    self.log_level = level;
+  }
+
+  /// Get the log level threshold
+  pub fn get_log_level(&self) -> LogLevel {
+    self.log_level
   }
 
   /// Set the base directory
@@ -147,10 +268,16 @@ impl Context {
     // Print fixed context variables
     output.push_str("\n--- Fixed Context Variables ---\n");
     output.push_str(&format!("  debugPrint = {}\n", self.get_debug_print()));
+    output.push_str(&format!("  logLevel = {}\n", self.log_level.as_str()));
     output.push_str(&format!(
       "  basedir = {}\n",
       self.get_basedir().to_string_lossy()
     ));
+    output.push_str(&format!(
+      "  parseCache = {} hits, {} misses\n",
+      self.parse_cache.hits(),
+      self.parse_cache.misses()
+    ));
 
     // Print current variables
     output.push_str("\n--- Session Variables ---\n");
@@ -158,7 +285,7 @@ impl Context {
       output.push_str("  (no variables set)\n");
     } else {
       for (name, value) in &self.variables {
-        let rendered = if is_sensitive_key(name) {
+        let rendered = if is_sensitive_key(name) || self.secret_vars.contains(name) {
           masked()
         } else {
           value.to_string()
@@ -21,6 +21,17 @@ pub const DOCKER_SOCKET_SUFFIX: &str = "/docker.sock";
 pub const DOCKER_COMPOSE_ARGS: &[&str] =
   &["compose", "run", "--rm", "--no-deps", "-T"];
 pub const DOCKER_MAKE_ARGS: &[&str] = &["make", "make"];
+/// Arguments rejected by `docker` in strict mode unless allowed with `docker-allow`
+pub const DOCKER_DENIED_ARGS: &[&str] = &[
+  "-v",
+  "--volume",
+  "--mount",
+  "--privileged",
+  "--cap-add",
+  "--network=host",
+  "--net=host",
+  "--pid=host",
+];
 
 /// Environment variable names
 pub const ENV_DOCKER_HOST_MAP: &str = "DOCKER_HOST_MAP";
@@ -101,6 +112,8 @@ pub const MSG_EXECUTING_OPERATION: &str = "Executing operation: {}";
 pub const WARNING_DOCKER_HOST_MAP_IN_ENV: &str = "Warning: The 'DOCKER_HOST_MAP' variable is present in .env. It is recommended to move it to .env.local.";
 pub const WARNING_PROJECT_NAME_MISSING: &str =
   "ERROR: The 'PROJECT_NAME' variable is not present in .env.";
+pub const WARNING_ENV_VAR_DROPPED: &str =
+  "Warning: '{}' was dropped because it references the undefined variable '{}'.";
 
 /// Structure for dynamic runtime configuration
 #[derive(Debug, Clone)]
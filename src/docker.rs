@@ -1,9 +1,12 @@
 use std::collections::HashMap;
 use std::env;
+use std::fs;
+use std::path::Path;
 use std::process::Command;
 
 use crate::file_ops::{read_env_file, write_env_file};
 use crate::model::*;
+use crate::signals::status_tracked;
 use crate::utils::{get_home_directory, socket_exists};
 
 /// Esegue un comando Docker con le variabili d'ambiente e le configurazioni appropriate.
@@ -122,7 +125,7 @@ pub fn execute_docker_command(
   }
 
   // Esegue il comando Docker
-  let status = command.status()?;
+  let status = status_tracked(&mut command)?;
 
   if !status.success() {
     eprintln!("{}", MSG_DOCKER_COMMAND_FAILED);
@@ -134,6 +137,11 @@ pub fn execute_docker_command(
 
 /// Processes Docker version information for a specific component
 ///
+/// Reads the component version file (`<versions_folder>/<component>.properties`),
+/// compares the stored MD5 with `md5_value` and, when they differ, advances the
+/// PATCH number and writes the new version and hash back. A missing file is
+/// treated as version 0.0.0 with no hash, so the first run records 0.0.1.
+///
 /// # Arguments
 /// * `dir_path` - Path to the directory containing the Docker component
 /// * `md5_value` - MD5 hash value for the component
@@ -154,8 +162,101 @@ pub fn process_docker_version(
     println!("Versions folder: {}", versions_folder);
   }
 
-  // TODO: Implement actual version processing logic
-  // This is a stub implementation to fix the build issue
+  let component = Path::new(dir_path)
+    .file_name()
+    .and_then(|name| name.to_str())
+    .ok_or(ERROR_CANNOT_DETERMINE_DOCKER_DIR)?;
+
+  let version_file = Path::new(versions_folder).join(format!("{}.properties", component));
+  let version_file_str = version_file.to_string_lossy().to_string();
+
+  let mut versions = if version_file.exists() {
+    read_env_file(&version_file_str)?
+  } else {
+    HashMap::new()
+  };
+
+  if versions.get(VERSION_KEY_MD5).map(String::as_str) == Some(md5_value) {
+    println!("{}", MSG_VERSION_NO_UPDATE.replacen("{}", component, 1));
+    return Ok(());
+  }
+
+  let read_number = |key: &str| -> Result<u64, Box<dyn std::error::Error>> {
+    match versions.get(key) {
+      Some(value) => value
+        .parse::<u64>()
+        .map_err(|_| format!("Invalid {} value '{}' in {}", key, value, version_file_str).into()),
+      None => Ok(0),
+    }
+  };
+  let major = read_number(VERSION_KEY_MAJOR)?;
+  let minor = read_number(VERSION_KEY_MINOR)?;
+  let patch = read_number(VERSION_KEY_PATCH)? + 1;
+
+  versions.insert(VERSION_KEY_MD5.to_string(), md5_value.to_string());
+  versions.insert(VERSION_KEY_MAJOR.to_string(), major.to_string());
+  versions.insert(VERSION_KEY_MINOR.to_string(), minor.to_string());
+  versions.insert(VERSION_KEY_PATCH.to_string(), patch.to_string());
+  versions.insert(
+    VERSION_KEY_FULL.to_string(),
+    format!("{}.{}.{}", major, minor, patch),
+  );
+
+  fs::create_dir_all(versions_folder)?;
+  write_env_file(&version_file_str, &versions)?;
+
+  println!(
+    "{}",
+    MSG_VERSION_UPDATED
+      .replacen("{}", component, 1)
+      .replacen("{}", &major.to_string(), 1)
+      .replacen("{}", &minor.to_string(), 1)
+      .replacen("{}", &patch.to_string(), 1)
+  );
 
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn read_versions(versions_folder: &Path, component: &str) -> HashMap<String, String> {
+    let path = versions_folder.join(format!("{}.properties", component));
+    read_env_file(&path.to_string_lossy()).unwrap()
+  }
+
+  #[test]
+  fn test_process_docker_version_bumps_patch_on_change() {
+    let versions_folder = Path::new("target").join("test_process_docker_version");
+    let _ = fs::remove_dir_all(&versions_folder);
+    let folder = versions_folder.to_string_lossy().to_string();
+
+    // First run records the hash and version 0.0.1
+    process_docker_version("dev/docker/app", "aaaa1111", &folder, false).unwrap();
+    let versions = read_versions(&versions_folder, "app");
+    assert_eq!(versions.get(VERSION_KEY_MD5).unwrap(), "aaaa1111");
+    assert_eq!(versions.get(VERSION_KEY_FULL).unwrap(), "0.0.1");
+
+    // Unchanged hash keeps the version
+    process_docker_version("dev/docker/app", "aaaa1111", &folder, false).unwrap();
+    let versions = read_versions(&versions_folder, "app");
+    assert_eq!(versions.get(VERSION_KEY_PATCH).unwrap(), "1");
+
+    // Changed hash advances the patch, keeping major and minor
+    let mut edited = versions.clone();
+    edited.insert(VERSION_KEY_MAJOR.to_string(), "2".to_string());
+    edited.insert(VERSION_KEY_MINOR.to_string(), "3".to_string());
+    write_env_file(
+      &versions_folder.join("app.properties").to_string_lossy(),
+      &edited,
+    )
+    .unwrap();
+    process_docker_version("dev/docker/app", "bbbb2222", &folder, false).unwrap();
+    let versions = read_versions(&versions_folder, "app");
+    assert_eq!(versions.get(VERSION_KEY_MD5).unwrap(), "bbbb2222");
+    assert_eq!(versions.get(VERSION_KEY_FULL).unwrap(), "2.3.2");
+
+    let _ = fs::remove_dir_all(&versions_folder);
+  }
+}
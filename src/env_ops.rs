@@ -35,39 +35,117 @@ pub fn try_read_env_file(path: &str) -> io::Result<HashMap<String, String>> {
 /// - Le variabili d'ambiente sono nel formato ${NOME_VARIABILE}
 /// - Se una variabile d'ambiente non è definita, la coppia chiave-valore viene omessa
 /// - Solo le variabili con tutti i riferimenti risolti vengono incluse nel risultato
+/// - Usare `expand_env_vars_reporting` per sapere quali chiavi sono state omesse
 pub fn expand_env_vars(input: &HashMap<String, String>) -> HashMap<String, String> {
+  expand_env_vars_reporting(input).0
+}
+
+/// Espande le variabili d'ambiente come `expand_env_vars`, riportando anche
+/// le chiavi omesse.
+///
+/// # Arguments
+/// * `input` - HashMap contenente le variabili d'ambiente da espandere
+///
+/// # Returns
+/// * `(HashMap<String, String>, Vec<(String, String)>)` - HashMap con le
+///   variabili espanse e, ordinate, le coppie (chiave omessa, variabile
+///   d'ambiente non definita), una per ogni riferimento mancante
+pub fn expand_env_vars_reporting(
+  input: &HashMap<String, String>,
+) -> (HashMap<String, String>, Vec<(String, String)>) {
   let re = Regex::new(ENV_VAR_PATTERN).unwrap();
   let mut expanded_map = HashMap::new();
+  let mut missing = Vec::new();
 
   for (key, value) in input {
-    let mut has_missing_variable = false;
+    let mut missing_variables = Vec::new();
 
     let expanded_value = re.replace_all(value, |caps: &Captures| {
       let var_name = &caps[1];
       match env::var(var_name) {
         Ok(env_value) => env_value,
         Err(_) => {
-          has_missing_variable = true;
+          missing_variables.push(var_name.to_string());
           String::new() // Anche se non effettivamente utilizzato, necessario per il closure
         }
       }
     });
 
     // Solo se tutte le variabili trovate avevano valori validi, inserisco la variabile
-    if !has_missing_variable {
+    if missing_variables.is_empty() {
       expanded_map.insert(key.clone(), expanded_value.to_string());
+    } else {
+      // Altrimenti: variabile omessa secondo le specifiche, ma riportata al chiamante
+      for var_name in missing_variables {
+        missing.push((key.clone(), var_name));
+      }
     }
-    // Altrimenti: variabile omessa secondo le specifiche
   }
 
-  expanded_map
+  missing.sort();
+  (expanded_map, missing)
+}
+
+/// Espande le variabili d'ambiente stampando un avviso per ogni chiave omessa,
+/// a meno che `silent_missing` sia attivo (comportamento precedente).
+fn expand_env_vars_warning(
+  input: &HashMap<String, String>,
+  silent_missing: bool,
+) -> HashMap<String, String> {
+  let (expanded, missing) = expand_env_vars_reporting(input);
+  if !silent_missing {
+    for (key, var_name) in missing {
+      eprintln!(
+        "{}",
+        WARNING_ENV_VAR_DROPPED
+          .replacen("{}", &key, 1)
+          .replacen("{}", &var_name, 1)
+      );
+    }
+  }
+  expanded
+}
+
+/// Restituisce, nell'ordine dato, i nomi delle variabili richieste che non risultano definite.
+///
+/// # Arguments
+/// * `names` - Nomi delle variabili richieste
+/// * `is_set` - Indica se la variabile con il nome dato è definita
+///
+/// # Returns
+/// * `Vec<String>` - Nomi delle variabili mancanti
+pub fn missing_required_vars<'a>(
+  names: impl IntoIterator<Item = &'a str>,
+  is_set: impl Fn(&str) -> bool,
+) -> Vec<String> {
+  names
+    .into_iter()
+    .filter(|name| !is_set(name))
+    .map(|name| name.to_string())
+    .collect()
+}
+
+/// Verifica che `PROJECT_NAME` sia definito, avvisando su stderr e usando il valore di default se manca.
+///
+/// # Arguments
+/// * `env` - HashMap delle variabili d'ambiente da verificare
+fn ensure_project_name(env: &mut HashMap<String, String>) {
+  if !missing_required_vars([ENV_PROJECT_NAME], |name| env.contains_key(name)).is_empty() {
+    eprintln!("{}", WARNING_PROJECT_NAME_MISSING);
+    env.insert(ENV_PROJECT_NAME.to_string(), DEFAULT_PROJECT_NAME.to_string());
+  }
 }
 
-/// Combina le variabili d'ambiente da diversi file .env in un unico HashMap.
+/// Combina le variabili d'ambiente da diversi file .env, letti da `base_dir`, in un unico HashMap.
+///
+/// Per il comportamento storico (percorsi relativi alla directory corrente) passare `Path::new(".")`.
 ///
 /// # Arguments
+/// * `base_dir` - Directory da cui vengono risolti .env, .env.local e il file di input
 /// * `input_env_file` - Percorso del file .env di input specificato dall'utente
 /// * `verbose` - Flag per abilitare l'output verboso
+/// * `silent_missing` - Se attivo, le chiavi che riferiscono variabili non
+///   definite vengono omesse senza avviso
 ///
 /// # Returns
 /// * `io::Result<HashMap<String, String>>` - HashMap contenente tutte le variabili d'ambiente combinate
@@ -77,64 +155,69 @@ pub fn expand_env_vars(input: &HashMap<String, String>) -> HashMap<String, Strin
 /// - Legge variabili da .env.local (se presente), che sovrascrivono quelle di .env
 /// - Legge variabili dal file di input specificato (se diverso dai precedenti), che sovrascrivono le precedenti
 /// - Verifica la presenza di variabili obbligatorie e fornisce valori di default se necessario
-/// - Espande le variabili d'ambiente nei valori
+/// - Espande le variabili d'ambiente nei valori, avvisando per ogni chiave omessa
+/// - Messaggi e avvisi sono scritti su stderr
 pub fn combine_env_files(
+  base_dir: &Path,
   input_env_file: &str,
   verbose: bool,
+  silent_missing: bool,
 ) -> io::Result<HashMap<String, String>> {
   use crate::model::{MSG_COMBINING_ENV_FILES, MSG_READING_ENV_FILE, MSG_ENV_FILE_NOT_FOUND};
 
+  let env_path = base_dir.join(ENV_FILE);
+  let env_local_path = base_dir.join(ENV_LOCAL_FILE);
+  let input_path = base_dir.join(input_env_file);
+  let env_file = env_path.to_string_lossy();
+  let env_local_file = env_local_path.to_string_lossy();
+  let input_file = input_path.to_string_lossy();
+
   if verbose {
-    println!("{}", MSG_COMBINING_ENV_FILES);
+    eprintln!("{}", MSG_COMBINING_ENV_FILES);
   }
 
   // legge variabili da .env, se presente
   if verbose {
-    if Path::new(ENV_FILE).exists() {
-      println!("{}", MSG_READING_ENV_FILE.replace("{}", ENV_FILE));
+    if env_path.exists() {
+      eprintln!("{}", MSG_READING_ENV_FILE.replace("{}", &env_file));
     } else {
-      println!("{}", MSG_ENV_FILE_NOT_FOUND.replace("{}", ENV_FILE));
+      eprintln!("{}", MSG_ENV_FILE_NOT_FOUND.replace("{}", &env_file));
     }
   }
-  let mut combined_env = expand_env_vars(&try_read_env_file(ENV_FILE)?);
+  let mut combined_env = expand_env_vars_warning(&try_read_env_file(&env_file)?, silent_missing);
 
   // Controlla se il file .env contiene variabili che andrebbero da un'altra parte
   if combined_env.contains_key(ENV_DOCKER_HOST_MAP) {
-    println!("{}", WARNING_DOCKER_HOST_MAP_IN_ENV);
+    eprintln!("{}", WARNING_DOCKER_HOST_MAP_IN_ENV);
   }
 
-  if !combined_env.contains_key(ENV_PROJECT_NAME) {
-    println!("{}", WARNING_PROJECT_NAME_MISSING);
-    combined_env.insert(ENV_PROJECT_NAME.to_string(), DEFAULT_PROJECT_NAME.to_string());
-  }
+  ensure_project_name(&mut combined_env);
 
   // legge variabili da .env.local, se presente, sovrascrivendo quelle precedenti
-  if Path::new(ENV_LOCAL_FILE).exists() {
+  if env_local_path.exists() {
     if verbose {
-      println!("{}", MSG_READING_ENV_FILE.replace("{}", ENV_LOCAL_FILE));
+      eprintln!("{}", MSG_READING_ENV_FILE.replace("{}", &env_local_file));
     }
-    let local_env = expand_env_vars(&try_read_env_file(ENV_LOCAL_FILE)?);
+    let local_env = expand_env_vars_warning(&try_read_env_file(&env_local_file)?, silent_missing);
     for (k, v) in local_env {
       combined_env.insert(k, v);
     }
   } else if verbose {
-    println!("{}", MSG_ENV_FILE_NOT_FOUND.replace("{}", ENV_LOCAL_FILE));
+    eprintln!("{}", MSG_ENV_FILE_NOT_FOUND.replace("{}", &env_local_file));
   }
 
   // legge variabili dal file di input specificato, se presente e diverso da .env o .env.local
-  if input_env_file != ENV_FILE
-    && input_env_file != ENV_LOCAL_FILE
-    && Path::new(input_env_file).exists()
-  {
+  let is_layered_file = input_path == env_path || input_path == env_local_path;
+  if !is_layered_file && input_path.exists() {
     if verbose {
-      println!("{}", MSG_READING_ENV_FILE.replace("{}", input_env_file));
+      eprintln!("{}", MSG_READING_ENV_FILE.replace("{}", &input_file));
     }
-    let input_env = expand_env_vars(&try_read_env_file(input_env_file)?);
+    let input_env = expand_env_vars_warning(&try_read_env_file(&input_file)?, silent_missing);
     for (k, v) in input_env {
       combined_env.insert(k, v);
     }
-  } else if verbose && input_env_file != ENV_FILE && input_env_file != ENV_LOCAL_FILE {
-    println!("{}", MSG_ENV_FILE_NOT_FOUND.replace("{}", input_env_file));
+  } else if verbose && !is_layered_file {
+    eprintln!("{}", MSG_ENV_FILE_NOT_FOUND.replace("{}", &input_file));
   }
 
   Ok(combined_env)
@@ -230,3 +313,56 @@ pub fn create_dir_env_map_and_calculate_md5(
 
   Ok((dir_env_map, env_vars, md5_values))
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_expand_env_vars_reports_missing_variables() {
+    let mut input = HashMap::new();
+    input.insert("WITH_PATH".to_string(), "${PATH}:/extra".to_string());
+    input.insert("LITERAL".to_string(), "plain".to_string());
+    input.insert(
+      "BROKEN".to_string(),
+      "${DPM_TEST_UNDEFINED_B}/${DPM_TEST_UNDEFINED_A}".to_string(),
+    );
+
+    let (expanded, missing) = expand_env_vars_reporting(&input);
+    assert_eq!(expanded.get("LITERAL").unwrap(), "plain");
+    assert!(expanded.get("WITH_PATH").unwrap().ends_with(":/extra"));
+    assert!(!expanded.contains_key("BROKEN"));
+    assert_eq!(
+      missing,
+      vec![
+        ("BROKEN".to_string(), "DPM_TEST_UNDEFINED_A".to_string()),
+        ("BROKEN".to_string(), "DPM_TEST_UNDEFINED_B".to_string()),
+      ]
+    );
+
+    // The silent variant drops the same keys
+    assert_eq!(expand_env_vars(&input), expanded);
+  }
+
+  #[test]
+  fn test_combine_env_files_reads_from_base_dir() {
+    let base = std::env::temp_dir().join("dpm_test_combine_env_files");
+    let _ = std::fs::remove_dir_all(&base);
+    std::fs::create_dir_all(base.join("config")).unwrap();
+    std::fs::write(base.join(".env"), "PROJECT_NAME=tree\nLAYER=env\n").unwrap();
+    std::fs::write(base.join(".env.local"), "LAYER=local\n").unwrap();
+    std::fs::write(base.join("config/override.env"), "EXTRA=1\n").unwrap();
+
+    let combined = combine_env_files(&base, "config/override.env", false, true).unwrap();
+    assert_eq!(combined.get("PROJECT_NAME"), Some(&"tree".to_string()));
+    assert_eq!(combined.get("LAYER"), Some(&"local".to_string()));
+    assert_eq!(combined.get("EXTRA"), Some(&"1".to_string()));
+
+    // Naming a layered file as input does not apply it twice
+    let combined = combine_env_files(&base, ".env", false, true).unwrap();
+    assert_eq!(combined.get("LAYER"), Some(&"local".to_string()));
+    assert_eq!(combined.get("EXTRA"), None);
+
+    let _ = std::fs::remove_dir_all(&base);
+  }
+}
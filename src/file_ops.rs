@@ -25,6 +25,28 @@ use walkdir::WalkDir;
 ///   i primi 8 caratteri
 pub fn compute_dir_md5(
   dir: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+  let final_md5 = compute_dir_md5_full(dir)?;
+
+  // Prende i primi 8 caratteri
+  let md5_short = &final_md5[..8];
+
+  Ok(md5_short.to_string())
+}
+
+/// Calcola l'hash MD5 completo (32 caratteri esadecimali) di una directory.
+///
+/// Usa lo stesso algoritmo di `compute_dir_md5`, che ne restituisce il
+/// prefisso di 8 caratteri.
+///
+/// # Arguments
+/// * `dir` - Percorso della directory di cui calcolare l'hash MD5
+///
+/// # Returns
+/// * `Result<String, Box<dyn std::error::Error>>` - L'hash MD5 completo, o un
+///   errore se la directory non esiste o non è valida
+pub fn compute_dir_md5_full(
+  dir: &str,
 ) -> Result<String, Box<dyn std::error::Error>> {
   let path = Path::new(dir);
 
@@ -74,12 +96,81 @@ pub fn compute_dir_md5(
   let mut final_hasher = Md5::new();
   final_hasher.update(concatenated_md5s.as_bytes());
   let final_result = final_hasher.finalize();
-  let final_md5 = format!("{:x}", final_result);
+  Ok(format!("{:x}", final_result))
+}
 
-  // Prende i primi 8 caratteri
-  let md5_short = &final_md5[..8];
+/// Calcola l'hash MD5 completo (32 caratteri esadecimali) del contenuto di un file.
+///
+/// Il file viene letto a blocchi, senza caricarlo interamente in memoria.
+///
+/// # Arguments
+/// * `file` - Percorso del file di cui calcolare l'hash MD5
+///
+/// # Returns
+/// * `Result<String, Box<dyn std::error::Error>>` - L'hash MD5 del contenuto, o
+///   un errore se il percorso non è un file leggibile
+pub fn compute_file_md5(
+  file: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+  let path = Path::new(file);
+  if !path.is_file() {
+    return Err(format!("'{}' is not a file", file).into());
+  }
 
-  Ok(md5_short.to_string())
+  let mut reader = BufReader::new(File::open(path)?);
+  let mut hasher = Md5::new();
+  io::copy(&mut reader, &mut hasher)?;
+  Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Characters controlling how .env-style files are parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvParseOptions {
+  /// Lines starting with this character are comments
+  pub comment: char,
+  /// Separator between key and value
+  pub delimiter: char,
+}
+
+impl Default for EnvParseOptions {
+  fn default() -> Self {
+    Self {
+      comment: '#',
+      delimiter: '=',
+    }
+  }
+}
+
+impl EnvParseOptions {
+  /// Parse a single line into a key/value pair
+  ///
+  /// # Returns
+  /// * `Option<(String, String)>` - `None` for empty lines, comments, lines
+  ///   without the delimiter and lines with an empty key
+  pub fn parse_line(&self, line: &str) -> Option<(String, String)> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with(self.comment) {
+      return None;
+    }
+
+    let (key, value) = strip_export_prefix(trimmed).split_once(self.delimiter)?;
+    let key = key.trim();
+    if key.is_empty() {
+      return None;
+    }
+    Some((key.to_string(), unquote_env_value(value.trim())))
+  }
+}
+
+/// Strip a leading shell `export` keyword from an assignment line
+///
+/// `export FOO=bar` becomes `FOO=bar`; lines without the keyword, or with a
+/// key that merely starts with `export`, are returned unchanged.
+pub fn strip_export_prefix(line: &str) -> &str {
+  match line.strip_prefix("export") {
+    Some(rest) if rest.starts_with(char::is_whitespace) => rest.trim_start(),
+    _ => line,
+  }
 }
 
 /// Read environment variables from a .env file
@@ -90,31 +181,94 @@ pub fn compute_dir_md5(
 /// # Returns
 /// * `io::Result<HashMap<String, String>>` - HashMap containing the environment variables
 pub fn read_env_file(path: &str) -> io::Result<HashMap<String, String>> {
+  read_env_file_with_options(path, &EnvParseOptions::default())
+}
+
+/// Read environment variables from a file using custom comment and delimiter characters
+///
+/// # Arguments
+/// * `path` - Path to the file to read
+/// * `options` - Comment character and key/value delimiter
+///
+/// # Returns
+/// * `io::Result<HashMap<String, String>>` - HashMap containing the environment variables
+pub fn read_env_file_with_options(
+  path: &str,
+  options: &EnvParseOptions,
+) -> io::Result<HashMap<String, String>> {
   let file = File::open(path)?;
   let reader = BufReader::new(file);
   let mut env_vars = HashMap::new();
 
   for line in reader.lines() {
-    let line = line?;
-    let trimmed = line.trim();
-
-    // Skip empty lines and comments
-    if trimmed.is_empty() || trimmed.starts_with('#') {
-      continue;
+    if let Some((key, value)) = options.parse_line(&line?) {
+      env_vars.insert(key, value);
     }
+  }
 
-    // Parse key=value format
-    if let Some(eq_pos) = trimmed.find('=') {
-      let key = trimmed[..eq_pos].trim().to_string();
-      let value = trimmed[eq_pos + 1..].trim().to_string();
+  Ok(env_vars)
+}
 
-      if !key.is_empty() {
-        env_vars.insert(key, value);
-      }
+/// Returns `true` when a value must be quoted to survive a write/read cycle
+/// through a .env file.
+fn env_value_needs_quoting(value: &str) -> bool {
+  value.chars().any(|c| {
+    c.is_whitespace() || matches!(c, '#' | '=' | '"' | '\\')
+  })
+}
+
+/// Quote a value for a .env file if it contains characters that would
+/// otherwise break parsing (whitespace, `#`, `=`, quotes, backslashes or
+/// newlines).
+///
+/// Quoted values are wrapped in double quotes, with `\`, `"`, newlines,
+/// carriage returns and tabs escaped. Plain values are returned unchanged.
+pub fn quote_env_value(value: &str) -> String {
+  if !env_value_needs_quoting(value) {
+    return value.to_string();
+  }
+
+  let mut quoted = String::with_capacity(value.len() + 2);
+  quoted.push('"');
+  for c in value.chars() {
+    match c {
+      '\\' => quoted.push_str("\\\\"),
+      '"' => quoted.push_str("\\\""),
+      '\n' => quoted.push_str("\\n"),
+      '\r' => quoted.push_str("\\r"),
+      '\t' => quoted.push_str("\\t"),
+      _ => quoted.push(c),
     }
   }
+  quoted.push('"');
+  quoted
+}
 
-  Ok(env_vars)
+/// Reverse of [`quote_env_value`]: strips surrounding double quotes and
+/// resolves escape sequences. Unquoted values are returned unchanged.
+pub fn unquote_env_value(value: &str) -> String {
+  let inner = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"'))
+  {
+    Some(inner) => inner,
+    None => return value.to_string(),
+  };
+
+  let mut result = String::with_capacity(inner.len());
+  let mut chars = inner.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      result.push(c);
+      continue;
+    }
+    match chars.next() {
+      Some('n') => result.push('\n'),
+      Some('r') => result.push('\r'),
+      Some('t') => result.push('\t'),
+      Some(other) => result.push(other),
+      None => result.push('\\'),
+    }
+  }
+  result
 }
 
 /// Write environment variables to a .env file
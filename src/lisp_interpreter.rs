@@ -24,7 +24,9 @@
 //! ```
 
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
 
 use crate::context::Context;
@@ -72,7 +74,7 @@ impl Value {
               result.push(Value::from_lexpr(cons.car())?);
               current = cons.cdr();
             }
-            lexpr::Value::Nil => break,
+            lexpr::Value::Nil | lexpr::Value::Null => break,
             _ => {
               result.push(Value::from_lexpr(current)?);
               break;
@@ -103,6 +105,17 @@ impl Value {
     }
   }
 
+  /// Converts our Value to JSON: lists become arrays and nil becomes null
+  pub fn to_json(&self) -> serde_json::Value {
+    match self {
+      Value::Nil => serde_json::Value::Null,
+      Value::Int(i) => serde_json::Value::from(*i),
+      Value::Str(s) => serde_json::Value::from(s.as_str()),
+      Value::Bool(b) => serde_json::Value::Bool(*b),
+      Value::List(list) => serde_json::Value::Array(list.iter().map(|v| v.to_json()).collect()),
+    }
+  }
+
   /// Checks if the value is truthy (non-nil and non-zero)
   pub fn is_truthy(&self) -> bool {
     match self {
@@ -169,6 +182,11 @@ pub mod tags {
     order: 2,
     text: "Command Management",
   };
+  pub const MATH: Tag = Tag {
+    name: "math",
+    order: 1001,
+    text: "Math",
+  };
   pub const RUST: Tag = Tag {
     name: "rust",
     order: 9999,
@@ -215,6 +233,34 @@ pub trait Command: Send + Sync {
   fn tag(&self) -> &Tag {
     &tags::CORE
   }
+
+  /// Whether this command is a special form
+  /// Special forms receive their arguments unevaluated through `execute_form`
+  fn is_special_form(&self) -> bool {
+    false
+  }
+
+  /// Execute the command with unevaluated argument expressions
+  /// Called by `evaluate` for special forms; the default implementation
+  /// evaluates every argument in order and delegates to `execute`
+  ///
+  /// # Arguments
+  /// * `args` - Argument expressions as parsed
+  /// * `ctx` - Mutable reference to the execution context
+  ///
+  /// # Returns
+  /// * `Result<Value, String>` - The result value or an error message
+  fn execute_form(
+    &self,
+    args: Vec<lexpr::Value>,
+    ctx: &mut Context,
+  ) -> Result<Value, String> {
+    let mut values = Vec::new();
+    for arg in &args {
+      values.push(evaluate(arg, ctx)?);
+    }
+    self.execute(values, ctx)
+  }
 }
 
 /// Type alias for boxed commands
@@ -225,6 +271,8 @@ pub type BoxedCommand = Box<dyn Command>;
 #[derive(Clone)]
 pub struct CommandRegistry {
   commands: Arc<Mutex<HashMap<String, Arc<dyn Command>>>>,
+  /// Alias name -> name of the registered command it resolves to
+  aliases: Arc<Mutex<HashMap<String, String>>>,
 }
 
 impl CommandRegistry {
@@ -232,6 +280,7 @@ impl CommandRegistry {
   pub fn new() -> Self {
     Self {
       commands: Arc::new(Mutex::new(HashMap::new())),
+      aliases: Arc::new(Mutex::new(HashMap::new())),
     }
   }
 
@@ -429,7 +478,54 @@ impl CommandRegistry {
     });
   }
 
+  /// Register an alias for an existing command
+  ///
+  /// # Arguments
+  /// * `alias` - New name that resolves to the target command
+  /// * `target` - Name of a registered command (or of another alias)
+  ///
+  /// # Returns
+  /// * `Result<(), String>` - Error if the target does not exist or the alias
+  ///   would shadow a registered command
+  pub fn register_alias(&mut self, alias: &str, target: &str) -> Result<(), String> {
+    // Resolve aliases of aliases to the underlying command name
+    let resolved = self.resolve_alias(target);
+
+    if !self.commands.lock().unwrap().contains_key(&resolved) {
+      return Err(format!("Cannot alias unknown command '{}'", target));
+    }
+    if self.commands.lock().unwrap().contains_key(alias) {
+      return Err(format!("'{}' is already a registered command", alias));
+    }
+    if alias == resolved {
+      return Err(format!("'{}' cannot be an alias of itself", alias));
+    }
+
+    let mut aliases = self.aliases.lock().unwrap();
+    aliases.insert(alias.to_string(), resolved);
+    Ok(())
+  }
+
+  /// Resolve an alias to the name of the command it points to
+  /// Names that are not aliases are returned unchanged
+  pub fn resolve_alias(&self, name: &str) -> String {
+    let aliases = self.aliases.lock().unwrap();
+    aliases.get(name).cloned().unwrap_or_else(|| name.to_string())
+  }
+
+  /// Get all aliases as (alias, target) pairs, sorted by alias
+  pub fn get_aliases(&self) -> Vec<(String, String)> {
+    let aliases = self.aliases.lock().unwrap();
+    let mut result: Vec<(String, String)> = aliases
+      .iter()
+      .map(|(alias, target)| (alias.clone(), target.clone()))
+      .collect();
+    result.sort();
+    result
+  }
+
   /// Get a command by name
+  /// Aliases resolve to the command they point to
   ///
   /// # Arguments
   /// * `name` - Name of the command to retrieve
@@ -438,13 +534,23 @@ impl CommandRegistry {
   /// * `Option<Arc<dyn Command>>` - The command if found
   pub fn get(&self, name: &str) -> Option<Arc<dyn Command>> {
     let commands = self.commands.lock().unwrap();
-    commands.get(name).cloned()
+    if let Some(command) = commands.get(name) {
+      return Some(command.clone());
+    }
+    let aliases = self.aliases.lock().unwrap();
+    aliases.get(name).and_then(|target| commands.get(target).cloned())
   }
 
   /// List all registered command names
+  /// Aliases are included and marked as "alias -> target"
   pub fn list_commands(&self) -> Vec<String> {
     let commands = self.commands.lock().unwrap();
-    commands.keys().cloned().collect()
+    let mut names: Vec<String> = commands.keys().cloned().collect();
+    drop(commands);
+    for (alias, target) in self.get_aliases() {
+      names.push(format!("{} (alias of {})", alias, target));
+    }
+    names
   }
 
   /// Get all commands with their descriptions
@@ -544,7 +650,10 @@ impl CommandRegistry {
   }
 }
 
-/// Enhanced parsing function that handles multi-line expressions
+/// Parses every top-level S-expression in `input`, in order
+///
+/// Forms are read one at a time from a single lexpr parser, so expressions
+/// may span lines, share a line, or be separated by `;` comments.
 ///
 /// # Arguments
 /// * `input` - String containing S-expressions
@@ -552,121 +661,197 @@ impl CommandRegistry {
 /// # Returns
 /// * `Result<Vec<lexpr::Value>, String>` - Vector of parsed AST nodes or error
 pub fn parse_string(input: &str) -> Result<Vec<lexpr::Value>, String> {
+  let mut parser = lexpr::Parser::from_str(input);
   let mut results = Vec::new();
-  let trimmed = input.trim();
 
-  if trimmed.is_empty() {
-    return Ok(results);
+  loop {
+    match parser.next_value() {
+      Ok(Some(value)) => results.push(value),
+      Ok(None) => break,
+      Err(e) => {
+        return Err(format!(
+          "Parse error in expression #{}: {}",
+          results.len() + 1,
+          e
+        ));
+      }
+    }
   }
 
-  // Try simple parsing first
-  match lexpr::from_str(trimmed) {
-    Ok(value) => {
-      results.push(value);
-      return Ok(results);
-    }
-    Err(_) => {} // Continue with advanced parsing
+  Ok(results)
+}
+
+/// Normalize whitespace and parse multi-line expressions
+///
+/// # Arguments
+/// * `input` - String containing S-expressions with potential comments and multi-line formatting
+///
+/// # Returns
+/// * `Result<Vec<lexpr::Value>, String>` - Vector of parsed AST nodes or error
+pub fn parse_string_normalized(
+  input: &str,
+) -> Result<Vec<lexpr::Value>, String> {
+  let normalized = strip_comments(input)
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+  parse_string(&normalized)
+}
+
+/// Maximum number of scripts kept by `ParseCache`
+const PARSE_CACHE_CAPACITY: usize = 64;
+
+/// Cache of parsed scripts, keyed by the hash of their source text
+/// Lets `evaluate_string` skip parsing when the same text is evaluated again
+#[derive(Debug, Default)]
+pub struct ParseCache {
+  /// Source text and its AST, by hash of the text
+  /// The text is kept to tell hash collisions apart
+  entries: HashMap<u64, (String, Vec<lexpr::Value>)>,
+  hits: usize,
+  misses: usize,
+}
+
+impl ParseCache {
+  pub fn new() -> Self {
+    Self::default()
   }
 
-  // Advanced parsing for multi-line expressions
-  let mut chars = trimmed.chars().peekable();
-  let mut current_expr = String::new();
-  let mut paren_depth = 0;
-  let mut in_string = false;
-  let mut escape_next = false;
+  /// Parses `input` as `evaluate_string` does, reusing the previous result
+  /// for identical text; parse errors are not cached
+  pub fn parse(&mut self, input: &str) -> Result<Vec<lexpr::Value>, String> {
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    let key = hasher.finish();
+
+    if let Some((source, ast)) = self.entries.get(&key) {
+      if source == input {
+        self.hits += 1;
+        return Ok(ast.clone());
+      }
+    }
 
-  while let Some(ch) = chars.next() {
-    if escape_next {
-      current_expr.push(ch);
-      escape_next = false;
-      continue;
+    self.misses += 1;
+    let ast = parse_string_normalized(input).or_else(|_| parse_string(input))?;
+    if self.entries.len() >= PARSE_CACHE_CAPACITY {
+      self.entries.clear();
     }
+    self.entries.insert(key, (input.to_string(), ast.clone()));
+    Ok(ast)
+  }
 
-    match ch {
-      '\\' if in_string => {
-        current_expr.push(ch);
-        escape_next = true;
-      }
-      '"' => {
-        current_expr.push(ch);
-        in_string = !in_string;
-      }
-      '(' if !in_string => {
-        current_expr.push(ch);
-        paren_depth += 1;
+  /// Number of parses answered from the cache
+  pub fn hits(&self) -> usize {
+    self.hits
+  }
+
+  /// Number of parses that had to run the parser
+  pub fn misses(&self) -> usize {
+    self.misses
+  }
+}
+
+/// Iterator over the structural characters of S-expression source
+///
+/// Yields `(byte index, char)` for everything outside string literals,
+/// comments and `#\x` character literals, so delimiters inside them are
+/// never mistaken for code. Escapes inside strings (`\"`, `\\`) are
+/// honored. The `;` opening a comment is yielded; the comment is not.
+pub struct CodeChars<'a> {
+  chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+  in_string: bool,
+  in_comment: bool,
+}
+
+/// Structural characters of `input`, see `CodeChars`
+pub fn code_chars(input: &str) -> CodeChars<'_> {
+  CodeChars {
+    chars: input.char_indices().peekable(),
+    in_string: false,
+    in_comment: false,
+  }
+}
+
+impl Iterator for CodeChars<'_> {
+  type Item = (usize, char);
+
+  fn next(&mut self) -> Option<(usize, char)> {
+    loop {
+      let (index, ch) = self.chars.next()?;
+
+      if self.in_comment {
+        if ch == '\n' {
+          self.in_comment = false;
+        }
+        continue;
       }
-      ')' if !in_string => {
-        current_expr.push(ch);
-        paren_depth -= 1;
-
-        if paren_depth == 0 {
-          let expr = current_expr.trim();
-          if !expr.is_empty() {
-            match lexpr::from_str(expr) {
-              Ok(value) => results.push(value),
-              Err(e) => {
-                return Err(format!(
-                  "Parse error in expression '{}': {}",
-                  expr, e
-                ));
-              }
-            }
+
+      if self.in_string {
+        match ch {
+          // The escaped character, quote or backslash, is part of the string
+          '\\' => {
+            self.chars.next();
           }
-          current_expr.clear();
+          '"' => self.in_string = false,
+          _ => {}
         }
+        continue;
       }
-      _ => {
-        current_expr.push(ch);
+
+      match ch {
+        '"' => self.in_string = true,
+        ';' => {
+          self.in_comment = true;
+          return Some((index, ch));
+        }
+        // Character literal such as #\( or #\"
+        '#' if matches!(self.chars.peek(), Some((_, '\\'))) => {
+          self.chars.next();
+          self.chars.next();
+        }
+        _ => return Some((index, ch)),
       }
     }
   }
+}
 
-  // Handle remaining expression
-  let remaining = current_expr.trim();
-  if !remaining.is_empty() {
-    if paren_depth != 0 {
-      return Err(format!("Unbalanced parentheses: {}", remaining));
-    }
+/// Removes `;` comments from `input`, leaving `;` inside strings alone
+pub fn strip_comments(input: &str) -> String {
+  let mut result = String::with_capacity(input.len());
+  let mut copied = 0;
 
-    match lexpr::from_str(remaining) {
-      Ok(value) => results.push(value),
-      Err(e) => return Err(format!("Parse error: {}", e)),
+  for (index, ch) in code_chars(input) {
+    if ch == ';' {
+      result.push_str(&input[copied..index]);
+      // Resume at the end of the line; the newline itself is kept
+      copied = input[index..].find('\n').map_or(input.len(), |end| index + end);
     }
   }
-
-  if results.is_empty() {
-    return Err("No valid expressions found".to_string());
+  if copied < input.len() {
+    result.push_str(&input[copied..]);
   }
 
-  Ok(results)
+  result
 }
 
-/// Normalize whitespace and parse multi-line expressions
+/// Net parenthesis depth of `input`, ignoring parentheses inside string
+/// literals, comments and character literals
 ///
 /// # Arguments
-/// * `input` - String containing S-expressions with potential comments and multi-line formatting
+/// * `input` - Partial or complete S-expression source
 ///
 /// # Returns
-/// * `Result<Vec<lexpr::Value>, String>` - Vector of parsed AST nodes or error
-pub fn parse_string_normalized(
-  input: &str,
-) -> Result<Vec<lexpr::Value>, String> {
-  let normalized = input
-    .lines()
-    .map(|line| {
-      // Remove inline comments
-      let without_comment = if let Some(pos) = line.find(';') {
-        &line[..pos]
-      } else {
-        line
-      };
-      without_comment.trim()
-    })
-    .filter(|line| !line.is_empty())
-    .collect::<Vec<_>>()
-    .join(" ");
-
-  parse_string(&normalized)
+/// * `i64` - Zero when balanced, positive while expressions are still open,
+///   negative when there are more closing than opening parentheses
+pub fn paren_depth(input: &str) -> i64 {
+  code_chars(input).fold(0, |depth, (_, ch)| match ch {
+    '(' => depth + 1,
+    ')' => depth - 1,
+    _ => depth,
+  })
 }
 
 /// Format multi-line S-expression to single line
@@ -677,21 +862,61 @@ pub fn parse_string_normalized(
 /// # Returns
 /// * `String` - Formatted single-line S-expression
 pub fn format_sexpr(input: &str) -> String {
-  input
+  strip_comments(input)
     .lines()
-    .map(|line| {
-      let trimmed = if let Some(comment_pos) = line.find(';') {
-        line[..comment_pos].trim()
-      } else {
-        line.trim()
-      };
-      trimmed
-    })
+    .map(str::trim)
     .filter(|line| !line.is_empty())
     .collect::<Vec<_>>()
     .join(" ")
 }
 
+/// Levenshtein edit distance between two strings, counted in characters
+fn levenshtein(a: &str, b: &str) -> usize {
+  let b_chars: Vec<char> = b.chars().collect();
+  let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+
+  for (i, a_char) in a.chars().enumerate() {
+    let mut current = vec![i + 1];
+    for (j, b_char) in b_chars.iter().enumerate() {
+      let substitution = previous[j] + usize::from(a_char != *b_char);
+      let insertion = current[j] + 1;
+      let deletion = previous[j + 1] + 1;
+      current.push(substitution.min(insertion).min(deletion));
+    }
+    previous = current;
+  }
+
+  previous[b_chars.len()]
+}
+
+/// Build the error message for an unknown command
+/// Adds a "did you mean" hint when a registered command (or alias) is within
+/// a small edit distance of the requested name
+pub(crate) fn unknown_command_error(
+  registry: &CommandRegistry,
+  command_name: &str,
+) -> String {
+  // Allow roughly one typo every three characters (rounded up), capped at three
+  let threshold = command_name.chars().count().div_ceil(3).clamp(1, 3);
+
+  let suggestion = registry
+    .list_commands()
+    .iter()
+    // Aliases are listed as "alias (alias of target)": keep only the name
+    .filter_map(|entry| entry.split(' ').next())
+    .map(|name| (levenshtein(command_name, name), name.to_string()))
+    .filter(|(distance, _)| *distance <= threshold)
+    .min();
+
+  match suggestion {
+    Some((_, name)) => format!(
+      "Unknown command: {} (did you mean '{}'?)",
+      command_name, name
+    ),
+    None => format!("Unknown command: {}", command_name),
+  }
+}
+
 /// Evaluate a single AST node
 ///
 /// # Arguments
@@ -706,47 +931,26 @@ pub fn evaluate(
 ) -> Result<Value, String> {
   match ast {
     lexpr::Value::Cons(cons) => {
-      // This is a function call
-      let car = cons.car();
-      let command_name = match car {
-        lexpr::Value::Symbol(s) => s.to_string(),
-        _ => {
-          return Err(
-            "First element of list must be a command name".to_string(),
-          );
-        }
-      };
-
-      // Get the command from registry
-      let command = ctx
-        .registry
-        .get(&command_name)
-        .ok_or_else(|| format!("Unknown command: {}", command_name))?;
-
-      // Evaluate arguments
-      let mut args = Vec::new();
-      let mut current = cons.cdr();
-
-      loop {
-        match current {
-          lexpr::Value::Cons(cons) => {
-            let arg_value = evaluate(cons.car(), ctx)?;
-            args.push(arg_value);
-            current = cons.cdr();
-          }
-          lexpr::Value::Nil | lexpr::Value::Null => {
-            break;
-          }
-          _ => {
-            let arg_value = evaluate(current, ctx)?;
-            args.push(arg_value);
-            break;
-          }
+      // Fail cleanly instead of overflowing the stack
+      if ctx.eval_depth >= ctx.max_eval_depth {
+        return Err(format!(
+          "maximum evaluation depth exceeded ({})",
+          ctx.max_eval_depth
+        ));
+      }
+      ctx.eval_depth += 1;
+      let result = evaluate_call(cons, ctx);
+      ctx.eval_depth -= 1;
+      // Calls enclosing the failing one see the error later and keep its name;
+      // a call that succeeds has recovered from any error raised inside it
+      match &result {
+        Ok(_) => ctx.error_command = None,
+        Err(_) if ctx.error_command.is_none() => {
+          ctx.error_command = cons.car().as_symbol().map(str::to_string);
         }
+        Err(_) => {}
       }
-
-      // Execute the command
-      command.execute(args, ctx)
+      result
     }
     _ => {
       // This is a literal value
@@ -755,6 +959,75 @@ pub fn evaluate(
   }
 }
 
+/// Evaluates a function call: special forms receive their argument forms
+/// unevaluated, regular commands their evaluated arguments
+fn evaluate_call(
+  cons: &lexpr::Cons,
+  ctx: &mut Context,
+) -> Result<Value, String> {
+  // This is a function call
+  let car = cons.car();
+  let command_name = match car {
+    lexpr::Value::Symbol(s) => s.to_string(),
+    _ => {
+      return Err(
+        "First element of list must be a command name".to_string(),
+      );
+    }
+  };
+
+  // Get the command from registry
+  let command = ctx
+    .registry
+    .get(&command_name)
+    .ok_or_else(|| unknown_command_error(&ctx.registry, &command_name))?;
+
+  // Special forms decide themselves which arguments to evaluate
+  if command.is_special_form() {
+    let mut forms = Vec::new();
+    let mut current = cons.cdr();
+    loop {
+      match current {
+        lexpr::Value::Cons(cons) => {
+          forms.push(cons.car().clone());
+          current = cons.cdr();
+        }
+        lexpr::Value::Nil | lexpr::Value::Null => break,
+        _ => {
+          forms.push(current.clone());
+          break;
+        }
+      }
+    }
+    return command.execute_form(forms, ctx);
+  }
+
+  // Evaluate arguments
+  let mut args = Vec::new();
+  let mut current = cons.cdr();
+
+  loop {
+    match current {
+      lexpr::Value::Cons(cons) => {
+        let arg_value = evaluate(cons.car(), ctx)?;
+        args.push(arg_value);
+        current = cons.cdr();
+      }
+      lexpr::Value::Nil | lexpr::Value::Null => {
+        break;
+      }
+      _ => {
+        let arg_value = evaluate(current, ctx)?;
+        args.push(arg_value);
+        break;
+      }
+    }
+  }
+
+  // Execute the command
+  command.execute(args, ctx)
+}
+
 /// Evaluate a string containing S-expressions
 ///
 /// # Arguments
@@ -767,8 +1040,7 @@ pub fn evaluate_string(
   input: &str,
   ctx: &mut Context,
 ) -> Result<Value, String> {
-  let ast_nodes =
-    parse_string_normalized(input).or_else(|_| parse_string(input))?;
+  let ast_nodes = ctx.parse_cache.parse(input)?;
   let mut last_result = Value::Nil;
 
   for ast in ast_nodes {
@@ -844,7 +1116,9 @@ pub fn value_to_bool(value: &Value) -> Result<bool, String> {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::commands::{DebugCommand, PipeCommand, PrintCommand, SumCommand};
+  use crate::context::{DEFAULT_MAX_EVAL_DEPTH, LogLevel};
+  use crate::commands::{DebugCommand, PipeCommand, PrintCommand};
+  use crate::commands::core::math::SumCommand;
   use crate::commands::{register_help_commands, register_list_commands};
 
   /// Test helper function to register builtin commands for testing
@@ -976,6 +1250,81 @@ mod tests {
     assert!(error_result.unwrap_err().contains("exactly one argument"));
   }
 
+  #[test]
+  fn test_debug_command_log_levels() {
+    let mut registry = CommandRegistry::new();
+    register_test_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+    assert_eq!(ctx.get_log_level(), LogLevel::Off);
+
+    let result = evaluate_string("(debug \"info\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("Log level set to: info".to_string()));
+    assert_eq!(ctx.get_log_level(), LogLevel::Info);
+    assert!(!ctx.get_debug_print());
+    assert_eq!(
+      ctx.get_variable("CTX:log_level"),
+      Some(Value::Str("info".to_string()))
+    );
+
+    let result = evaluate_string("(debug \"ERROR\")", &mut ctx).unwrap();
+    assert_eq!(result, Value::Str("Log level set to: error".to_string()));
+    assert_eq!(ctx.get_log_level(), LogLevel::Error);
+
+    // true/false map onto the Debug and Off levels
+    evaluate_string("(debug \"true\")", &mut ctx).unwrap();
+    assert_eq!(ctx.get_log_level(), LogLevel::Debug);
+    evaluate_string("(debug \"false\")", &mut ctx).unwrap();
+    assert_eq!(ctx.get_log_level(), LogLevel::Off);
+
+    let result = evaluate_string("(debug)", &mut ctx).unwrap();
+    assert!(result.to_string().contains("logLevel = off"));
+  }
+
+  #[test]
+  fn test_log_level_filtering() {
+    // Off prints nothing, not even errors
+    assert!(!LogLevel::Off.allows(LogLevel::Error));
+    assert!(!LogLevel::Off.allows(LogLevel::Debug));
+
+    assert!(LogLevel::Error.allows(LogLevel::Error));
+    assert!(!LogLevel::Error.allows(LogLevel::Info));
+    assert!(!LogLevel::Error.allows(LogLevel::Debug));
+
+    assert!(LogLevel::Info.allows(LogLevel::Error));
+    assert!(LogLevel::Info.allows(LogLevel::Info));
+    assert!(!LogLevel::Info.allows(LogLevel::Debug));
+
+    assert!(LogLevel::Debug.allows(LogLevel::Error));
+    assert!(LogLevel::Debug.allows(LogLevel::Info));
+    assert!(LogLevel::Debug.allows(LogLevel::Debug));
+
+    // A message tagged Off is never printed
+    assert!(!LogLevel::Debug.allows(LogLevel::Off));
+
+    assert_eq!(LogLevel::parse("Info"), Some(LogLevel::Info));
+    assert_eq!(LogLevel::parse("verbose"), None);
+  }
+
+  #[test]
+  fn test_unknown_command_suggestion() {
+    let mut registry = CommandRegistry::new();
+    register_test_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let err = evaluate_string("(pirnt \"x\")", &mut ctx).unwrap_err();
+    assert_eq!(err, "Unknown command: pirnt (did you mean 'print'?)");
+
+    let err = evaluate_string("(debu)", &mut ctx).unwrap_err();
+    assert_eq!(err, "Unknown command: debu (did you mean 'debug'?)");
+
+    let err = evaluate_string("(zzzzzzzz)", &mut ctx).unwrap_err();
+    assert_eq!(err, "Unknown command: zzzzzzzz");
+
+    assert_eq!(levenshtein("docker-socke", "docker-socket"), 1);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+  }
+
   #[test]
   fn test_multiline_parsing_issue() {
     // Test case from the issue description - this should fail with current implementation
@@ -1011,4 +1360,135 @@ mod tests {
       "lexpr should work with normalized input"
     );
   }
+
+  #[test]
+  fn test_paren_depth() {
+    assert_eq!(paren_depth("(sum 1 2)"), 0);
+    assert_eq!(paren_depth("(print\n  (sum 1"), 2);
+    assert_eq!(paren_depth("(print \"(\" \"\\\")\""), 1);
+    assert_eq!(paren_depth("(sum 1 2))"), -1);
+  }
+
+  #[test]
+  fn test_scanner_ignores_delimiters_in_literals() {
+    // String containing ")("
+    assert_eq!(paren_depth(r#"(print ")(")"#), 0);
+    // String ending in an escaped backslash: the quote after it closes the string
+    assert_eq!(paren_depth(r#"(print "C:\\") (print "(")"#), 0);
+    assert_eq!(paren_depth(r#"(print "C:\\" (sum 1"#), 2);
+    // Escaped quotes inside strings
+    assert_eq!(paren_depth(r#"(print "say \"(hi\"" (concat "\")" "x"))"#), 0);
+    // Character literals and comments
+    assert_eq!(paren_depth("(list #\\( #\\\" 1) ; (unclosed"), 0);
+
+    assert_eq!(strip_comments("(print \"a;b\") ; note\n(sum 1) ;x"), "(print \"a;b\") \n(sum 1) ");
+  }
+
+  #[test]
+  fn test_semicolon_in_string_survives_normalization() {
+    let mut registry = CommandRegistry::new();
+    register_test_commands(&mut registry);
+    crate::commands::core::vars::register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let script = "(set-var \"a\" \"x;y\") ; comment\n(set-var \"b\" \"z\")";
+    evaluate_string(script, &mut ctx).unwrap();
+    assert_eq!(ctx.get_variable("a"), Some(Value::Str("x;y".to_string())));
+    assert_eq!(ctx.get_variable("b"), Some(Value::Str("z".to_string())));
+  }
+
+  #[test]
+  fn test_evaluation_depth_limit() {
+    // Each level takes about 2 KiB of stack in debug builds: give the test
+    // the same 8 MiB stack the main thread gets instead of the 2 MiB default
+    let handle = std::thread::Builder::new()
+      .stack_size(8 * 1024 * 1024)
+      .spawn(|| {
+        let mut registry = CommandRegistry::new();
+        register_test_commands(&mut registry);
+        // Calls itself forever, like a recursive function without a base case
+        registry.register_closure("recurse", "Evaluate (recurse) again", |_args, ctx| {
+          evaluate_string("(recurse)", ctx)
+        });
+        let mut ctx = Context::new(registry);
+
+        let err = evaluate_string("(recurse)", &mut ctx).unwrap_err();
+        assert_eq!(err, format!("maximum evaluation depth exceeded ({})", DEFAULT_MAX_EVAL_DEPTH));
+        // The depth is unwound after an error
+        assert_eq!(ctx.eval_depth, 0);
+
+        let nested = |depth: usize| format!("{}1{}", "(sum ".repeat(depth), ")".repeat(depth));
+        ctx.max_eval_depth = 10;
+        assert_eq!(evaluate_string(&nested(10), &mut ctx), Ok(Value::Int(1)));
+        let err = evaluate_string(&nested(11), &mut ctx).unwrap_err();
+        assert!(err.contains("maximum evaluation depth exceeded (10)"), "{}", err);
+
+        // Pathologically nested input is rejected by the parser before evaluation
+        assert!(evaluate_string(&nested(100_000), &mut ctx).is_err());
+      })
+      .unwrap();
+    handle.join().unwrap();
+  }
+
+  #[test]
+  fn test_parse_cache_reuses_identical_text() {
+    let mut registry = CommandRegistry::new();
+    register_test_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let script = "; totals\n(sum 1 2)\n(sum 3\n  4)";
+    assert_eq!(evaluate_string(script, &mut ctx), Ok(Value::Int(7)));
+    assert_eq!((ctx.parse_cache.hits(), ctx.parse_cache.misses()), (0, 1));
+
+    // Second run of the same text skips the parser
+    assert_eq!(evaluate_string(script, &mut ctx), Ok(Value::Int(7)));
+    assert_eq!((ctx.parse_cache.hits(), ctx.parse_cache.misses()), (1, 1));
+
+    // Changed text is parsed again
+    let changed = script.replace('4', "5");
+    assert_eq!(evaluate_string(&changed, &mut ctx), Ok(Value::Int(8)));
+    assert_eq!((ctx.parse_cache.hits(), ctx.parse_cache.misses()), (1, 2));
+
+    // Parse errors are not cached
+    assert!(evaluate_string("(sum 1", &mut ctx).is_err());
+    assert!(evaluate_string("(sum 1", &mut ctx).is_err());
+    assert_eq!(ctx.parse_cache.misses(), 4);
+  }
+
+  #[test]
+  fn test_parse_cache_capacity() {
+    let mut cache = ParseCache::new();
+    for i in 0..PARSE_CACHE_CAPACITY + 1 {
+      cache.parse(&format!("(sum {})", i)).unwrap();
+    }
+    assert!(cache.entries.len() <= PARSE_CACHE_CAPACITY);
+    cache.parse(&format!("(sum {})", PARSE_CACHE_CAPACITY)).unwrap();
+    assert_eq!(cache.hits(), 1);
+  }
+
+  #[test]
+  fn test_parse_string_returns_every_top_level_form() {
+    let forms = parse_string("(sum 1 2) 5 \"text\"\n(print\n  \")\") ; trailing comment\n").unwrap();
+    assert_eq!(forms.len(), 4);
+    assert_eq!(parse_string("  \n; only a comment\n").unwrap(), Vec::<lexpr::Value>::new());
+
+    let err = parse_string("(sum 1 2) (sum 3").unwrap_err();
+    assert!(err.starts_with("Parse error in expression #2"), "{}", err);
+    assert!(parse_string("(sum 1))").is_err());
+  }
+
+  #[test]
+  fn test_three_top_level_forms_all_evaluate() {
+    let mut registry = CommandRegistry::new();
+    register_test_commands(&mut registry);
+    crate::commands::core::vars::register_var_commands(&mut registry);
+    let mut ctx = Context::new(registry);
+
+    let script = "(set-var \"a\" \"1\") (set-var \"b\" \"2\")\n(set-var \"c\" \"3\")";
+    assert_eq!(parse_string(script).unwrap().len(), 3);
+    evaluate_string(script, &mut ctx).unwrap();
+    for name in ["a", "b", "c"] {
+      assert!(ctx.has_variable(name), "{} was not set", name);
+    }
+  }
 }
@@ -1,5 +1,6 @@
 use std::env;
 use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
 
 mod commands;
 mod config_commands;
@@ -12,12 +13,20 @@ mod env_ops;
 mod file_ops;
 mod lisp_interpreter;
 mod model;
+mod signals;
 mod utils;
 
 use commands::{
-  ConcatCommand, DebugCommand, MultiplyCommand, PipeCommand, PrintCommand,
-  SumCommand, register_all_rust_commands, register_app_commands,
-  register_basedir_commands, register_help_commands, register_list_commands,
+  AndCommand, ApplyCommand, AssertCommand, AssertEqCommand, CaseCommand,
+  ConcatCommand, CondCommand, DebugCommand, EvalCommand, NotCommand, OrCommand,
+  PipeCommand, PpCommand, PrintCommand, PrintRawCommand, QuoteCommand,
+  RetryCommand, TeeCommand, TryCommand, format_help_json, format_help_long,
+  format_help_short, register_alias_commands, register_all_rust_commands,
+  register_app_commands, register_basedir_commands, register_coercion_commands,
+  register_config_commands, register_hash_commands, register_help_commands,
+  register_limit_commands, register_list_commands, register_math_commands,
+  register_random_commands, register_string_commands, register_time_commands,
+  register_type_commands,
 };
 use context::Context;
 use lisp_interpreter::*;
@@ -29,18 +38,61 @@ use lisp_interpreter::*;
 fn register_builtin_commands(registry: &mut CommandRegistry) {
   // Register struct-based commands
   registry.register(PrintCommand);
-  registry.register(SumCommand);
+  registry.register(PrintRawCommand);
+  registry.register(PpCommand);
   registry.register(PipeCommand);
-  registry.register(MultiplyCommand);
+  registry.register(TeeCommand);
   registry.register(ConcatCommand);
   registry.register(DebugCommand);
+  registry.register(AndCommand);
+  registry.register(OrCommand);
+  registry.register(NotCommand);
+  registry.register(CondCommand);
+  registry.register(CaseCommand);
+  registry.register(TryCommand);
+  registry.register(RetryCommand);
+  registry.register(QuoteCommand);
+  registry.register(EvalCommand);
+  registry.register(ApplyCommand);
+  registry.register(AssertCommand);
+  registry.register(AssertEqCommand);
+
+  // Register arithmetic commands (sum, multiply, subtract, ...)
+  register_math_commands(registry);
 
   // Register list utility commands
   register_list_commands(registry);
 
+  // Register value type-predicate commands
+  register_type_commands(registry);
+
+  // Register type-coercion commands
+  register_coercion_commands(registry);
+
+  // Register string manipulation commands
+  register_string_commands(registry);
+
+  // Register string hashing commands
+  register_hash_commands(registry);
+
+  // Register time commands
+  register_time_commands(registry);
+
+  // Register random number commands
+  register_random_commands(registry);
+
   // Register help commands
   register_help_commands(registry);
 
+  // Register command aliasing
+  register_alias_commands(registry);
+
+  // Register interpreter limit commands
+  register_limit_commands(registry);
+
+  // Register legacy configuration commands
+  register_config_commands(registry);
+
   // Register basedir commands
   register_basedir_commands(registry);
 
@@ -51,13 +103,41 @@ fn register_builtin_commands(registry: &mut CommandRegistry) {
   register_all_rust_commands(registry);
 }
 
+/// Writes the final value of a script to `path` (`-` for stdout)
+///
+/// # Arguments
+/// * `value` - Value of the last evaluated expression
+/// * `path` - Output file, or `-` for stdout
+/// * `json` - Serialize the value as JSON instead of its string form
+fn write_output(value: &Value, path: &Path, json: bool) -> io::Result<()> {
+  let text = if json {
+    value.to_json().to_string()
+  } else {
+    value.to_string()
+  };
+
+  if path.as_os_str() == "-" {
+    println!("{}", text);
+    Ok(())
+  } else {
+    std::fs::write(path, format!("{}\n", text))
+  }
+}
+
+fn usage() -> &'static str {
+  "Usage:\n  --pipe                 Read commands from standard input (pipe)\n  --command <string>     Execute the provided command string\n  --file <path>          Read command(s) from the specified file\n  --dump-commands        Print the detailed command reference and exit\n  --dump-commands-json   Print the command catalog as JSON and exit\n  --version              Print the dpm version and exit\n  --help                 Print this usage and a command overview and exit\n\nOutput options (before --command or --file):\n  --output <file>        Write the value of the last expression to <file> (- for stdout)\n  --output-json          Write that value as JSON\n\nExamples:\n  echo \"(print \"Hello\")\" | dpm --pipe\n  dpm --command \"(print \"Hello\")\"\n  dpm --file script.lisp\n  dpm --output - --command \"(sum 1 2)\""
+}
+
 fn print_usage() {
-  println!(
-    "Usage:\n  --pipe                 Read commands from standard input (pipe)\n  --command <string>     Execute the provided command string\n  --file <path>          Read command(s) from the specified file\n\nExamples:\n  echo \"(print \"Hello\")\" | dpm --pipe\n  dpm --command \"(print \"Hello\")\"\n  dpm --file script.lisp"
-  );
+  eprintln!("{}", usage());
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+  // Kill the running child process, if any, when interrupted with Ctrl-C
+  if let Err(e) = signals::install_interrupt_handler() {
+    eprintln!("Warning: {}", e);
+  }
+
   // Step 1: Create command registry and register built-in commands
   let mut registry = CommandRegistry::new();
   register_builtin_commands(&mut registry);
@@ -66,7 +146,41 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
   let mut context = Context::new(registry);
 
   // Step 3: Get command line arguments
-  let args: Vec<String> = env::args().skip(1).collect();
+  let mut args: Vec<String> = env::args().skip(1).collect();
+
+  // Leading output options apply to --command and --file
+  let mut output: Option<PathBuf> = None;
+  let mut output_json = false;
+  while let Some(option) = args.first() {
+    match option.as_str() {
+      "--output" => {
+        if args.len() < 2 {
+          eprintln!("Error: --output requires a file path (- for stdout).\n");
+          print_usage();
+          return Err("missing --output argument".into());
+        }
+        // Resolved now: the script may change the current directory
+        output = Some(match args[1].as_str() {
+          "-" => PathBuf::from("-"),
+          path => std::path::absolute(path)?,
+        });
+        args.drain(..2);
+      }
+      "--output-json" => {
+        output_json = true;
+        args.remove(0);
+      }
+      _ => break,
+    }
+  }
+  if output_json && output.is_none() {
+    eprintln!("Error: --output-json requires --output.");
+    std::process::exit(2);
+  }
+  if output.is_some() && !matches!(args.first().map(String::as_str), Some("--command" | "--file")) {
+    eprintln!("Error: --output can only be used with --command or --file.");
+    std::process::exit(2);
+  }
 
   if args.is_empty() {
     // No arguments: show usage and exit
@@ -77,68 +191,99 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
   match args[0].as_str() {
     "--pipe" => {
-      // Read from stdin
+      // Read from stdin, accumulating lines until the parentheses balance
       let stdin = io::stdin();
       let reader = BufReader::new(stdin.lock());
+      let mut pending = String::new();
       for line in reader.lines() {
         match line {
           Ok(input) => {
-            let trimmed = input.trim();
-            if trimmed.is_empty() {
+            if pending.is_empty() && input.trim().is_empty() {
               continue; // Skip empty lines
             }
-            match evaluate_string(trimmed, &mut context) {
+            pending.push_str(&input);
+            pending.push('\n');
+            if paren_depth(&pending) > 0 {
+              continue; // Expression continues on the next line
+            }
+            match evaluate_string(pending.trim(), &mut context) {
               Ok(_) => {}
               Err(e) => {
-                println!("Error: {}", e);
+                eprintln!("Error: {}", e);
                 // Continue processing other lines instead of exiting
               }
             }
+            pending.clear();
           }
           Err(e) => {
-            println!("Error reading from stdin: {}", e);
+            eprintln!("Error reading from stdin: {}", e);
             return Err(e.into());
           }
         }
       }
+      if !pending.trim().is_empty() {
+        let e = format!("unbalanced parentheses at end of input: {}", pending.trim());
+        eprintln!("Error: {}", e);
+        return Err(e.into());
+      }
     }
     "--command" => {
       if args.len() < 2 {
-        println!("Error: --command requires a command string.\n");
+        eprintln!("Error: --command requires a command string.\n");
         print_usage();
         return Err("missing --command argument".into());
       }
       // Join remaining args to support spaces without quoting across some shells
       let cmd = args[1..].join(" ");
       match evaluate_string(&cmd, &mut context) {
-        Ok(_) => {}
+        Ok(value) => {
+          if let Some(path) = &output {
+            write_output(&value, path, output_json)?;
+          }
+        }
         Err(e) => {
-          println!("Error: {}\n", e);
+          eprintln!("Error: {}\n", e);
           return Err(e.into());
         }
       }
     }
     "--file" => {
       if args.len() < 2 {
-        println!("Error: --file requires a path to a file.\n");
+        eprintln!("Error: --file requires a path to a file.\n");
         print_usage();
         return Err("missing --file argument".into());
       }
       let path = &args[1];
       let content = std::fs::read_to_string(path)?;
       match evaluate_string(&content, &mut context) {
-        Ok(_) => {}
+        Ok(value) => {
+          if let Some(path) = &output {
+            write_output(&value, path, output_json)?;
+          }
+        }
         Err(e) => {
-          println!("Error: {}\n", e);
+          eprintln!("Error: {}\n", e);
           return Err(e.into());
         }
       }
     }
+    "--dump-commands" => {
+      println!("{}", format_help_long(&context.registry));
+    }
+    "--dump-commands-json" => {
+      println!("{}", format_help_json(&context.registry)?);
+    }
+    "--version" => {
+      println!("dpm {}", env!("CARGO_PKG_VERSION"));
+    }
+    "--help" => {
+      println!("{}\n\n{}", usage(), format_help_short(&context.registry));
+    }
     _ => {
       // Unknown option: show usage
       print_usage();
       eprintln!(
-        "Error: unknown option '{}'. Use --pipe, --command or --file.",
+        "Error: unknown option '{}'. Use --pipe, --command, --file, --dump-commands, --dump-commands-json, --version or --help.",
         args[0]
       );
       std::process::exit(2);
@@ -0,0 +1,95 @@
+//! Ctrl-C handling
+//!
+//! Child processes are waited on synchronously, so an interrupted dpm would
+//! otherwise leave them running. Every wait goes through a `ChildGuard`,
+//! which records the pid of the running child; the Ctrl-C handler thread
+//! kills that child before exiting.
+
+use std::process::{Child, Command, ExitStatus};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// Exit code used when dpm is interrupted (128 + SIGINT)
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Pid of the child currently being waited on, 0 when there is none
+static CURRENT_CHILD: AtomicU32 = AtomicU32::new(0);
+
+static HANDLER_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Set once Ctrl-C was received and the process is about to exit
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Tracks a running child for the interrupt handler while in scope
+pub struct ChildGuard {
+  previous: u32,
+}
+
+impl ChildGuard {
+  pub fn new(child: &Child) -> Self {
+    ChildGuard {
+      previous: CURRENT_CHILD.swap(child.id(), Ordering::SeqCst),
+    }
+  }
+}
+
+impl Drop for ChildGuard {
+  fn drop(&mut self) {
+    CURRENT_CHILD.store(self.previous, Ordering::SeqCst);
+    // The child was killed by the handler: wait for it to exit the process
+    // instead of carrying on as if the child had just failed
+    while INTERRUPTED.load(Ordering::SeqCst) {
+      std::thread::park();
+    }
+  }
+}
+
+/// Spawns `cmd` and waits for it, keeping it tracked meanwhile
+/// Drop-in replacement for `Command::status`
+pub fn status_tracked(cmd: &mut Command) -> std::io::Result<ExitStatus> {
+  let mut child = cmd.spawn()?;
+  let _guard = ChildGuard::new(&child);
+  child.wait()
+}
+
+/// Installs the Ctrl-C handler; calling it again is a no-op
+pub fn install_interrupt_handler() -> Result<(), String> {
+  if HANDLER_INSTALLED.swap(true, Ordering::SeqCst) {
+    return Ok(());
+  }
+  ctrlc::set_handler(|| {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+    let pid = CURRENT_CHILD.load(Ordering::SeqCst);
+    if pid != 0 {
+      kill_process(pid);
+    }
+    std::process::exit(INTERRUPTED_EXIT_CODE);
+  })
+  .map_err(|e| {
+    HANDLER_INSTALLED.store(false, Ordering::SeqCst);
+    format!("Failed to install Ctrl-C handler: {}", e)
+  })
+}
+
+/// Kills the process `pid` with SIGKILL
+#[cfg(unix)]
+fn kill_process(pid: u32) {
+  let _ = Command::new("kill").args(["-KILL", &pid.to_string()]).status();
+}
+
+/// Console Ctrl-C is delivered to the whole process group on Windows,
+/// children included, so there is nothing to forward
+#[cfg(not(unix))]
+fn kill_process(_pid: u32) {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_interrupt_handler_installed() {
+    install_interrupt_handler().unwrap();
+    assert!(HANDLER_INSTALLED.load(Ordering::SeqCst));
+    // Installing twice is harmless
+    install_interrupt_handler().unwrap();
+  }
+}
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 use std::env;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
 #[cfg(windows)]
 use dirs::home_dir;
@@ -11,9 +15,10 @@ use uzers::os::unix::UserExt;
 #[cfg(unix)]
 use uzers::{get_current_gid, get_current_uid, get_user_by_uid};
 
-use crate::context::Context;
+use crate::context::{Context, LogLevel};
 use crate::docker::process_docker_version;
 use crate::model::*;
+use crate::signals::ChildGuard;
 
 #[cfg(unix)]
 /// Ottiene l'ID utente, l'ID gruppo e il nome utente corrente sui sistemi Unix.
@@ -79,17 +84,166 @@ pub fn get_home_directory() -> Option<PathBuf> {
   return dirs::home_dir();
 }
 
-/// Prints a debug message if debug_print is enabled in the context.
+/// Prints a log message if its level passes the context's log level threshold.
 ///
 /// # Arguments
-/// * `ctx` - The execution context containing the debug_print flag
+/// * `ctx` - The execution context containing the log level threshold
+/// * `level` - Level of this message (`Error`, `Info` or `Debug`)
 /// * `module_name` - The name of the module/command issuing the debug message
 /// * `description` - Description of what is being done
 ///
 /// # Format
-/// The debug message is printed in the format: "module-name: description"
-pub fn debug_log(ctx: &Context, module_name: &str, description: &str) {
-  if ctx.get_debug_print() {
-    println!("{}: {}", module_name, description);
+/// The debug message is printed to stderr in the format: "module-name: description"
+pub fn debug_log(
+  ctx: &Context,
+  level: LogLevel,
+  module_name: &str,
+  description: &str,
+) {
+  if ctx.get_log_level().allows(level) {
+    eprintln!("{}: {}", module_name, description);
   }
 }
+
+/// Waits for a spawned child process, killing it if the timeout elapses.
+///
+/// # Arguments
+/// * `child` - The running child process
+/// * `timeout` - Maximum time to wait, or `None` to wait indefinitely
+///
+/// # Returns
+/// * `Result<ExitStatus, String>` - The exit status, or an error if waiting
+///   failed or the timeout was exceeded (in which case the child is killed
+///   and reaped, never leaked)
+pub fn wait_with_timeout(
+  child: &mut Child,
+  timeout: Option<Duration>,
+) -> Result<ExitStatus, String> {
+  let _guard = ChildGuard::new(child);
+  let timeout = match timeout {
+    Some(timeout) => timeout,
+    None => {
+      return child
+        .wait()
+        .map_err(|e| format!("Failed to wait for process: {}", e));
+    }
+  };
+
+  let deadline = Instant::now() + timeout;
+  loop {
+    match child.try_wait() {
+      Ok(Some(status)) => return Ok(status),
+      Ok(None) => {}
+      Err(e) => return Err(format!("Failed to wait for process: {}", e)),
+    }
+
+    if Instant::now() >= deadline {
+      let _ = child.kill();
+      let _ = child.wait();
+      return Err(format!(
+        "Process timed out after {} seconds and was killed",
+        timeout.as_secs()
+      ));
+    }
+
+    thread::sleep(Duration::from_millis(20));
+  }
+}
+
+/// Runs a command capturing stdout and stderr, killing it if the timeout
+/// elapses.
+///
+/// The output pipes are drained on background threads so a chatty child
+/// cannot block on a full pipe while we wait for it.
+///
+/// # Arguments
+/// * `cmd` - The command to run (stdout/stderr are replaced with pipes)
+/// * `timeout` - Maximum time to wait, or `None` to wait indefinitely
+///
+/// # Returns
+/// * `Result<Output, String>` - The captured output, or an error if the
+///   command could not be spawned or timed out
+pub fn output_with_timeout(
+  cmd: &mut Command,
+  timeout: Option<Duration>,
+) -> Result<Output, String> {
+  output_with_input_and_timeout(cmd, None, timeout)
+}
+
+/// Like `output_with_timeout`, additionally feeding `input` to the child's
+/// stdin when given.
+///
+/// The input is written on a background thread and stdin is closed once it
+/// has been written, so the child sees end-of-file. A child that exits (or
+/// closes stdin) before reading all the input does not block us: the write
+/// error is ignored.
+///
+/// # Arguments
+/// * `cmd` - The command to run (stdout/stderr, and stdin when `input` is
+///   given, are replaced with pipes)
+/// * `input` - Bytes to write to stdin, or `None` to give the child a null
+///   stdin like `Command::output` does
+/// * `timeout` - Maximum time to wait, or `None` to wait indefinitely
+///
+/// # Returns
+/// * `Result<Output, String>` - The captured output, or an error if the
+///   command could not be spawned or timed out
+pub fn output_with_input_and_timeout(
+  cmd: &mut Command,
+  input: Option<&[u8]>,
+  timeout: Option<Duration>,
+) -> Result<Output, String> {
+  cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+  if input.is_some() {
+    cmd.stdin(Stdio::piped());
+  } else {
+    cmd.stdin(Stdio::null());
+  }
+  let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+
+  let stdin_writer = match (child.stdin.take(), input) {
+    (Some(mut pipe), Some(input)) => {
+      let input = input.to_vec();
+      Some(thread::spawn(move || {
+        // Dropping the pipe at the end of the thread closes stdin
+        let _ = pipe.write_all(&input);
+      }))
+    }
+    _ => None,
+  };
+
+  let stdout_reader = child.stdout.take().map(|mut pipe| {
+    thread::spawn(move || {
+      let mut buf = Vec::new();
+      let _ = pipe.read_to_end(&mut buf);
+      buf
+    })
+  });
+  let stderr_reader = child.stderr.take().map(|mut pipe| {
+    thread::spawn(move || {
+      let mut buf = Vec::new();
+      let _ = pipe.read_to_end(&mut buf);
+      buf
+    })
+  });
+
+  // On timeout the reader threads are left to finish on their own: a
+  // grandchild may still hold the pipes open.
+  let status = wait_with_timeout(&mut child, timeout)?;
+
+  if let Some(writer) = stdin_writer {
+    let _ = writer.join();
+  }
+  let stdout = stdout_reader
+    .map(|h| h.join().unwrap_or_default())
+    .unwrap_or_default();
+  let stderr = stderr_reader
+    .map(|h| h.join().unwrap_or_default())
+    .unwrap_or_default();
+
+  Ok(Output {
+    status,
+    stdout,
+    stderr,
+  })
+}
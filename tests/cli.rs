@@ -0,0 +1,302 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Runs the dpm binary with the given arguments and returns its captured output
+fn run_dpm(args: &[&str]) -> Output {
+  Command::new(env!("CARGO_BIN_EXE_dpm"))
+    .args(args)
+    .output()
+    .expect("failed to run dpm")
+}
+
+/// Runs `dpm --pipe`, feeding `input` on stdin, and returns its captured output
+fn run_dpm_pipe(input: &str) -> Output {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_dpm"))
+    .arg("--pipe")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to run dpm");
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(input.as_bytes())
+    .unwrap();
+  child.wait_with_output().expect("failed to wait for dpm")
+}
+
+/// Sends `signal` (e.g. `-INT`, or `-0` to probe) to `pid` with kill(1)
+/// Returns whether the signal was delivered
+#[cfg(unix)]
+fn send_signal(signal: &str, pid: u32) -> bool {
+  Command::new("kill")
+    .args([signal, &pid.to_string()])
+    .stderr(Stdio::null())
+    .status()
+    .map(|status| status.success())
+    .unwrap_or(false)
+}
+
+#[test]
+fn test_debug_output_goes_to_stderr() {
+  let output = run_dpm(&["--command", "(debug)"]);
+  assert!(output.status.success());
+
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(stdout.is_empty(), "unexpected stdout: {}", stdout);
+  assert!(stderr.contains("=== DEBUG: Current Program State ==="));
+}
+
+#[test]
+fn test_debug_log_goes_to_stderr_and_print_to_stdout() {
+  let output = run_dpm(&[
+    "--command",
+    "(debug \"true\") (set-var \"name\" \"dpm\") (print (get-var \"name\"))",
+  ]);
+  assert!(output.status.success());
+
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert_eq!(stdout.trim(), "dpm");
+  assert!(stderr.contains("Debug printing enabled"));
+  assert!(stderr.contains("set-var:"));
+}
+
+#[test]
+fn test_debug_log_masks_secret_values() {
+  let output = run_dpm(&[
+    "--command",
+    "(debug \"true\") (docker-env-secret \"DB_PASSWORD\") (set-var \"DB_PASSWORD\" \"s3cr3t\") (get-var \"DB_PASSWORD\")",
+  ]);
+  assert!(output.status.success());
+
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(stderr.contains("value to store: DB_PASSWORD = ****"), "{}", stderr);
+  assert!(!stderr.contains("s3cr3t"), "{}", stderr);
+}
+
+#[test]
+fn test_help_output_goes_to_stderr() {
+  let output = run_dpm(&["--command", "(help \"sum\")"]);
+  assert!(output.status.success());
+  assert!(output.stdout.is_empty());
+  assert!(String::from_utf8_lossy(&output.stderr).contains("DETAILED HELP FOR 'sum'"));
+}
+
+#[test]
+fn test_errors_go_to_stderr() {
+  let output = run_dpm(&["--command", "(unknown-command)"]);
+  assert!(!output.status.success());
+  assert!(output.stdout.is_empty());
+  assert!(String::from_utf8_lossy(&output.stderr).contains("Error:"));
+}
+
+#[test]
+fn test_set_current_dir_changes_process_cwd() {
+  let target = std::env::temp_dir().canonicalize().unwrap();
+  let script = format!(
+    "(rust-env-set-current-dir \"{}\") (print (rust-env-current-dir))",
+    target.display()
+  );
+  let output = run_dpm(&["--command", &script]);
+  assert!(output.status.success());
+  assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), target.display().to_string());
+}
+
+#[test]
+fn test_dump_commands() {
+  let output = run_dpm(&["--dump-commands"]);
+  assert!(output.status.success());
+
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  assert!(stdout.starts_with("=== DETAILED COMMAND REFERENCE ==="));
+  assert!(stdout.contains("Command: docker"));
+  assert!(stdout.contains("Command: rust-env-var"));
+}
+
+#[test]
+fn test_dump_commands_json() {
+  let output = run_dpm(&["--dump-commands-json"]);
+  assert!(output.status.success());
+
+  let catalog: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+  let names: Vec<&str> = catalog
+    .as_array()
+    .unwrap()
+    .iter()
+    .flat_map(|group| group["commands"].as_array().unwrap())
+    .map(|command| command["name"].as_str().unwrap())
+    .collect();
+  assert!(names.contains(&"help-json"));
+  assert!(names.contains(&"rust-fs-read-base64"));
+}
+
+#[test]
+fn test_version_flag() {
+  let output = run_dpm(&["--version"]);
+  assert!(output.status.success());
+  assert_eq!(
+    String::from_utf8_lossy(&output.stdout).trim(),
+    format!("dpm {}", env!("CARGO_PKG_VERSION"))
+  );
+}
+
+#[test]
+fn test_help_flag() {
+  let output = run_dpm(&["--help"]);
+  assert!(output.status.success());
+
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  assert!(stdout.starts_with("Usage:"));
+  assert!(stdout.contains("--version"));
+  assert!(stdout.contains("Available commands:"));
+  assert!(stdout.contains("  sum "));
+}
+
+#[test]
+fn test_pipe_accumulates_multiline_expressions() {
+  let output = run_dpm_pipe("(print \"one\")\n\n(print\n  \"two (\"\n  (sum 1\n       2))\n");
+  assert!(output.status.success());
+  assert_eq!(String::from_utf8_lossy(&output.stdout), "one\ntwo ( 3\n");
+}
+
+#[test]
+fn test_pipe_reports_unbalanced_input_at_eof() {
+  let output = run_dpm_pipe("(print \"ok\")\n(print\n  \"never closed\"\n");
+  assert!(!output.status.success());
+  assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+  assert!(String::from_utf8_lossy(&output.stderr).contains("unbalanced parentheses"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_pipe_child_gets_null_stdin() {
+  use std::io::{BufRead, BufReader, Read};
+
+  // dpm's stdin stays open: a child inheriting it would block until the timeout
+  let mut dpm = Command::new(env!("CARGO_BIN_EXE_dpm"))
+    .arg("--pipe")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to run dpm");
+  let mut stdin = dpm.stdin.take().unwrap();
+  stdin
+    .write_all(b"(print (rust-process-output 2 \"cat\"))\n(print \"done\")\n")
+    .unwrap();
+
+  let mut stdout = BufReader::new(dpm.stdout.take().unwrap());
+  let mut lines = Vec::new();
+  let mut line = String::new();
+  while stdout.read_line(&mut line).unwrap() > 0 && line.trim() != "done" {
+    lines.push(std::mem::take(&mut line));
+  }
+  drop(stdin);
+  let mut stderr = String::new();
+  dpm.stderr.take().unwrap().read_to_string(&mut stderr).unwrap();
+  dpm.wait().unwrap();
+
+  assert_eq!(line.trim(), "done", "stderr: {}", stderr);
+  assert!(!stderr.contains("timed out"));
+  assert!(lines.concat().contains("true"));
+}
+
+#[test]
+fn test_print_raw_has_no_trailing_newline() {
+  let output = run_dpm(&["--command", "(print-raw \"Step\" 1) (print-raw \"/3\") (print \" done\")"]);
+  assert!(output.status.success());
+  assert_eq!(String::from_utf8_lossy(&output.stdout), "Step 1/3 done\n");
+
+  let output = run_dpm(&["--command", "(print-raw \"no newline\")"]);
+  assert_eq!(String::from_utf8_lossy(&output.stdout), "no newline");
+}
+
+#[test]
+fn test_tee_label_goes_to_stdout() {
+  let output = run_dpm(&[
+    "--output",
+    "-",
+    "--command",
+    "(pipe (sum 1 2) (tee \"after sum\") (multiply 10))",
+  ]);
+  assert!(output.status.success());
+  assert_eq!(String::from_utf8_lossy(&output.stdout), "after sum: 3\n30\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_interrupt_kills_running_child() {
+  use std::time::{Duration, Instant};
+
+  let pid_file = std::env::temp_dir().join("dpm_test_interrupt_child.pid");
+  let _ = std::fs::remove_file(&pid_file);
+  let script = format!(
+    "(rust-process-command \"sh\" \"-c\" \"echo $$ > {}; exec sleep 30\")",
+    pid_file.display()
+  );
+  let mut dpm = Command::new(env!("CARGO_BIN_EXE_dpm"))
+    .args(["--command", &script])
+    .spawn()
+    .expect("failed to run dpm");
+
+  // Wait for the child to record its pid
+  let deadline = Instant::now() + Duration::from_secs(5);
+  let child_pid = loop {
+    if let Ok(pid) = std::fs::read_to_string(&pid_file) {
+      if let Ok(pid) = pid.trim().parse::<u32>() {
+        break pid;
+      }
+    }
+    assert!(Instant::now() < deadline, "child never started");
+    std::thread::sleep(Duration::from_millis(20));
+  };
+
+  // Only dpm receives the signal: the child must be killed by the handler
+  assert!(send_signal("-INT", dpm.id()));
+  let status = dpm.wait().unwrap();
+  assert_eq!(status.code(), Some(130));
+
+  let deadline = Instant::now() + Duration::from_secs(5);
+  while send_signal("-0", child_pid) {
+    assert!(Instant::now() < deadline, "child {} still running", child_pid);
+    std::thread::sleep(Duration::from_millis(20));
+  }
+  let _ = std::fs::remove_file(&pid_file);
+}
+
+#[test]
+fn test_output_writes_final_value() {
+  let dir = std::env::temp_dir().join("dpm_test_output");
+  let _ = std::fs::remove_dir_all(&dir);
+  std::fs::create_dir_all(&dir).unwrap();
+  let text_file = dir.join("result.txt");
+  let json_file = dir.join("result.json");
+
+  let output = run_dpm(&["--output", text_file.to_str().unwrap(), "--command", "(print \"side effect\") (sum 40 2)"]);
+  assert!(output.status.success());
+  assert_eq!(String::from_utf8_lossy(&output.stdout), "side effect\n");
+  assert_eq!(std::fs::read_to_string(&text_file).unwrap(), "42\n");
+
+  let output = run_dpm(&[
+    "--output",
+    json_file.to_str().unwrap(),
+    "--output-json",
+    "--command",
+    "(list \"a\" 1 #t (list))",
+  ]);
+  assert!(output.status.success());
+  let value: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&json_file).unwrap()).unwrap();
+  assert_eq!(value, serde_json::json!(["a", 1, true, []]));
+
+  let output = run_dpm(&["--output", "-", "--command", "(concat \"a\" \"b\")"]);
+  assert_eq!(String::from_utf8_lossy(&output.stdout), "ab\n");
+
+  let output = run_dpm(&["--output", "-", "--pipe"]);
+  assert_eq!(output.status.code(), Some(2));
+
+  let _ = std::fs::remove_dir_all(&dir);
+}